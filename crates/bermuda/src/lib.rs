@@ -1,20 +1,516 @@
 #![allow(clippy::useless_conversion)]
 
-use numpy::{PyArray, PyArray2, PyArrayMethods, PyReadonlyArray2};
+use numpy::ndarray::{Array2, ArrayView1, ArrayView2};
+use numpy::{
+    Element, PyArray, PyArray1, PyArray2, PyArrayMethods, PyReadonlyArray1, PyReadonlyArray2,
+    PyReadwriteArray2, PyUntypedArrayMethods,
+};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PySlice;
+use pyo3::IntoPyObjectExt;
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use triangulation::delaunay::{flip_to_constrained_delaunay, refine_delaunay};
+use triangulation::intersection::calc_edges;
+use triangulation::point::Vector;
 use triangulation::{
-    is_convex, split_polygons_on_repeated_edges as split_polygons_on_repeated_edges_rust,
-    sweeping_line_triangulation, triangulate_convex_polygon,
-    triangulate_path_edge as triangulate_path_edge_rust, triangulate_paths_edge, PathTriangulation,
-    Point, Triangle,
+    is_convex, label_contours as label_contours_rust, offset_polygons as offset_polygons_rust,
+    polygon_boolean as polygon_boolean_rust, project_to_plane,
+    rasterize_shapes as rasterize_shapes_rust, simplify_polygons as simplify_polygons_rust,
+    split_polygons_on_repeated_edges as split_polygons_on_repeated_edges_rust,
+    split_polygons_with_fill_rule, sweeping_line_triangulation,
+    trace_label_boundaries as trace_label_boundaries_rust, triangulate_convex_polygon,
+    triangulate_path_edge_dashed as triangulate_path_edge_dashed_rust,
+    triangulate_path_edge_styled, triangulate_path_edge_with_widths, triangulate_path_ribbon,
+    triangulate_path_tube, triangulate_paths_edge as triangulate_paths_edge_rust,
+    triangulate_paths_edge_styled, triangulate_rectilinear_face,
+    triangulate_shapes_face as triangulate_shapes_face_rust, BooleanOp, CapStyle, DashPattern,
+    FaceStyle, FillRule, Float, JoinStyle, OffsetStyle, PathStyle, PathTriangulation, Plane, Point,
+    RasterMode, Refinement, Segment, ShapesTriangulation, SimplifyMethod, SimplifyStyle, Triangle,
+    TriangulationError as TriangulationErrorRust, TriangulationQuality, TubeStyle,
 };
 
-type EdgeTriangulation = (Py<PyArray2<f32>>, Py<PyArray2<f32>>, Py<PyArray2<u32>>);
-type FaceTriangulation = (Py<PyArray2<u32>>, Py<PyArray2<f32>>);
-type PyEdgeTriangulation = PyResult<EdgeTriangulation>;
-type PyFaceTriangulation = PyResult<FaceTriangulation>;
-type PyPolygonTriangulation = PyResult<(FaceTriangulation, EdgeTriangulation)>;
+create_exception!(
+    _bermuda,
+    TriangulationError,
+    PyValueError,
+    "Raised when polygons cannot be triangulated.\n\n\
+     Attributes `polygon_index` and `vertex` point to the failing polygon\n\
+     and vertex, or are None if they cannot be determined. For batched\n\
+     functions `shape_index` points to the failing shape."
+);
+
+type EdgeOut<'py> = (Bound<'py, PyAny>, Bound<'py, PyAny>, Bound<'py, PyAny>);
+type FaceOut<'py> = (Bound<'py, PyAny>, Bound<'py, PyAny>);
+type EdgeTriangulation<T> = (Py<PyArray2<T>>, Py<PyArray2<T>>, Py<PyArray2<u32>>);
+type FaceTriangulation<T> = (Py<PyArray2<u32>>, Py<PyArray2<T>>);
+type ProjectedPolygons3D<T> = (Vec<Vec<Point<T>>>, Plane, HashMap<Point<T>, [T; 3]>);
+type PyEdgeTriangulation<T> = PyResult<EdgeTriangulation<T>>;
+type PyFaceTriangulation<T> = PyResult<FaceTriangulation<T>>;
+type PyPolygonTriangulation<T> = PyResult<(FaceTriangulation<T>, EdgeTriangulation<T>)>;
+type PyShapesTriangulation<T> = PyResult<(Py<PyArray2<u32>>, Py<PyArray2<T>>, Py<PyArray2<u32>>)>;
+type PyRibbonTriangulation<T> = PyResult<(
+    Py<PyArray2<T>>,
+    Py<PyArray2<T>>,
+    Py<PyArray2<T>>,
+    Py<PyArray2<u32>>,
+    Py<PyArray1<T>>,
+)>;
+type PyDashedPathTriangulation<T> = PyResult<(
+    Py<PyArray2<T>>,
+    Py<PyArray2<T>>,
+    Py<PyArray2<u32>>,
+    Py<PyArray1<T>>,
+)>;
+
+/// Coordinate type which can be stored in numpy arrays (float32 or float64).
+trait NumpyFloat: Float + Element {
+    /// Coordinates of `coords`, without copying, if the array has this dtype
+    /// and is C-contiguous.
+    fn contiguous<'a>(coords: &'a PyCoords<'_>) -> Option<&'a [Self]>;
+}
+
+impl NumpyFloat for f32 {
+    fn contiguous<'a>(coords: &'a PyCoords<'_>) -> Option<&'a [Self]> {
+        match coords {
+            PyCoords::F32(array) => array.as_slice().ok(),
+            PyCoords::F64(_) => None,
+        }
+    }
+}
+
+impl NumpyFloat for f64 {
+    fn contiguous<'a>(coords: &'a PyCoords<'_>) -> Option<&'a [Self]> {
+        match coords {
+            PyCoords::F32(_) => None,
+            PyCoords::F64(array) => array.as_slice().ok(),
+        }
+    }
+}
+
+/// Nx2 (or Nx3) array of coordinates of float32 or float64 dtype.
+///
+/// Results of functions keep the dtype of their input. If inputs of a function
+/// mix both dtypes, float32 arrays are promoted to float64.
+#[derive(FromPyObject)]
+enum PyCoords<'py> {
+    F32(PyReadonlyArray2<'py, f32>),
+    F64(PyReadonlyArray2<'py, f64>),
+}
+
+impl PyCoords<'_> {
+    fn is_f64(&self) -> bool {
+        matches!(self, PyCoords::F64(_))
+    }
+
+    /// Copy of the array with coordinates converted to `T`.
+    fn to_array<T: Float>(&self) -> Array2<T> {
+        match self {
+            PyCoords::F32(array) => array.as_array().mapv(|value| T::from_f64(value.to_f64())),
+            PyCoords::F64(array) => array.as_array().mapv(T::from_f64),
+        }
+    }
+
+    fn columns(&self) -> usize {
+        match self {
+            PyCoords::F32(array) => array.shape()[1],
+            PyCoords::F64(array) => array.shape()[1],
+        }
+    }
+
+    /// Convert the first two columns of the array into points.
+    ///
+    /// C-contiguous Nx2 arrays of dtype `T` are borrowed without copying.
+    fn to_points<T: NumpyFloat>(&self) -> Cow<'_, [Point<T>]> {
+        fn rows_to_points<S: Float, T: Float>(array: ArrayView2<'_, S>) -> Vec<Point<T>> {
+            array
+                .rows()
+                .into_iter()
+                .map(|row| Point::new(T::from_f64(row[0].to_f64()), T::from_f64(row[1].to_f64())))
+                .collect()
+        }
+        if self.columns() == 2 {
+            if let Some(points) = T::contiguous(self).and_then(Point::slice_from_coords) {
+                return Cow::Borrowed(points);
+            }
+        }
+        Cow::Owned(match self {
+            PyCoords::F32(array) => rows_to_points(array.as_array()),
+            PyCoords::F64(array) => rows_to_points(array.as_array()),
+        })
+    }
+
+    /// Convert rows of an Nx3 array into 3D points.
+    fn to_points_3d<T: Float>(&self) -> Vec<[T; 3]> {
+        self.to_array::<T>()
+            .rows()
+            .into_iter()
+            .map(|row| [row[0], row[1], row[2]])
+            .collect()
+    }
+}
+
+/// Whether results for `arrays` are computed in float64.
+fn any_f64(arrays: &[PyCoords<'_>]) -> bool {
+    arrays.iter().any(PyCoords::is_f64)
+}
+
+/// 2D array of labels of boolean or integer dtype.
+#[derive(FromPyObject)]
+enum PyLabels<'py> {
+    Bool(PyReadonlyArray2<'py, bool>),
+    U8(PyReadonlyArray2<'py, u8>),
+    U16(PyReadonlyArray2<'py, u16>),
+    U32(PyReadonlyArray2<'py, u32>),
+    U64(PyReadonlyArray2<'py, u64>),
+    I8(PyReadonlyArray2<'py, i8>),
+    I16(PyReadonlyArray2<'py, i16>),
+    I32(PyReadonlyArray2<'py, i32>),
+    I64(PyReadonlyArray2<'py, i64>),
+}
+
+/// Writeable 2D array of labels of boolean or integer dtype.
+#[derive(FromPyObject)]
+enum PyLabelsMut<'py> {
+    Bool(PyReadwriteArray2<'py, bool>),
+    U8(PyReadwriteArray2<'py, u8>),
+    U16(PyReadwriteArray2<'py, u16>),
+    U32(PyReadwriteArray2<'py, u32>),
+    U64(PyReadwriteArray2<'py, u64>),
+    I8(PyReadwriteArray2<'py, i8>),
+    I16(PyReadwriteArray2<'py, i16>),
+    I32(PyReadwriteArray2<'py, i32>),
+    I64(PyReadwriteArray2<'py, i64>),
+}
+
+/// Evaluate `$body` with `$array` bound to the typed array of `PyLabels`,
+/// or of `PyLabelsMut` when prefixed with `mut`.
+macro_rules! with_labels {
+    ($labels:expr, $array:ident => $body:expr) => {
+        with_labels!(@match PyLabels, $labels, $array => $body)
+    };
+    (mut $labels:expr, $array:ident => $body:expr) => {
+        with_labels!(@match PyLabelsMut, $labels, $array => $body)
+    };
+    (@match $kind:ident, $labels:expr, $array:ident => $body:expr) => {
+        match $labels {
+            $kind::Bool($array) => $body,
+            $kind::U8($array) => $body,
+            $kind::U16($array) => $body,
+            $kind::U32($array) => $body,
+            $kind::U64($array) => $body,
+            $kind::I8($array) => $body,
+            $kind::I16($array) => $body,
+            $kind::I32($array) => $body,
+            $kind::I64($array) => $body,
+        }
+    };
+}
+
+/// Label type which can be stored in numpy arrays.
+trait NumpyLabel: Element + Copy + Ord + Default + Send + Sync + for<'py> IntoPyObject<'py> {}
+
+impl<L: Element + Copy + Ord + Default + Send + Sync + for<'py> IntoPyObject<'py>> NumpyLabel
+    for L
+{
+}
+
+/// Contours of all labels of `labels` except `background`, which is zero (or False) by default.
+/// With `exact` the pixel boundaries are traced instead of marching squares contours.
+fn contours_of_labels<'py, L>(
+    labels: &PyReadonlyArray2<'py, L>,
+    background: Option<&Bound<'py, PyAny>>,
+    exact: bool,
+) -> PyResult<BTreeMap<L, Vec<Vec<Point<f32>>>>>
+where
+    L: NumpyLabel + for<'a> FromPyObject<'a, 'py>,
+{
+    let background = match background {
+        Some(background) => background.extract::<L>().map_err(Into::into)?,
+        None => L::default(),
+    };
+    let shape = [labels.shape()[0], labels.shape()[1]];
+    let values = match labels.as_slice() {
+        Ok(values) => Cow::Borrowed(values),
+        Err(_) => Cow::Owned(labels.as_array().iter().copied().collect()),
+    };
+    Ok(labels.py().detach(|| {
+        if exact {
+            trace_label_boundaries_rust(&values, shape, background)
+        } else {
+            label_contours_rust(&values, shape, background)
+        }
+    }))
+}
+
+/// N array of values of float32 or float64 dtype.
+#[derive(FromPyObject)]
+enum PyValues<'py> {
+    F32(PyReadonlyArray1<'py, f32>),
+    F64(PyReadonlyArray1<'py, f64>),
+}
+
+impl PyValues<'_> {
+    fn to_vec<T: Float>(&self) -> Vec<T> {
+        fn convert<S: Float, T: Float>(array: ArrayView1<'_, S>) -> Vec<T> {
+            array
+                .iter()
+                .map(|value| T::from_f64(value.to_f64()))
+                .collect()
+        }
+        match self {
+            PyValues::F32(array) => convert(array.as_array()),
+            PyValues::F64(array) => convert(array.as_array()),
+        }
+    }
+}
+
+/// Parameter of batched functions given either once for all elements
+/// or as a sequence with a separate value for each element.
+#[derive(FromPyObject)]
+enum PerElement<T> {
+    Single(T),
+    Many(Vec<T>),
+}
+
+impl<T: Copy> PerElement<T> {
+    /// Expand parameter to `count` values, checking the length of the sequence.
+    fn expand(&self, count: usize, name: &str) -> PyResult<Vec<T>> {
+        match self {
+            PerElement::Single(value) => Ok(vec![*value; count]),
+            PerElement::Many(values) if values.len() == count => Ok(values.clone()),
+            PerElement::Many(values) => Err(PyValueError::new_err(format!(
+                "Length of `{}` ({}) does not match number of elements ({})",
+                name,
+                values.len(),
+                count
+            ))),
+        }
+    }
+}
+
+/// Cap style of open paths given as one of 'butt', 'square' or 'round'.
+#[derive(Clone, Copy)]
+struct PyCapStyle(CapStyle);
+
+impl<'py> FromPyObject<'_, 'py> for PyCapStyle {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "butt" => Ok(PyCapStyle(CapStyle::Butt)),
+            "square" => Ok(PyCapStyle(CapStyle::Square)),
+            "round" => Ok(PyCapStyle(CapStyle::round())),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown cap style '{}', expected 'butt', 'square' or 'round'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Join style of path corners given as one of 'miter', 'bevel' or 'round'.
+#[derive(Clone, Copy)]
+struct PyJoinStyle(JoinStyle);
+
+impl<'py> FromPyObject<'_, 'py> for PyJoinStyle {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "miter" => Ok(PyJoinStyle(JoinStyle::Miter)),
+            "bevel" => Ok(PyJoinStyle(JoinStyle::Bevel)),
+            "round" => Ok(PyJoinStyle(JoinStyle::round())),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown join style '{}', expected 'miter', 'bevel' or 'round'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Fill rule given as one of 'evenodd', 'nonzero', 'positive' or 'negative'.
+#[derive(Clone, Copy)]
+struct PyFillRule(FillRule);
+
+impl<'py> FromPyObject<'_, 'py> for PyFillRule {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "evenodd" => Ok(PyFillRule(FillRule::EvenOdd)),
+            "nonzero" => Ok(PyFillRule(FillRule::NonZero)),
+            "positive" => Ok(PyFillRule(FillRule::Positive)),
+            "negative" => Ok(PyFillRule(FillRule::Negative)),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown fill rule '{}', expected 'evenodd', 'nonzero', 'positive' or 'negative'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Quality of face triangulation given as one of 'fast', 'delaunay' or 'refined'.
+#[derive(Clone, Copy)]
+struct PyTriangulationQuality(TriangulationQuality);
+
+impl<'py> FromPyObject<'_, 'py> for PyTriangulationQuality {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "fast" => Ok(PyTriangulationQuality(TriangulationQuality::Fast)),
+            "delaunay" => Ok(PyTriangulationQuality(TriangulationQuality::Delaunay)),
+            "refined" => Ok(PyTriangulationQuality(TriangulationQuality::Refined(
+                Refinement::default(),
+            ))),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown quality '{}', expected 'fast', 'delaunay' or 'refined'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Face triangulation style from optional `fill_rule`, `quality` and refinement arguments.
+///
+/// Giving `min_angle` or `max_area` without `quality` selects 'refined' quality.
+fn resolve_face_style(
+    fill_rule: Option<PyFillRule>,
+    quality: Option<PyTriangulationQuality>,
+    min_angle: Option<f64>,
+    max_area: Option<f64>,
+) -> PyResult<FaceStyle> {
+    if min_angle.is_some_and(|min_angle| !min_angle.is_finite()) {
+        return Err(PyValueError::new_err("min_angle must be finite"));
+    }
+    if max_area.is_some_and(|max_area| !(max_area.is_finite() && max_area > 0.0)) {
+        return Err(PyValueError::new_err(
+            "max_area must be a positive finite number",
+        ));
+    }
+    let bounded = min_angle.is_some() || max_area.is_some();
+    let quality = match (quality.map(|quality| quality.0), bounded) {
+        (None, true) | (Some(TriangulationQuality::Refined(_)), _) => {
+            TriangulationQuality::Refined(Refinement {
+                min_angle: min_angle.unwrap_or(Refinement::default().min_angle),
+                max_area,
+                ..Refinement::default()
+            })
+        }
+        (Some(_), true) => {
+            return Err(PyValueError::new_err(
+                "min_angle and max_area require quality='refined'",
+            ))
+        }
+        (quality, false) => quality.unwrap_or_default(),
+    };
+    Ok(FaceStyle::new(fill_rule.map_or(FillRule::EvenOdd, |rule| rule.0)).with_quality(quality))
+}
+
+/// Boolean operation given as one of 'union', 'intersection', 'difference' or 'xor'.
+#[derive(Clone, Copy)]
+struct PyBooleanOp(BooleanOp);
+
+impl<'py> FromPyObject<'_, 'py> for PyBooleanOp {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "union" => Ok(PyBooleanOp(BooleanOp::Union)),
+            "intersection" => Ok(PyBooleanOp(BooleanOp::Intersection)),
+            "difference" => Ok(PyBooleanOp(BooleanOp::Difference)),
+            "xor" => Ok(PyBooleanOp(BooleanOp::Xor)),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown boolean operation '{}', expected 'union', 'intersection', 'difference' or 'xor'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Simplification method given as 'douglas-peucker' or 'visvalingam-whyatt'.
+#[derive(Clone, Copy)]
+struct PySimplifyMethod(SimplifyMethod);
+
+impl<'py> FromPyObject<'_, 'py> for PySimplifyMethod {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "douglas-peucker" => Ok(PySimplifyMethod(SimplifyMethod::DouglasPeucker)),
+            "visvalingam-whyatt" => Ok(PySimplifyMethod(SimplifyMethod::VisvalingamWhyatt)),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown simplification method '{}', expected 'douglas-peucker' or 'visvalingam-whyatt'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Pixel inclusion rule given as 'pixel-center' or 'any-touch'.
+#[derive(Clone, Copy)]
+struct PyRasterMode(RasterMode);
+
+impl<'py> FromPyObject<'_, 'py> for PyRasterMode {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let name: String = obj.extract()?;
+        match name.as_str() {
+            "pixel-center" => Ok(PyRasterMode(RasterMode::PixelCenter)),
+            "any-touch" => Ok(PyRasterMode(RasterMode::AnyTouch)),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown raster mode '{}', expected 'pixel-center' or 'any-touch'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Resolve join style from `join` argument and legacy `bevel` flag,
+/// `join` takes precedence when given.
+fn resolve_join(join: Option<PyJoinStyle>, bevel: bool) -> JoinStyle {
+    match join {
+        Some(join) => join.0,
+        None if bevel => JoinStyle::Bevel,
+        None => JoinStyle::Miter,
+    }
+}
+
+/// Shape passed to batched functions, either a single polygon
+/// or a list of rings (outer boundary and holes).
+#[derive(FromPyObject)]
+enum PyShape<'py> {
+    Polygon(PyCoords<'py>),
+    Rings(Vec<PyCoords<'py>>),
+}
+
+impl PyShape<'_> {
+    fn rings(&self) -> &[PyCoords<'_>] {
+        match self {
+            PyShape::Polygon(polygon) => std::slice::from_ref(polygon),
+            PyShape::Rings(rings) => rings,
+        }
+    }
+
+    fn to_rust_polygons<T: NumpyFloat>(&self) -> Vec<Vec<Point<T>>> {
+        // batched functions take owned rings
+        numpy_polygons_to_rust_polygons(self.rings())
+            .into_iter()
+            .map(Cow::into_owned)
+            .collect()
+    }
+}
 
 /// Determines the triangulation of a path in 2D
 ///
@@ -29,8 +525,27 @@ type PyPolygonTriangulation = PyResult<(FaceTriangulation, EdgeTriangulation)>;
 ///     bevel join
 /// bevel : bool, optional (default=False)
 ///     Bool which if True causes a bevel join to always be used. If False
-///     a bevel join will only be used when the miter limit is exceeded
-//
+///     a bevel join will only be used when the miter limit is exceeded.
+///     Kept for backward compatibility, equivalent to ``join='bevel'``
+/// cap : str, optional (default='butt')
+///     Shape of both ends of an open path, one of 'butt', 'square'
+///     (extended by half of the width) or 'round'. Ignored for closed paths
+/// join : str, optional (default='miter')
+///     Shape of path corners, one of 'miter' (switching to bevel when
+///     the miter limit is exceeded), 'bevel' or 'round'. Takes precedence
+///     over `bevel` when given
+/// width : np.ndarray, optional
+///     N array of path widths at each vertex. If given, returned offsets
+///     are already scaled by the width
+/// attributes : bool, optional (default=False)
+///     If True, also return per-vertex `arc_lengths` and `sides`
+/// out : tuple of np.ndarray, optional
+///     Preallocated arrays for `centers`, `offsets` and `triangles`, with
+///     the dtypes of the results and enough rows for them. Results are
+///     computed first and then copied into their first rows, so `out` saves
+///     allocating the returned arrays but not the memory of the computation.
+///     Views of these rows are returned.
+///
 /// Returns
 /// -------
 /// centers : np.ndarray
@@ -38,45 +553,392 @@ type PyPolygonTriangulation = PyResult<(FaceTriangulation, EdgeTriangulation)>;
 /// offsets : np.ndarray
 ///     Mx2 array of the offsets to the central coordinates that need to
 ///     be scaled by the line width and then added to the centers to
-///     generate the actual vertices of the triangulation
+///     generate the actual vertices of the triangulation. If `width` is
+///     given, offsets are already scaled and vertices are ``centers + offsets``
 /// triangles : np.ndarray
 ///     (M-2)x3 array of the indices of the vertices that will form the
 ///     triangles of the triangulation
+/// arc_lengths : np.ndarray
+///     M array of distances along the path from its start to the vertex
+///     centers, returned only if `attributes` is True
+/// sides : np.ndarray
+///     M array with 1.0 for vertices on the left side of the path (looking
+///     along the path direction), -1.0 for vertices on the right side and
+///     0.0 for vertices on the path, returned only if `attributes` is True
 #[pyfunction]
-#[pyo3(signature = (path, closed=false, limit=3.0, bevel=false))]
-fn triangulate_path_edge(
-    py: Python<'_>,
-    path: PyReadonlyArray2<'_, f32>,
+#[pyo3(signature = (
+    path, closed=false, limit=3.0, bevel=false, cap=None, join=None, width=None, attributes=false,
+    out=None
+))]
+#[allow(clippy::too_many_arguments)]
+fn triangulate_path_edge<'py>(
+    py: Python<'py>,
+    path: PyCoords<'_>,
     closed: Option<bool>,
     limit: Option<f32>,
     bevel: Option<bool>,
-) -> PyEdgeTriangulation {
-    // Convert the numpy array into a rust compatible representations which is a vector of points.
-    let path_: Vec<Point> = path
-        .as_array()
-        .rows()
-        .into_iter()
-        .map(|row| Point {
-            x: row[0],
-            y: row[1],
-        })
-        .collect();
-
-    // Call the re-exported Rust function directly
-    let result = triangulate_path_edge_rust(
-        &path_,
+    cap: Option<PyCapStyle>,
+    join: Option<PyJoinStyle>,
+    width: Option<PyValues<'_>>,
+    attributes: bool,
+    out: Option<EdgeOut<'py>>,
+) -> PyResult<Py<PyAny>> {
+    let style = PathStyle::new(
         closed.unwrap_or(false),
         limit.unwrap_or(3.0),
-        bevel.unwrap_or(false),
+        resolve_join(join, bevel.unwrap_or(false)),
+    )
+    .with_cap(cap.map_or(CapStyle::Butt, |cap| cap.0))
+    .with_attributes(attributes);
+    if path.is_f64() {
+        triangulate_path_edge_impl::<f64>(py, &path, &style, width.as_ref(), out.as_ref())
+    } else {
+        triangulate_path_edge_impl::<f32>(py, &path, &style, width.as_ref(), out.as_ref())
+    }
+}
+
+fn triangulate_path_edge_impl<'py, T: NumpyFloat>(
+    py: Python<'py>,
+    path: &PyCoords<'_>,
+    style: &PathStyle,
+    width: Option<&PyValues<'_>>,
+    out: Option<&EdgeOut<'py>>,
+) -> PyResult<Py<PyAny>> {
+    let path_ = numpy_path_to_rust_path::<T>(path);
+    let widths: Option<Vec<T>> = width.map(PyValues::to_vec);
+    if let Some(widths) = &widths {
+        if widths.len() != path_.len() {
+            return Err(PyValueError::new_err(format!(
+                "Length of `width` ({}) does not match number of path points ({})",
+                widths.len(),
+                path_.len()
+            )));
+        }
+    }
+    // Call the re-exported Rust function directly, without holding the GIL
+    let mut result = py.detach(|| match &widths {
+        Some(widths) => triangulate_path_edge_with_widths(&path_, widths, style),
+        None => triangulate_path_edge_styled(&path_, style),
+    });
+    let (centers, offsets, triangles) = match out {
+        Some((centers, offsets, triangles)) => (
+            write_rows(
+                centers,
+                result.centers.iter().map(|p| [p.x, p.y]),
+                "centers",
+            )?,
+            write_rows(
+                offsets,
+                result.offsets.iter().map(|v| [v.x, v.y]),
+                "offsets",
+            )?,
+            write_rows(triangles, triangle_rows(&result.triangles), "triangles")?,
+        ),
+        None => {
+            let (centers, offsets, triangles) =
+                path_triangulation_to_numpy_arrays(py, &mut result)?;
+            (centers.into_any(), offsets.into_any(), triangles.into_any())
+        }
+    };
+    if style.attributes {
+        let (arc_lengths, sides) = path_attributes_to_numpy_arrays(py, result);
+        Ok((centers, offsets, triangles, arc_lengths, sides)
+            .into_pyobject(py)?
+            .into_any()
+            .unbind())
+    } else {
+        Ok((centers, offsets, triangles)
+            .into_pyobject(py)?
+            .into_any()
+            .unbind())
+    }
+}
+
+/// Convert the numpy array into a rust compatible representations which is a slice of points,
+/// borrowed from the array when possible.
+fn numpy_path_to_rust_path<'a, T: NumpyFloat>(path: &'a PyCoords<'_>) -> Cow<'a, [Point<T>]> {
+    path.to_points()
+}
+
+/// Determines the triangulation of a dashed path in 2D
+///
+/// The path is split into dashes along its length and each dash is
+/// triangulated as an open path with the given caps and joins.
+///
+/// Parameters
+/// ----------
+/// path : np.ndarray
+///     Nx2 array of central coordinates of path to be triangulated
+/// dash : Sequence[float]
+///     Alternating lengths of dashes and gaps, starting with a dash. Dashes
+///     of zero length are drawn as dots with 'square' or 'round' caps
+/// dash_phase : float, optional (default=0.0)
+///     Distance into the dash pattern at which the path starts
+/// closed : bool, optional (default=False)
+///     Bool which determines if the path is closed or not
+/// limit : float, optional (default=3.0)
+///     Miter limit which determines when to switch from a miter join to a
+///     bevel join
+/// cap : str, optional (default='butt')
+///     Shape of both ends of each dash, one of 'butt', 'square' or 'round'
+/// join : str, optional (default='miter')
+///     Shape of path corners inside dashes, one of 'miter', 'bevel' or 'round'
+///
+/// Returns
+/// -------
+/// centers : np.ndarray
+///     Mx2 array central coordinates of path triangles.
+/// offsets : np.ndarray
+///     Mx2 array of the offsets to the central coordinates that need to
+///     be scaled by the line width and then added to the centers to
+///     generate the actual vertices of the triangulation
+/// triangles : np.ndarray
+///     Kx3 array of the indices of the vertices that will form the
+///     triangles of the triangulation
+/// arc_lengths : np.ndarray
+///     M array of distances along the path from its start to the vertex centers
+#[pyfunction]
+#[pyo3(signature = (path, dash, dash_phase=0.0, closed=false, limit=3.0, cap=None, join=None))]
+#[allow(clippy::too_many_arguments)]
+fn triangulate_path_edge_dashed(
+    py: Python<'_>,
+    path: PyCoords<'_>,
+    dash: Vec<f64>,
+    dash_phase: f64,
+    closed: bool,
+    limit: f32,
+    cap: Option<PyCapStyle>,
+    join: Option<PyJoinStyle>,
+) -> PyResult<Py<PyAny>> {
+    if dash.iter().any(|length| length.is_nan() || *length < 0.0) {
+        return Err(PyValueError::new_err(
+            "Lengths in `dash` must be non-negative numbers",
+        ));
+    }
+    let style = PathStyle::new(closed, limit, resolve_join(join, false))
+        .with_cap(cap.map_or(CapStyle::Butt, |cap| cap.0));
+    if path.is_f64() {
+        triangulate_path_edge_dashed_impl::<f64>(py, &path, &style, &dash, dash_phase)?
+            .into_py_any(py)
+    } else {
+        triangulate_path_edge_dashed_impl::<f32>(py, &path, &style, &dash, dash_phase)?
+            .into_py_any(py)
+    }
+}
+
+fn triangulate_path_edge_dashed_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    path: &PyCoords<'_>,
+    style: &PathStyle,
+    dash: &[f64],
+    dash_phase: f64,
+) -> PyDashedPathTriangulation<T> {
+    let path_ = numpy_path_to_rust_path::<T>(path);
+    let dash = DashPattern::new(
+        dash.iter().map(|&length| T::from_f64(length)).collect(),
+        T::from_f64(dash_phase),
     );
-    path_triangulation_to_numpy_arrays(py, &result)
+    let mut result = py.detach(|| triangulate_path_edge_dashed_rust(&path_, style, &dash));
+    let (centers, offsets, triangles) = path_triangulation_to_numpy_arrays(py, &mut result)?;
+    let arc_lengths = result.arc_lengths.unwrap_or_default();
+    Ok((
+        centers,
+        offsets,
+        triangles,
+        PyArray::from_vec(py, arc_lengths).into(),
+    ))
+}
+
+/// Convert 3D points into an Nx3 numpy array
+fn points_3d_to_numpy_array<T: NumpyFloat>(
+    py: Python<'_>,
+    points: Vec<[T; 3]>,
+) -> PyResult<Py<PyArray2<T>>> {
+    let len = points.len();
+    let flat_points: Vec<T> = points.into_iter().flatten().collect();
+    Ok(PyArray::from_vec(py, flat_points).reshape([len, 3])?.into())
+}
+
+/// Determines the ribbon triangulation of a path in 3D
+///
+/// Offsets of ribbon vertices depend on the view direction, so they are
+/// left to the renderer (usually a vertex shader). Each path point has two
+/// vertices, ``2 * i`` on side -1.0 and ``2 * i + 1`` on side 1.0. A
+/// camera-facing ribbon has vertices
+/// ``centers + sides * width / 2 * normalize(cross(tangents, view_direction))``,
+/// a ribbon with a fixed orientation ``centers + sides * width / 2 * normals``.
+///
+/// Parameters
+/// ----------
+/// path : np.ndarray
+///     Nx3 array of central coordinates of path to be triangulated
+/// closed : bool, optional (default=False)
+///     Bool which determines if the path is closed or not
+///
+/// Returns
+/// -------
+/// centers : np.ndarray
+///     (2N)x3 array of central coordinates of ribbon vertices
+/// tangents : np.ndarray
+///     (2N)x3 array of unit directions of the path at the vertices, at
+///     corners the average of directions of both segments
+/// normals : np.ndarray
+///     (2N)x3 array of unit vectors perpendicular to the tangents, which
+///     follow the path without twisting
+/// triangles : np.ndarray
+///     Mx3 array of the indices of the vertices that will form the
+///     triangles of the triangulation
+/// sides : np.ndarray
+///     2N array with the side of each vertex, -1.0 or 1.0
+#[pyfunction]
+#[pyo3(signature = (path, closed=false))]
+fn triangulate_path_ribbon_3d(
+    py: Python<'_>,
+    path: PyCoords<'_>,
+    closed: bool,
+) -> PyResult<Py<PyAny>> {
+    if path.is_f64() {
+        triangulate_path_ribbon_3d_impl::<f64>(py, &path, closed)?.into_py_any(py)
+    } else {
+        triangulate_path_ribbon_3d_impl::<f32>(py, &path, closed)?.into_py_any(py)
+    }
+}
+
+fn triangulate_path_ribbon_3d_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    path: &PyCoords<'_>,
+    closed: bool,
+) -> PyRibbonTriangulation<T> {
+    let path_ = path.to_points_3d::<T>();
+    let result = py.detach(|| triangulate_path_ribbon(&path_, closed));
+    Ok((
+        points_3d_to_numpy_array(py, result.centers)?,
+        points_3d_to_numpy_array(py, result.tangents)?,
+        points_3d_to_numpy_array(py, result.normals)?,
+        triangles_to_numpy_array(py, &result.triangles),
+        PyArray::from_vec(py, result.sides).into(),
+    ))
+}
+
+/// Determines the tube triangulation of a path in 3D
+///
+/// The resulting `offsets` can be multiplied by a `radius` scalar and be
+/// added to the resulting `centers` to generate the vertices of the tube,
+/// i.e. `vertices = centers + radius*offsets`. Triangles are oriented
+/// counter-clockwise when seen from outside the tube.
+///
+/// Parameters
+/// ----------
+/// path : np.ndarray
+///     Nx3 array of central coordinates of path to be triangulated
+/// closed : bool, optional (default=False)
+///     Bool which determines if the path is closed or not
+/// segments : int, optional (default=8)
+///     Number of vertices around the tube, at least 3
+/// limit : float, optional (default=3.0)
+///     Miter limit which determines when segments stop meeting in a single
+///     ring of vertices, and are connected with a bevel instead
+/// caps : bool, optional (default=False)
+///     If True, ends of an open tube are closed with flat disks
+///
+/// Returns
+/// -------
+/// centers : np.ndarray
+///     Mx3 array of central coordinates of tube vertices
+/// offsets : np.ndarray
+///     Mx3 array of the offsets to the central coordinates that need to
+///     be scaled by the tube radius and then added to the centers to
+///     generate the actual vertices of the triangulation
+/// triangles : np.ndarray
+///     Kx3 array of the indices of the vertices that will form the
+///     triangles of the triangulation
+#[pyfunction]
+#[pyo3(signature = (path, closed=false, segments=8, limit=3.0, caps=false))]
+fn triangulate_path_tube_3d(
+    py: Python<'_>,
+    path: PyCoords<'_>,
+    closed: bool,
+    segments: usize,
+    limit: f32,
+    caps: bool,
+) -> PyResult<Py<PyAny>> {
+    let style = TubeStyle::new(closed, segments)
+        .with_limit(limit)
+        .with_caps(caps);
+    if path.is_f64() {
+        triangulate_path_tube_3d_impl::<f64>(py, &path, &style)?.into_py_any(py)
+    } else {
+        triangulate_path_tube_3d_impl::<f32>(py, &path, &style)?.into_py_any(py)
+    }
+}
+
+fn triangulate_path_tube_3d_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    path: &PyCoords<'_>,
+    style: &TubeStyle,
+) -> PyEdgeTriangulation<T> {
+    let path_ = path.to_points_3d::<T>();
+    let result = py.detach(|| triangulate_path_tube(&path_, style));
+    Ok((
+        points_3d_to_numpy_array(py, result.centers)?,
+        points_3d_to_numpy_array(py, result.offsets)?,
+        triangles_to_numpy_array(py, &result.triangles),
+    ))
+}
+
+fn point_coords<T: Float>(p: Point<T>) -> Vec<f64> {
+    vec![p.x.to_f64(), p.y.to_f64()]
+}
+
+/// Convert triangulation error into Python `TriangulationError` exception
+///
+/// If error does not carry polygon index, it is determined by searching for the
+/// failing vertex in `polygons`. The `vertex_coords` function converts the vertex
+/// to coordinates reported to the user (to allow reporting 3D coordinates).
+fn triangulation_error_to_py_err<T: Float>(
+    py: Python<'_>,
+    err: TriangulationErrorRust<T>,
+    polygons: &[impl AsRef<[Point<T>]>],
+    vertex_coords: impl Fn(Point<T>) -> Vec<f64>,
+) -> PyErr {
+    let polygon_index = err.polygon_index().or_else(|| {
+        err.vertex().and_then(|vertex| {
+            polygons
+                .iter()
+                .position(|polygon| polygon.as_ref().contains(&vertex))
+        })
+    });
+    let vertex = err.vertex().map(vertex_coords);
+    let py_err = TriangulationError::new_err(err.to_string());
+    let value = py_err.value(py);
+    let set_result = value
+        .setattr("shape_index", err.shape_index())
+        .and_then(|_| value.setattr("polygon_index", polygon_index))
+        .and_then(|_| value.setattr("vertex", vertex));
+    match set_result {
+        Ok(()) => py_err,
+        Err(set_err) => set_err,
+    }
+}
+
+/// Convert arc lengths and sides of path vertices into numpy arrays
+fn path_attributes_to_numpy_arrays<T: NumpyFloat>(
+    py: Python<'_>,
+    data: PathTriangulation<T>,
+) -> (Py<PyArray1<T>>, Py<PyArray1<T>>) {
+    (
+        PyArray::from_vec(py, data.arc_lengths.unwrap_or_default()).into(),
+        PyArray::from_vec(py, data.sides.unwrap_or_default()).into(),
+    )
 }
 
 /// Convert internal representation of path triangulation into numpy arrays
-fn path_triangulation_to_numpy_arrays(
+///
+/// Vertices of `data` are moved into the arrays, its attributes are left in place.
+fn path_triangulation_to_numpy_arrays<T: NumpyFloat>(
     py: Python<'_>,
-    data: &PathTriangulation,
-) -> PyEdgeTriangulation {
+    data: &mut PathTriangulation<T>,
+) -> PyEdgeTriangulation<T> {
     let triangle_data: Vec<u32> = data
         .triangles
         .iter()
@@ -89,20 +951,70 @@ fn path_triangulation_to_numpy_arrays(
         PyArray2::<u32>::zeros(py, [0, 3], false)
     };
 
-    let flat_centers: Vec<f32> = data.centers.iter().flat_map(|p| [p.x, p.y]).collect();
-    let flat_offsets: Vec<f32> = data.offsets.iter().flat_map(|v| [v.x, v.y]).collect();
+    let (centers, offsets) = (
+        std::mem::take(&mut data.centers),
+        std::mem::take(&mut data.offsets),
+    );
+    let (centers_count, offsets_count) = (centers.len(), offsets.len());
 
     Ok((
-        PyArray::from_vec(py, flat_centers)
-            .reshape([data.centers.len(), 2])?
+        PyArray::from_vec(py, Point::vec_into_coords(centers))
+            .reshape([centers_count, 2])?
             .into(),
-        PyArray::from_vec(py, flat_offsets)
-            .reshape([data.offsets.len(), 2])?
+        PyArray::from_vec(py, Vector::vec_into_coords(offsets))
+            .reshape([offsets_count, 2])?
             .into(),
         triangle_array.into(),
     ))
 }
 
+/// Rows of vertex indices of triangles, as stored in numpy arrays.
+fn triangle_rows(triangles: &[Triangle]) -> impl ExactSizeIterator<Item = [u32; 3]> + '_ {
+    triangles
+        .iter()
+        .map(|t| [t.x as u32, t.y as u32, t.z as u32])
+}
+
+/// Write `rows` into the first rows of the preallocated array `out`,
+/// returning a view of these rows.
+///
+/// `out` has to be a writeable C-contiguous array of dtype `E` with `N` columns
+/// and at least as many rows as `rows`. `name` is used in error messages.
+fn write_rows<'py, E: Element + Copy, const N: usize>(
+    out: &Bound<'py, PyAny>,
+    rows: impl ExactSizeIterator<Item = [E; N]>,
+    name: &str,
+) -> PyResult<Py<PyAny>> {
+    let py = out.py();
+    let count = rows.len();
+    let array = out.cast::<PyArray2<E>>().map_err(|_| {
+        PyValueError::new_err(format!(
+            "Output array for `{name}` must be a 2D array of dtype {}",
+            numpy::dtype::<E>(py)
+        ))
+    })?;
+    let shape = array.shape();
+    if shape[1] != N || shape[0] < count {
+        return Err(PyValueError::new_err(format!(
+            "Output array for `{name}` must have shape (n, {N}) with n >= {count}, got {shape:?}"
+        )));
+    }
+    {
+        let mut writeable = array.try_readwrite().map_err(|err| {
+            PyValueError::new_err(format!("Output array for `{name}` is not writeable: {err}"))
+        })?;
+        let data = writeable.as_slice_mut().map_err(|_| {
+            PyValueError::new_err(format!("Output array for `{name}` must be C-contiguous"))
+        })?;
+        for (target, row) in data.chunks_exact_mut(N).zip(rows) {
+            target.copy_from_slice(&row);
+        }
+    }
+    Ok(out
+        .get_item(PySlice::new(py, 0, count as isize, 1))?
+        .unbind())
+}
+
 fn triangles_to_numpy_array(py: Python<'_>, triangles: &[Triangle]) -> Py<PyArray2<u32>> {
     let triangle_data: Vec<u32> = triangles
         .iter()
@@ -119,112 +1031,79 @@ fn triangles_to_numpy_array(py: Python<'_>, triangles: &[Triangle]) -> Py<PyArra
 }
 
 /// Convert internal representation of face triangulation into numpy arrays
-fn face_triangulation_to_numpy_arrays(
+fn face_triangulation_to_numpy_arrays<T: NumpyFloat>(
     py: Python<'_>,
     triangles: &[Triangle],
-    points: &[Point],
-) -> PyFaceTriangulation {
-    let flat_points: Vec<f32> = points.iter().flat_map(|p| [p.x, p.y]).collect();
+    points: Vec<Point<T>>,
+) -> PyFaceTriangulation<T> {
+    let count = points.len();
     Ok((
         triangles_to_numpy_array(py, triangles),
-        PyArray::from_vec(py, flat_points)
-            .reshape([points.len(), 2])?
+        PyArray::from_vec(py, Point::vec_into_coords(points))
+            .reshape([count, 2])?
             .into(),
     ))
 }
 
-fn numpy_polygons_to_rust_polygons(polygons: Vec<PyReadonlyArray2<'_, f32>>) -> Vec<Vec<Point>> {
-    let polygons_: Vec<Vec<Point>> = polygons
-        .into_iter()
+/// Convert the numpy arrays into rust compatible representations with consecutive
+/// duplicate points removed. Rings are borrowed from the arrays when possible
+/// and copied only if they have duplicates to remove.
+fn numpy_polygons_to_rust_polygons<'a, T: NumpyFloat>(
+    polygons: &'a [PyCoords<'_>],
+) -> Vec<Cow<'a, [Point<T>]>> {
+    polygons
+        .iter()
         .map(|polygon| {
-            let mut points: Vec<Point> = polygon
-                .as_array()
-                .rows()
-                .into_iter()
-                .map(|row| Point {
-                    x: row[0],
-                    y: row[1],
-                })
-                .collect();
-            points.dedup();
-            points
+            let points = numpy_path_to_rust_path(polygon);
+            if points.windows(2).any(|pair| pair[0] == pair[1]) {
+                let mut points = points.into_owned();
+                points.dedup();
+                Cow::Owned(points)
+            } else {
+                points
+            }
         })
-        .collect();
-    polygons_
+        .collect()
 }
 
-/// Projects 3D polygons onto a 2D plane by dropping the axis along which all points are collinear.
+/// Projects 3D polygons into 2D coordinates of the plane fitted to them.
 ///
-/// Determines which coordinate axis is constant across all points in all polygons, then projects each polygon onto the remaining two axes. Consecutive duplicate points are removed from each projected polygon.
+/// See [`project_to_plane`] for the plane fitting and the default `tolerance`.
 ///
 /// # Returns
 /// A tuple containing:
-/// - The projected 2D polygons as vectors of `Point`.
-/// - The index of the dropped axis (0 for x, 1 for y, 2 for z).
-/// - The constant value along the dropped axis.
-///
-/// If the input polygons are not coplanar (i.e., no axis is constant across all points), returns empty polygons, zero for the dropped axis, and zero for the dropped value.
-fn numpy_polygons_to_rust_polygons_3d(
-    polygons: Vec<PyReadonlyArray2<'_, f32>>,
-) -> (Vec<Vec<Point>>, usize, f32) {
-    let mut is_collinearity_axis = [true; 3];
-    let first_coordinates = [
-        polygons[0].as_array().row(0)[0],
-        polygons[0].as_array().row(0)[1],
-        polygons[0].as_array().row(0)[2],
-    ];
-
-    for polygon in &polygons {
-        let polygon_ = polygon.as_array();
-        for point in polygon_.rows() {
-            is_collinearity_axis[0] = is_collinearity_axis[0] && point[0] == first_coordinates[0];
-            is_collinearity_axis[1] = is_collinearity_axis[1] && point[1] == first_coordinates[1];
-            is_collinearity_axis[2] = is_collinearity_axis[2] && point[2] == first_coordinates[2];
-        }
-    }
-
-    let count_false = is_collinearity_axis.iter().filter(|&&x| !x).count();
-
-    if count_false != 2 {
-        // Either points are collinear against one of the axis or there
-        // is no hyperplane defined by axis that contains all points.
-        return (Vec::new(), 0, 0.0);
+/// - The projected 2D polygons, with consecutive duplicate points removed.
+/// - The fitted plane.
+/// - A mapping from projected points to the original 3D points.
+///
+/// # Errors
+/// Returns [`TriangulationErrorRust::NotPlanar`] if vertices are farther from
+/// the fitted plane than `tolerance`.
+fn numpy_polygons_to_rust_polygons_3d<T: Float>(
+    polygons: &[PyCoords<'_>],
+    tolerance: Option<T>,
+) -> Result<ProjectedPolygons3D<T>, TriangulationErrorRust<T>> {
+    let rings: Vec<Vec<[T; 3]>> = polygons.iter().map(PyCoords::to_points_3d).collect();
+    let (polygons_, plane) = project_to_plane(&rings, tolerance)?;
+    // projection of different points may be rounded to the same point, keep the first one
+    let mut originals = HashMap::new();
+    for p in rings.iter().flatten() {
+        let [x, y] = plane.project(p.map(T::to_f64));
+        originals
+            .entry(Point::new(T::from_f64(x), T::from_f64(y)))
+            .or_insert(*p);
     }
-
-    let false_positions: Vec<usize> = is_collinearity_axis
-        .iter()
-        .enumerate()
-        .filter(|(_, &value)| !value)
-        .map(|(index, _)| index)
-        .collect();
-
-    let drop_axis = is_collinearity_axis.iter().position(|&x| x).unwrap();
-    let drop_value = first_coordinates[drop_axis];
-
-    // Now false_positions contains [0, 2]
-    let pos1 = false_positions[0]; // 0
-    let pos2 = false_positions[1]; // 2
-
-    let polygons_: Vec<Vec<Point>> = polygons
-        .into_iter()
-        .map(|polygon| {
-            let mut points: Vec<Point> = polygon
-                .as_array()
-                .rows()
-                .into_iter()
-                .map(|row| Point {
-                    x: row[pos1],
-                    y: row[pos2],
-                })
-                .collect();
-            points.dedup();
-            points
-        })
-        .collect();
-    (polygons_, drop_axis, drop_value)
+    Ok((polygons_, plane, originals))
 }
 
-fn face_triangulate_single_polygon(polygon: &[Point]) -> Option<Vec<Triangle>> {
+fn face_triangulate_single_polygon<T: Float>(
+    polygon: &[Point<T>],
+    fill_rule: FillRule,
+) -> Option<Vec<Triangle>> {
+    // orientation of the polygon matters only for rules with a sign
+    if !matches!(fill_rule, FillRule::EvenOdd | FillRule::NonZero) {
+        return None;
+    }
     if polygon.len() < 3 {
         return Some(vec![Triangle::new(0, 0, 0)]);
     }
@@ -237,6 +1116,28 @@ fn face_triangulate_single_polygon(polygon: &[Point]) -> Option<Vec<Triangle>> {
     None
 }
 
+/// Flip edges of face triangulation, or refine it, to get triangles of the requested
+/// quality, `constraints` returns edges which have to be kept.
+fn apply_quality<T: Float>(
+    triangles: Vec<Triangle>,
+    points: Vec<Point<T>>,
+    constraints: impl FnOnce() -> Vec<Segment<T>>,
+    quality: TriangulationQuality,
+) -> (Vec<Triangle>, Vec<Point<T>>) {
+    match quality {
+        // degenerate triangle of polygons with less than 3 points is kept
+        _ if points.len() < 3 => (triangles, points),
+        TriangulationQuality::Fast => (triangles, points),
+        TriangulationQuality::Delaunay => {
+            let triangles = flip_to_constrained_delaunay(&triangles, &points, &constraints());
+            (triangles, points)
+        }
+        TriangulationQuality::Refined(refinement) => {
+            refine_delaunay(&triangles, &points, &constraints(), &refinement)
+        }
+    }
+}
+
 /// Triangulates multiple polygons and generates both face and edge triangulations
 ///
 /// This function performs two types of triangulation:
@@ -248,6 +1149,20 @@ fn face_triangulate_single_polygon(polygon: &[Point]) -> Option<Vec<Triangle>> {
 /// polygons : List[numpy.ndarray]
 ///     List of Nx2 arrays where each array contains the vertices of a polygon
 ///     as (x, y) coordinates. Each polygon should be defined in counter-clockwise order.
+/// fill_rule : str, optional
+///     Rule deciding which area of overlapping polygons is filled, one of
+///     'evenodd' (default), 'nonzero', 'positive' or 'negative'.
+/// quality : str, optional
+///     Shape of triangles of the face triangulation, 'fast' (default) for triangles
+///     of monotone polygons, 'delaunay' for a constrained Delaunay triangulation
+///     with fewer thin triangles, or 'refined' for a Delaunay triangulation with
+///     additional points satisfying `min_angle` and `max_area`.
+/// min_angle : float, optional
+///     Minimum angle of triangles in degrees for 'refined' quality, 20 by default,
+///     values above 30 are clamped. Small angles of polygons are kept.
+/// max_area : float, optional
+///     Maximum area of triangles for 'refined' quality, unlimited by default.
+///     Has to be positive.
 ///
 /// Returns
 /// -------
@@ -268,6 +1183,13 @@ fn face_triangulate_single_polygon(polygon: &[Point]) -> Option<Vec<Triangle>> {
 ///         - triangles : numpy.ndarray
 ///             Rx3 array of vertex indices for edge triangles
 ///
+/// Raises
+/// ------
+/// TriangulationError
+///     If the polygons cannot be triangulated, for example if a vertex is not
+///     finite or if edges still cross after 8 passes inserting their
+///     intersection points (rounded points may create new crossings).
+///
 /// Notes
 /// -----
 /// The function first processes any self-intersecting edges and repeated vertices,
@@ -277,31 +1199,62 @@ fn face_triangulate_single_polygon(polygon: &[Point]) -> Option<Vec<Triangle>> {
 /// The edge triangulation uses default parameters:
 /// - closed = true (treats polygons as closed)
 /// - miter_limit = 3.0
-/// - bevel = false (uses miter joins by default)
+/// - join = miter (switching to bevel when the miter limit is exceeded)
 #[pyfunction]
-#[pyo3(signature = (polygons))]
+#[pyo3(signature = (polygons, fill_rule=None, quality=None, min_angle=None, max_area=None))]
 fn triangulate_polygons_with_edge(
     py: Python<'_>,
-    polygons: Vec<PyReadonlyArray2<'_, f32>>,
-) -> PyPolygonTriangulation {
-    // Convert the numpy array into a rust compatible representation which is a vector of points.
-    let polygons_ = numpy_polygons_to_rust_polygons(polygons);
-    if polygons_.len() == 1 {
-        if let Some(result) = face_triangulate_single_polygon(&polygons_[0]) {
-            let path_triangulation = triangulate_paths_edge(&polygons_, true, 3.0, false);
-            return Ok((
-                face_triangulation_to_numpy_arrays(py, &result, &polygons_[0])?,
-                path_triangulation_to_numpy_arrays(py, &path_triangulation)?,
-            ));
-        }
+    polygons: Vec<PyCoords<'_>>,
+    fill_rule: Option<PyFillRule>,
+    quality: Option<PyTriangulationQuality>,
+    min_angle: Option<f64>,
+    max_area: Option<f64>,
+) -> PyResult<Py<PyAny>> {
+    let style = resolve_face_style(fill_rule, quality, min_angle, max_area)?;
+    if any_f64(&polygons) {
+        triangulate_polygons_with_edge_impl::<f64>(py, &polygons, &style)?.into_py_any(py)
+    } else {
+        triangulate_polygons_with_edge_impl::<f32>(py, &polygons, &style)?.into_py_any(py)
     }
+}
 
-    let (new_polygons, segments) = split_polygons_on_repeated_edges_rust(&polygons_);
-    let (face_triangles, face_points) = sweeping_line_triangulation(segments);
-    let path_triangulation = triangulate_paths_edge(&new_polygons, true, 3.0, false);
+fn triangulate_polygons_with_edge_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    polygons: &[PyCoords<'_>],
+    style: &FaceStyle,
+) -> PyPolygonTriangulation<T> {
+    // Convert the numpy array into a rust compatible representation which is a vector of points.
+    let polygons_ = numpy_polygons_to_rust_polygons::<T>(polygons);
+    let (face_triangles, face_points, mut path_triangulation) = py
+        .detach(|| {
+            if polygons_.len() == 1 {
+                if let Some(result) =
+                    face_triangulate_single_polygon(&polygons_[0], style.fill_rule)
+                {
+                    let (triangles, points) = apply_quality(
+                        result,
+                        polygons_[0].to_vec(),
+                        || calc_edges(&polygons_),
+                        style.quality,
+                    );
+                    let path_triangulation =
+                        triangulate_paths_edge_rust(&polygons_, true, 3.0, JoinStyle::Miter);
+                    return Ok((triangles, points, path_triangulation));
+                }
+            }
+            let (new_polygons, segments) =
+                split_polygons_with_fill_rule(&polygons_, style.fill_rule)?;
+            let (face_triangles, face_points) = sweeping_line_triangulation(segments.clone())?;
+            let (face_triangles, face_points) =
+                apply_quality(face_triangles, face_points, || segments, style.quality);
+            let path_triangulation =
+                triangulate_paths_edge_rust(&new_polygons, true, 3.0, JoinStyle::Miter);
+            Ok((face_triangles, face_points, path_triangulation))
+        })
+        .map_err(|err| triangulation_error_to_py_err(py, err, &polygons_, point_coords))?;
     Ok((
-        face_triangulation_to_numpy_arrays(py, &face_triangles, &face_points)?,
-        path_triangulation_to_numpy_arrays(py, &path_triangulation)?,
+        face_triangulation_to_numpy_arrays(py, &face_triangles, face_points)?,
+        path_triangulation_to_numpy_arrays(py, &mut path_triangulation)?,
     ))
 }
 
@@ -312,6 +1265,26 @@ fn triangulate_polygons_with_edge(
 /// polygons : List[numpy.ndarray]
 ///     List of Nx2 arrays where each array contains the vertices of a polygon
 ///     as (x, y) coordinates. Each polygon should be defined in counter-clockwise order.
+/// fill_rule : str, optional
+///     Rule deciding which area of overlapping polygons is filled, one of
+///     'evenodd' (default), 'nonzero', 'positive' or 'negative'.
+/// quality : str, optional
+///     Shape of triangles of the face triangulation, 'fast' (default) for triangles
+///     of monotone polygons, 'delaunay' for a constrained Delaunay triangulation
+///     with fewer thin triangles, or 'refined' for a Delaunay triangulation with
+///     additional points satisfying `min_angle` and `max_area`.
+/// min_angle : float, optional
+///     Minimum angle of triangles in degrees for 'refined' quality, 20 by default,
+///     values above 30 are clamped. Small angles of polygons are kept.
+/// max_area : float, optional
+///     Maximum area of triangles for 'refined' quality, unlimited by default.
+///     Has to be positive.
+/// out : tuple of numpy.ndarray, optional
+///     Preallocated arrays for `triangles` (uint32) and `points` (dtype of the
+///     results), with enough rows for the results. Results are computed first
+///     and then copied into their first rows, so `out` saves allocating the
+///     returned arrays but not the memory of the computation. Views of these
+///     rows are returned.
 ///
 /// Returns
 /// -------
@@ -322,6 +1295,13 @@ fn triangulate_polygons_with_edge(
 ///     - points : numpy.ndarray
 ///         Px2 array of vertex coordinates used in the triangulation
 ///
+/// Raises
+/// ------
+/// TriangulationError
+///     If the polygons cannot be triangulated, for example if a vertex is not
+///     finite or if edges still cross after 8 passes inserting their
+///     intersection points (rounded points may create new crossings).
+///
 /// Notes
 /// -----
 /// The function processes the input polygons by:
@@ -332,37 +1312,89 @@ fn triangulate_polygons_with_edge(
 /// The function returns only the face triangulation without edge triangulation,
 /// making it suitable for cases where only the interior triangulation is needed.
 #[pyfunction]
-#[pyo3(signature = (polygons))]
-fn triangulate_polygons_face(
-    py: Python<'_>,
-    polygons: Vec<PyReadonlyArray2<'_, f32>>,
-) -> PyFaceTriangulation {
+#[pyo3(signature = (
+    polygons, fill_rule=None, quality=None, min_angle=None, max_area=None, out=None
+))]
+fn triangulate_polygons_face<'py>(
+    py: Python<'py>,
+    polygons: Vec<PyCoords<'_>>,
+    fill_rule: Option<PyFillRule>,
+    quality: Option<PyTriangulationQuality>,
+    min_angle: Option<f64>,
+    max_area: Option<f64>,
+    out: Option<FaceOut<'py>>,
+) -> PyResult<Py<PyAny>> {
+    let style = resolve_face_style(fill_rule, quality, min_angle, max_area)?;
+    if any_f64(&polygons) {
+        triangulate_polygons_face_impl::<f64>(py, &polygons, &style, out.as_ref())
+    } else {
+        triangulate_polygons_face_impl::<f32>(py, &polygons, &style, out.as_ref())
+    }
+}
+
+fn triangulate_polygons_face_impl<'py, T: NumpyFloat>(
+    py: Python<'py>,
+    polygons: &[PyCoords<'_>],
+    style: &FaceStyle,
+    out: Option<&FaceOut<'py>>,
+) -> PyResult<Py<PyAny>> {
     // Convert the numpy array into a rust compatible representation which is a vector of points.
-    let polygons_ = numpy_polygons_to_rust_polygons(polygons);
+    let polygons_ = numpy_polygons_to_rust_polygons::<T>(polygons);
 
     if polygons_.len() == 1 {
-        if let Some(result) = face_triangulate_single_polygon(&polygons_[0]) {
-            return face_triangulation_to_numpy_arrays(py, &result, &polygons_[0]);
+        if let Some((triangles, points)) = py.detach(|| {
+            face_triangulate_single_polygon(&polygons_[0], style.fill_rule).map(|result| {
+                apply_quality(
+                    result,
+                    polygons_[0].to_vec(),
+                    || calc_edges(&polygons_),
+                    style.quality,
+                )
+            })
+        }) {
+            return face_triangulation_to_output(py, &triangles, points, out);
         }
     }
 
-    let (_new_polygons, segments) = split_polygons_on_repeated_edges_rust(&polygons_);
-    let (face_triangles, face_points) = sweeping_line_triangulation(segments);
-    face_triangulation_to_numpy_arrays(py, &face_triangles, &face_points)
+    let (face_triangles, face_points) = py
+        .detach(|| {
+            let (_new_polygons, segments) =
+                split_polygons_with_fill_rule(&polygons_, style.fill_rule)?;
+            let (triangles, points) = sweeping_line_triangulation(segments.clone())?;
+            Ok(apply_quality(triangles, points, || segments, style.quality))
+        })
+        .map_err(|err| triangulation_error_to_py_err(py, err, &polygons_, point_coords))?;
+    face_triangulation_to_output(py, &face_triangles, face_points, out)
+}
+
+/// Face triangulation as new numpy arrays, or written into `out` arrays when given.
+fn face_triangulation_to_output<'py, T: NumpyFloat>(
+    py: Python<'py>,
+    triangles: &[Triangle],
+    points: Vec<Point<T>>,
+    out: Option<&FaceOut<'py>>,
+) -> PyResult<Py<PyAny>> {
+    match out {
+        Some((triangles_out, points_out)) => (
+            write_rows(triangles_out, triangle_rows(triangles), "triangles")?,
+            write_rows(points_out, points.iter().map(|p| [p.x, p.y]), "points")?,
+        )
+            .into_py_any(py),
+        None => face_triangulation_to_numpy_arrays(py, triangles, points)?.into_py_any(py),
+    }
 }
 
-fn convert_rust_polygons_to_py_arrays(
+fn convert_rust_polygons_to_py_arrays<T: NumpyFloat>(
     py: Python<'_>,
-    polygons: Vec<Vec<Point>>,
-) -> PyResult<Vec<Py<PyArray2<f32>>>> {
+    polygons: Vec<Vec<Point<T>>>,
+) -> PyResult<Vec<Py<PyArray2<T>>>> {
     let mut py_arrays = Vec::with_capacity(polygons.len());
 
     for polygon in polygons {
         let num_points = polygon.len();
-        let flat_points: Vec<f32> = polygon.iter().flat_map(|p| [p.x, p.y]).collect();
 
-        // Create a PyArray from the flat vector
-        let array = PyArray::from_vec(py, flat_points);
+        // Create a PyArray taking over memory of the points
+        let array = PyArray::from_vec(py, Point::vec_into_coords(polygon));
 
         // Reshape the array to [num_points, 2]
         // The reshape operation returns a PyResult, so we use '?' to propagate errors
@@ -388,53 +1420,283 @@ fn convert_rust_polygons_to_py_arrays(
 /// List[numpy.ndarray]
 ///     A list of Mx2 arrays where each array represents a polygon after splitting
 ///     at repeated edges. Consecutive duplicate points are automatically removed.
+///
+/// Raises
+/// ------
+/// TriangulationError
+///     If a vertex is not finite, or if edges still cross after 8 passes
+///     inserting their intersection points (rounded points may create new
+///     crossings).
 #[pyfunction]
 #[pyo3(signature = (polygons))]
 fn split_polygons_on_repeated_edges(
     py: Python<'_>,
-    polygons: Vec<PyReadonlyArray2<'_, f32>>,
-) -> PyResult<Vec<Py<PyArray2<f32>>>> {
-    let polygons_ = numpy_polygons_to_rust_polygons(polygons);
-    let (new_polygons, _segments) = split_polygons_on_repeated_edges_rust(&polygons_);
+    polygons: Vec<PyCoords<'_>>,
+) -> PyResult<Py<PyAny>> {
+    if any_f64(&polygons) {
+        split_polygons_on_repeated_edges_impl::<f64>(py, &polygons)?.into_py_any(py)
+    } else {
+        split_polygons_on_repeated_edges_impl::<f32>(py, &polygons)?.into_py_any(py)
+    }
+}
+
+fn split_polygons_on_repeated_edges_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    polygons: &[PyCoords<'_>],
+) -> PyResult<Vec<Py<PyArray2<T>>>> {
+    let polygons_ = numpy_polygons_to_rust_polygons::<T>(polygons);
+    let (new_polygons, _segments) = py
+        .detach(|| split_polygons_on_repeated_edges_rust(&polygons_))
+        .map_err(|err| triangulation_error_to_py_err(py, err, &polygons_, point_coords))?;
     convert_rust_polygons_to_py_arrays(py, new_polygons)
 }
 
+/// Computes a boolean operation on two sets of polygons
+///
+/// Parameters
+/// ----------
+/// subject : List[numpy.ndarray]
+///     List of Nx2 arrays with rings of the first set of polygons.
+///     Rings are combined with the even-odd rule, so holes are given as separate rings.
+/// clip : List[numpy.ndarray]
+///     List of Nx2 arrays with rings of the second set of polygons.
+/// operation : str
+///     One of 'union', 'intersection', 'difference' (subject minus clip) or 'xor'.
+///
+/// Returns
+/// -------
+/// List[numpy.ndarray]
+///     A list of Mx2 arrays with rings of the result. Outer rings are
+///     counterclockwise and holes are clockwise.
+#[pyfunction]
+#[pyo3(signature = (subject, clip, operation))]
+fn polygon_boolean(
+    py: Python<'_>,
+    subject: Vec<PyCoords<'_>>,
+    clip: Vec<PyCoords<'_>>,
+    operation: PyBooleanOp,
+) -> PyResult<Py<PyAny>> {
+    if any_f64(&subject) || any_f64(&clip) {
+        polygon_boolean_impl::<f64>(py, &subject, &clip, operation.0)?.into_py_any(py)
+    } else {
+        polygon_boolean_impl::<f32>(py, &subject, &clip, operation.0)?.into_py_any(py)
+    }
+}
+
+fn polygon_boolean_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    subject: &[PyCoords<'_>],
+    clip: &[PyCoords<'_>],
+    operation: BooleanOp,
+) -> PyResult<Vec<Py<PyArray2<T>>>> {
+    let subject_ = numpy_polygons_to_rust_polygons::<T>(subject);
+    let clip_ = numpy_polygons_to_rust_polygons::<T>(clip);
+    let result = py
+        .detach(|| polygon_boolean_rust(&subject_, &clip_, operation))
+        .map_err(|err| {
+            let polygons_: Vec<_> = subject_.iter().chain(&clip_).cloned().collect();
+            triangulation_error_to_py_err(py, err, &polygons_, point_coords)
+        })?;
+    convert_rust_polygons_to_py_arrays(py, result)
+}
+
+/// Grows or shrinks polygons by a distance
+///
+/// Every ring is moved away from the filled area by `distance`, and overlaps
+/// of the moved rings are removed, so the result has no self-intersections.
+/// Holes shrink while polygons grow, and parts of polygons narrower than
+/// twice the distance vanish while they shrink.
+///
+/// Parameters
+/// ----------
+/// polygons : List[numpy.ndarray]
+///     List of Nx2 arrays with rings of the polygons.
+///     Rings are combined with the even-odd rule, so holes are given as separate rings.
+/// distance : float
+///     Offset distance, positive to grow and negative to shrink the polygons.
+///     Has to be finite.
+/// join : str, optional (default='miter')
+///     Shape of corners which move away from the polygon edges, one of
+///     'miter', 'bevel' or 'round'. Other corners stay sharp.
+/// limit : float, optional (default=3.0)
+///     Miter limit, the largest distance of a mitered corner from its vertex
+///     in units of `distance`, above which the corner is bevelled.
+///
+/// Returns
+/// -------
+/// List[numpy.ndarray]
+///     A list of Mx2 arrays with rings of the result. Outer rings are
+///     counterclockwise and holes are clockwise.
+///
+/// Raises
+/// ------
+/// ValueError
+///     If `distance` is NaN or infinite.
 #[pyfunction]
-#[pyo3(signature = (polygons))]
-fn triangulate_polygons_face_3d(
+#[pyo3(signature = (polygons, distance, join=None, limit=3.0))]
+fn offset_polygons(
     py: Python<'_>,
-    polygons: Vec<PyReadonlyArray2<'_, f32>>,
-) -> PyFaceTriangulation {
-    // Convert the numpy array into a rust compatible representation which is a vector of points.
-    let (polygons_, drop_axis, drop_value) = numpy_polygons_to_rust_polygons_3d(polygons);
-    let (face_triangles, face_points) = if polygons_.len() == 1 {
-        if let Some(result) = face_triangulate_single_polygon(&polygons_[0]) {
-            (result, polygons_[0].clone())
-        } else {
-            sweeping_line_triangulation(split_polygons_on_repeated_edges_rust(&polygons_).1)
-        }
+    polygons: Vec<PyCoords<'_>>,
+    distance: f64,
+    join: Option<PyJoinStyle>,
+    limit: f32,
+) -> PyResult<Py<PyAny>> {
+    if !distance.is_finite() {
+        return Err(PyValueError::new_err("distance must be finite"));
+    }
+    let style = OffsetStyle::new(resolve_join(join, false)).with_limit(limit);
+    if any_f64(&polygons) {
+        offset_polygons_impl::<f64>(py, &polygons, distance, &style)?.into_py_any(py)
     } else {
-        sweeping_line_triangulation(split_polygons_on_repeated_edges_rust(&polygons_).1)
-    };
+        offset_polygons_impl::<f32>(py, &polygons, distance, &style)?.into_py_any(py)
+    }
+}
 
-    let triangles = triangles_to_numpy_array(py, &face_triangles);
+fn offset_polygons_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    polygons: &[PyCoords<'_>],
+    distance: f64,
+    style: &OffsetStyle,
+) -> PyResult<Vec<Py<PyArray2<T>>>> {
+    let polygons_ = numpy_polygons_to_rust_polygons::<T>(polygons);
+    let result = py
+        .detach(|| offset_polygons_rust(&polygons_, T::from_f64(distance), style))
+        .map_err(|err| triangulation_error_to_py_err(py, err, &polygons_, point_coords))?;
+    convert_rust_polygons_to_py_arrays(py, result)
+}
 
-    let flat_points: Vec<f32> = if drop_axis == 0 {
-        face_points
-            .iter()
-            .flat_map(|p| [drop_value, p.x, p.y])
-            .collect()
-    } else if drop_axis == 1 {
-        face_points
-            .iter()
-            .flat_map(|p| [p.x, drop_value, p.y])
-            .collect()
+/// Simplifies rings of polygons, removing vertices which do not change their shape
+///
+/// Parameters
+/// ----------
+/// polygons : List[numpy.ndarray]
+///     List of Nx2 arrays with closed rings of the polygons.
+/// tolerance : float
+///     For 'douglas-peucker' the largest distance of a removed vertex from the
+///     simplified ring, for 'visvalingam-whyatt' the largest area of a triangle,
+///     formed by a vertex and its neighbors, for which the vertex is removed.
+/// method : str, optional (default='douglas-peucker')
+///     One of 'douglas-peucker' or 'visvalingam-whyatt'.
+/// preserve_topology : bool, optional (default=False)
+///     If True, removed vertices are restored where simplified edges would
+///     cross each other, so rings cross neither themselves nor each other
+///     unless the input rings do.
+///
+/// Returns
+/// -------
+/// List[numpy.ndarray]
+///     A list of Mx2 arrays with the kept vertices of each ring, in their
+///     original order. Rings of at least three vertices keep three or more of them.
+#[pyfunction]
+#[pyo3(signature = (polygons, tolerance, method=None, preserve_topology=false))]
+fn simplify_polygons(
+    py: Python<'_>,
+    polygons: Vec<PyCoords<'_>>,
+    tolerance: f64,
+    method: Option<PySimplifyMethod>,
+    preserve_topology: bool,
+) -> PyResult<Py<PyAny>> {
+    let style =
+        SimplifyStyle::new(method.map_or(SimplifyMethod::DouglasPeucker, |method| method.0))
+            .with_preserve_topology(preserve_topology);
+    if any_f64(&polygons) {
+        simplify_polygons_impl::<f64>(py, &polygons, tolerance, &style)?.into_py_any(py)
     } else {
-        face_points
-            .iter()
-            .flat_map(|p| [p.x, p.y, drop_value])
-            .collect()
+        simplify_polygons_impl::<f32>(py, &polygons, tolerance, &style)?.into_py_any(py)
+    }
+}
+
+fn simplify_polygons_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    polygons: &[PyCoords<'_>],
+    tolerance: f64,
+    style: &SimplifyStyle,
+) -> PyResult<Vec<Py<PyArray2<T>>>> {
+    let polygons_ = numpy_polygons_to_rust_polygons::<T>(polygons);
+    let result = py.detach(|| simplify_polygons_rust(&polygons_, T::from_f64(tolerance), style));
+    convert_rust_polygons_to_py_arrays(py, result)
+}
+
+/// Performs face triangulation of planar polygons in 3D
+///
+/// Polygons may lie on any plane, not only on planes perpendicular to a coordinate
+/// axis. The plane is fitted to all vertices with Newell's method, polygons are
+/// triangulated in 2D coordinates of the plane and the result is mapped back to 3D.
+///
+/// Parameters
+/// ----------
+/// polygons : List[numpy.ndarray]
+///     List of Nx3 arrays where each array contains the vertices of a polygon
+///     as (x, y, z) coordinates. All polygons have to lie on a common plane.
+/// tolerance : float, optional
+///     Largest allowed distance of a vertex from the fitted plane. By default
+///     1e-6 of the bounding box diagonal, plus the rounding error of coordinates.
+///
+/// Returns
+/// -------
+/// tuple
+///     A tuple containing two elements:
+///     - triangles : numpy.ndarray
+///         Mx3 array of vertex indices that form the triangulation
+///     - points : numpy.ndarray
+///         Px3 array of vertex coordinates used in the triangulation. Vertices of
+///         the polygons keep their coordinates, new vertices (like intersections
+///         of edges) lie on the fitted plane.
+///
+/// Raises
+/// ------
+/// TriangulationError
+///     If the polygons are not planar within `tolerance`, the message contains
+///     the largest distance of a vertex from the fitted plane.
+#[pyfunction]
+#[pyo3(signature = (polygons, tolerance=None))]
+fn triangulate_polygons_face_3d(
+    py: Python<'_>,
+    polygons: Vec<PyCoords<'_>>,
+    tolerance: Option<f64>,
+) -> PyResult<Py<PyAny>> {
+    if any_f64(&polygons) {
+        triangulate_polygons_face_3d_impl::<f64>(py, &polygons, tolerance)?.into_py_any(py)
+    } else {
+        triangulate_polygons_face_3d_impl::<f32>(py, &polygons, tolerance)?.into_py_any(py)
+    }
+}
+
+fn triangulate_polygons_face_3d_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    polygons: &[PyCoords<'_>],
+    tolerance: Option<f64>,
+) -> PyFaceTriangulation<T> {
+    // Convert the numpy arrays into polygons in 2D coordinates of their plane.
+    let (polygons_, plane, originals) =
+        numpy_polygons_to_rust_polygons_3d::<T>(polygons, tolerance.map(T::from_f64)).map_err(
+            |err| triangulation_error_to_py_err(py, err, &[] as &[Vec<Point<T>>], point_coords),
+        )?;
+    let to_3d = |p: Point<T>| match originals.get(&p) {
+        Some(original) => *original,
+        None => plane
+            .unproject([p.x.to_f64(), p.y.to_f64()])
+            .map(T::from_f64),
     };
+    let (face_triangles, face_points) = py
+        .detach(|| {
+            let single_polygon_result = if polygons_.len() == 1 {
+                face_triangulate_single_polygon(&polygons_[0], FillRule::EvenOdd)
+            } else {
+                None
+            };
+            match single_polygon_result {
+                Some(result) => Ok((result, polygons_[0].clone())),
+                None => split_polygons_on_repeated_edges_rust(&polygons_)
+                    .and_then(|(_new_polygons, segments)| sweeping_line_triangulation(segments)),
+            }
+        })
+        .map_err(|err| {
+            triangulation_error_to_py_err(py, err, &polygons_, |p| to_3d(p).map(T::to_f64).to_vec())
+        })?;
+
+    let triangles = triangles_to_numpy_array(py, &face_triangles);
+    let flat_points: Vec<T> = face_points.iter().flat_map(|&p| to_3d(p)).collect();
 
     Ok((
         triangles,
@@ -444,12 +1706,485 @@ fn triangulate_polygons_face_3d(
     ))
 }
 
+/// Determines the triangulation of multiple paths in 2D
+///
+/// Each path may use its own `closed`, `limit`, `bevel`, `join` and `cap` settings.
+///
+/// Parameters
+/// ----------
+/// paths : List[np.ndarray]
+///     List of Nx2 arrays of central coordinates of paths to be triangulated
+/// closed : bool or Sequence[bool], optional (default=False)
+///     Bool which determines if the path is closed or not, either one value
+///     for all paths or a sequence with a value for each path
+/// limit : float or Sequence[float], optional (default=3.0)
+///     Miter limit which determines when to switch from a miter join to a
+///     bevel join, either one value for all paths or a sequence with a value for each path
+/// bevel : bool or Sequence[bool], optional (default=False)
+///     Bool which if True causes a bevel join to always be used, either one
+///     value for all paths or a sequence with a value for each path.
+///     Kept for backward compatibility, equivalent to ``join='bevel'``
+/// join : str or Sequence[str], optional (default='miter')
+///     Shape of path corners, one of 'miter', 'bevel' or 'round', either one
+///     value for all paths or a sequence with a value for each path.
+///     Takes precedence over `bevel` when given
+/// cap : str or Sequence[str], optional (default='butt')
+///     Shape of ends of open paths, one of 'butt', 'square' or 'round',
+///     either one value for all paths or a sequence with a value for each path
+/// attributes : bool, optional (default=False)
+///     If True, also return per-vertex `arc_lengths` and `sides`
+///
+/// Returns
+/// -------
+/// centers : np.ndarray
+///     Mx2 array central coordinates of triangles of all paths.
+/// offsets : np.ndarray
+///     Mx2 array of the offsets to the central coordinates that need to
+///     be scaled by the line width and then added to the centers to
+///     generate the actual vertices of the triangulation
+/// triangles : np.ndarray
+///     Kx3 array of the indices of the vertices that will form the
+///     triangles of the triangulation
+/// path_ranges : np.ndarray
+///     Px2 array, where P is the number of paths. Row i contains start
+///     (inclusive) and stop (exclusive) index of vertices of path i
+///     in `centers` and `offsets`
+/// arc_lengths : np.ndarray
+///     M array of distances along each path from its start to the vertex
+///     centers, returned only if `attributes` is True
+/// sides : np.ndarray
+///     M array with 1.0 for vertices on the left side of their path, -1.0
+///     for vertices on the right side and 0.0 for vertices on the path,
+///     returned only if `attributes` is True
+#[pyfunction]
+#[pyo3(signature = (
+    paths, closed=None, limit=None, bevel=None, cap=None, join=None, attributes=false
+))]
+#[allow(clippy::too_many_arguments)]
+fn triangulate_paths_edge(
+    py: Python<'_>,
+    paths: Vec<PyCoords<'_>>,
+    closed: Option<PerElement<bool>>,
+    limit: Option<PerElement<f32>>,
+    bevel: Option<PerElement<bool>>,
+    cap: Option<PerElement<PyCapStyle>>,
+    join: Option<PerElement<PyJoinStyle>>,
+    attributes: bool,
+) -> PyResult<Py<PyAny>> {
+    let count = paths.len();
+    let closed = closed
+        .unwrap_or(PerElement::Single(false))
+        .expand(count, "closed")?;
+    let limit = limit
+        .unwrap_or(PerElement::Single(3.0))
+        .expand(count, "limit")?;
+    let bevel = bevel
+        .unwrap_or(PerElement::Single(false))
+        .expand(count, "bevel")?;
+    let cap = cap
+        .unwrap_or(PerElement::Single(PyCapStyle(CapStyle::Butt)))
+        .expand(count, "cap")?;
+    let join = join.map(|join| join.expand(count, "join")).transpose()?;
+    let styles: Vec<PathStyle> = (0..count)
+        .map(|i| {
+            let join = resolve_join(join.as_ref().map(|join| join[i]), bevel[i]);
+            PathStyle::new(closed[i], limit[i], join)
+                .with_cap(cap[i].0)
+                .with_attributes(attributes)
+        })
+        .collect();
+    if any_f64(&paths) {
+        triangulate_paths_edge_impl::<f64>(py, &paths, &styles)
+    } else {
+        triangulate_paths_edge_impl::<f32>(py, &paths, &styles)
+    }
+}
+
+fn triangulate_paths_edge_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    paths: &[PyCoords<'_>],
+    styles: &[PathStyle],
+) -> PyResult<Py<PyAny>> {
+    let count = paths.len();
+    let paths_ = numpy_polygons_to_rust_polygons::<T>(paths);
+
+    let (mut result, vertex_offsets) = py.detach(|| triangulate_paths_edge_styled(&paths_, styles));
+    let (centers, offsets, triangles) = path_triangulation_to_numpy_arrays(py, &mut result)?;
+    let flat_ranges: Vec<u32> = vertex_offsets
+        .windows(2)
+        .flat_map(|range| [range[0] as u32, range[1] as u32])
+        .collect();
+    let path_ranges: Py<PyArray2<u32>> = PyArray::from_vec(py, flat_ranges)
+        .reshape([count, 2])?
+        .into();
+    if styles.iter().any(|style| style.attributes) {
+        let (arc_lengths, sides) = path_attributes_to_numpy_arrays(py, result);
+        Ok(
+            (centers, offsets, triangles, path_ranges, arc_lengths, sides)
+                .into_pyobject(py)?
+                .into_any()
+                .unbind(),
+        )
+    } else {
+        Ok((centers, offsets, triangles, path_ranges)
+            .into_pyobject(py)?
+            .into_any()
+            .unbind())
+    }
+}
+
+/// Convert internal representation of multiple shapes triangulation into numpy arrays
+fn shapes_triangulation_to_numpy_arrays<T: NumpyFloat>(
+    py: Python<'_>,
+    data: ShapesTriangulation<T>,
+) -> PyShapesTriangulation<T> {
+    let points_count = data.points.len();
+    let flat_offsets: Vec<u32> = data
+        .vertex_offsets
+        .iter()
+        .zip(data.triangle_offsets.iter())
+        .flat_map(|(&vertex, &triangle)| [vertex as u32, triangle as u32])
+        .collect();
+    Ok((
+        triangles_to_numpy_array(py, &data.triangles),
+        PyArray::from_vec(py, Point::vec_into_coords(data.points))
+            .reshape([points_count, 2])?
+            .into(),
+        PyArray::from_vec(py, flat_offsets)
+            .reshape([data.vertex_offsets.len(), 2])?
+            .into(),
+    ))
+}
+
+/// Performs face triangulation of multiple independent shapes
+///
+/// In contrast to `triangulate_polygons_face`, each shape is triangulated
+/// separately, so overlapping shapes do not create holes in each other.
+///
+/// Parameters
+/// ----------
+/// shapes : List[numpy.ndarray | List[numpy.ndarray]]
+///     List of shapes. Each shape is either a Nx2 array with polygon vertices
+///     or a list of such arrays, where the first is the outer boundary and
+///     the remaining are holes (rings are combined with even-odd rule).
+///
+/// Returns
+/// -------
+/// tuple
+///     A tuple containing three elements:
+///     - triangles : numpy.ndarray
+///         Mx3 array of vertex indices into the concatenated points array
+///     - points : numpy.ndarray
+///         Px2 array of vertex coordinates of all shapes
+///     - offsets : numpy.ndarray
+///         (S+1)x2 array, where S is the number of shapes. Row i contains
+///         the index of the first vertex and the first triangle of shape i,
+///         the last row contains the total number of vertices and triangles.
+///         Vertices of shape i are ``points[offsets[i, 0]:offsets[i + 1, 0]]``.
+///
+/// Raises
+/// ------
+/// TriangulationError
+///     If any shape cannot be triangulated. The `shape_index` attribute
+///     contains the index of the failing shape.
+#[pyfunction]
+#[pyo3(signature = (shapes))]
+fn triangulate_shapes_face(py: Python<'_>, shapes: Vec<PyShape<'_>>) -> PyResult<Py<PyAny>> {
+    if shapes.iter().any(|shape| any_f64(shape.rings())) {
+        triangulate_shapes_face_impl::<f64>(py, &shapes)?.into_py_any(py)
+    } else {
+        triangulate_shapes_face_impl::<f32>(py, &shapes)?.into_py_any(py)
+    }
+}
+
+fn triangulate_shapes_face_impl<T: NumpyFloat>(
+    py: Python<'_>,
+    shapes: &[PyShape<'_>],
+) -> PyShapesTriangulation<T> {
+    let shapes_: Vec<Vec<Vec<Point<T>>>> = shapes.iter().map(PyShape::to_rust_polygons).collect();
+    let result = py
+        .detach(|| triangulate_shapes_face_rust(&shapes_))
+        .map_err(|err| {
+            let rings = err
+                .shape_index()
+                .map_or(&[][..], |index| shapes_[index].as_slice());
+            triangulation_error_to_py_err(py, err, rings, point_coords)
+        })?;
+    shapes_triangulation_to_numpy_arrays(py, result)
+}
+
+/// Extracts contours of labeled regions of a 2D label image with marching squares
+///
+/// Points are given in (row, column) order, pixel ``labels[i, j]`` lies at point
+/// ``(i, j)``. Contours pass through midpoints between pixels of a label and their
+/// neighbors, like contours at level 0.5 of the label mask in scikit-image.
+/// Pixels touching only diagonally belong to separate regions.
+///
+/// Parameters
+/// ----------
+/// labels : numpy.ndarray
+///     2D array of boolean or integer dtype with the label of each pixel.
+/// background : int or bool, optional (default=0)
+///     Label of pixels which are not part of any region.
+///
+/// Returns
+/// -------
+/// Dict[int, List[numpy.ndarray]]
+///     For each label present in the image, a list of Mx2 float32 arrays with
+///     rings of its contours. Outer boundaries are counterclockwise and
+///     boundaries of holes are clockwise, so they can be passed to
+///     `triangulate_polygons_face`.
+#[pyfunction]
+#[pyo3(signature = (labels, background=None))]
+fn label_contours<'py>(
+    py: Python<'py>,
+    labels: PyLabels<'py>,
+    background: Option<Bound<'py, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    with_labels!(labels, array => {
+        let contours = contours_of_labels(&array, background.as_ref(), false)?;
+        contours
+            .into_iter()
+            .map(|(label, rings)| Ok((label, convert_rust_polygons_to_py_arrays(py, rings)?)))
+            .collect::<PyResult<BTreeMap<_, _>>>()?
+            .into_py_any(py)
+    })
+}
+
+/// Traces pixel-exact boundaries of labeled regions of a 2D label image
+///
+/// Points are given in (row, column) order, pixel ``labels[i, j]`` is the unit
+/// square centered at point ``(i, j)``. Rings follow the pixel edges, so they
+/// enclose exactly the pixels of each label, and only have vertices at corners.
+/// Pixels touching only diagonally belong to separate rings.
+///
+/// Parameters
+/// ----------
+/// labels : numpy.ndarray
+///     2D array of boolean or integer dtype with the label of each pixel.
+/// background : int or bool, optional (default=0)
+///     Label of pixels which are not part of any region.
+///
+/// Returns
+/// -------
+/// Dict[int, List[numpy.ndarray]]
+///     For each label present in the image, a list of Mx2 float32 arrays with
+///     rectilinear rings of its boundaries. Outer boundaries are counterclockwise
+///     and boundaries of holes are clockwise.
+#[pyfunction]
+#[pyo3(signature = (labels, background=None))]
+fn trace_label_boundaries<'py>(
+    py: Python<'py>,
+    labels: PyLabels<'py>,
+    background: Option<Bound<'py, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    with_labels!(labels, array => {
+        let boundaries = contours_of_labels(&array, background.as_ref(), true)?;
+        boundaries
+            .into_iter()
+            .map(|(label, rings)| Ok((label, convert_rust_polygons_to_py_arrays(py, rings)?)))
+            .collect::<PyResult<BTreeMap<_, _>>>()?
+            .into_py_any(py)
+    })
+}
+
+/// Performs face triangulation of labeled regions of a 2D label image
+///
+/// Contours of each label are extracted as in `label_contours` and triangulated
+/// as one shape, as in `triangulate_shapes_face`, without creating Python arrays
+/// for the contours. With `exact`, pixel boundaries are traced as in
+/// `trace_label_boundaries` instead, and triangulated by merging pixels into
+/// rectangles, which is faster than the general triangulation.
+///
+/// Parameters
+/// ----------
+/// labels : numpy.ndarray
+///     2D array of boolean or integer dtype with the label of each pixel.
+/// background : int or bool, optional (default=0)
+///     Label of pixels which are not part of any region.
+/// exact : bool, optional (default=False)
+///     Whether to triangulate the pixels of each label exactly, instead of
+///     their marching squares contours.
+///
+/// Returns
+/// -------
+/// tuple
+///     A tuple containing four elements:
+///     - label_values : numpy.ndarray
+///         Array of S labels present in the image, in increasing order,
+///         with the dtype of `labels`
+///     - triangles : numpy.ndarray
+///         Mx3 array of vertex indices into the concatenated points array
+///     - points : numpy.ndarray
+///         Px2 float32 array of vertex coordinates of all labels, in (row, column) order
+///     - offsets : numpy.ndarray
+///         (S+1)x2 array, row i contains the index of the first vertex and the
+///         first triangle of label ``label_values[i]``, the last row contains the
+///         total number of vertices and triangles.
+///
+/// Raises
+/// ------
+/// TriangulationError
+///     If the region of any label cannot be triangulated. The `shape_index`
+///     attribute contains the index of the failing label in `label_values`.
+#[pyfunction]
+#[pyo3(signature = (labels, background=None, exact=false))]
+fn triangulate_labels_face<'py>(
+    py: Python<'py>,
+    labels: PyLabels<'py>,
+    background: Option<Bound<'py, PyAny>>,
+    exact: bool,
+) -> PyResult<Py<PyAny>> {
+    with_labels!(labels, array => {
+        let contours = contours_of_labels(&array, background.as_ref(), exact)?;
+        let (label_values, shapes): (Vec<_>, Vec<_>) = contours.into_iter().unzip();
+        let result = py
+            .detach(|| {
+                if !exact {
+                    return triangulate_shapes_face_rust(&shapes);
+                }
+                let mut result = ShapesTriangulation::new();
+                for (index, shape) in shapes.iter().enumerate() {
+                    let (triangles, points) =
+                        triangulate_rectilinear_face(shape).map_err(|err| err.in_shape(index))?;
+                    result.push_shape(triangles, points);
+                }
+                Ok(result)
+            })
+            .map_err(|err| {
+                let rings = err
+                    .shape_index()
+                    .map_or(&[][..], |index| shapes[index].as_slice());
+                triangulation_error_to_py_err(py, err, rings, point_coords)
+            })?;
+        let (triangles, points, offsets) = shapes_triangulation_to_numpy_arrays(py, result)?;
+        (PyArray1::from_vec(py, label_values), triangles, points, offsets).into_py_any(py)
+    })
+}
+
+/// Fills pixels of a 2D label image covered by polygons with a label, in place
+///
+/// Points are given in (row, column) order, pixel ``image[i, j]`` is the unit
+/// square centered at point ``(i, j)``, as in `label_contours`. Polygons are
+/// combined using the even-odd rule, like in `triangulate_polygons_face`.
+///
+/// Parameters
+/// ----------
+/// polygons : List[numpy.ndarray]
+///     List of Nx2 arrays with rings of the polygons.
+/// image : numpy.ndarray
+///     Writeable C-contiguous 2D array of boolean or integer dtype. Pixels not
+///     covered by the polygons keep their values.
+/// label : int or bool
+///     Value written to covered pixels.
+/// mode : str, optional (default='pixel-center')
+///     One of 'pixel-center', to fill pixels whose center lies inside the polygons,
+///     or 'any-touch', to fill pixels which overlap the polygons by any area.
+#[pyfunction]
+#[pyo3(signature = (polygons, image, label, mode=None))]
+fn rasterize_polygons<'py>(
+    py: Python<'py>,
+    polygons: Vec<PyCoords<'py>>,
+    image: PyLabelsMut<'py>,
+    label: Bound<'py, PyAny>,
+    mode: Option<PyRasterMode>,
+) -> PyResult<()> {
+    let shapes = [PyShape::Rings(polygons)];
+    with_labels!(mut image, array => rasterize_into(py, array, &shapes, &[label], mode))
+}
+
+/// Fills pixels of a 2D label image covered by each shape with its label, in place
+///
+/// Each shape is rasterized as in `rasterize_polygons`, in order, so later shapes
+/// overwrite pixels of earlier ones where they overlap.
+///
+/// Parameters
+/// ----------
+/// shapes : List[Union[numpy.ndarray, List[numpy.ndarray]]]
+///     List of shapes, each given as an Nx2 array with a single polygon or as a list
+///     of Nx2 arrays with its rings.
+/// labels : Sequence[int] or numpy.ndarray
+///     Label of each shape.
+/// image : numpy.ndarray
+///     Writeable C-contiguous 2D array of boolean or integer dtype. Pixels not
+///     covered by any shape keep their values.
+/// mode : str, optional (default='pixel-center')
+///     One of 'pixel-center', to fill pixels whose center lies inside a shape,
+///     or 'any-touch', to fill pixels which overlap a shape by any area.
+#[pyfunction]
+#[pyo3(signature = (shapes, labels, image, mode=None))]
+fn rasterize_shapes<'py>(
+    py: Python<'py>,
+    shapes: Vec<PyShape<'py>>,
+    labels: Vec<Bound<'py, PyAny>>,
+    image: PyLabelsMut<'py>,
+    mode: Option<PyRasterMode>,
+) -> PyResult<()> {
+    with_labels!(mut image, array => rasterize_into(py, array, &shapes, &labels, mode))
+}
+
+/// Rasterize `shapes` into `image`, converting `labels` to its dtype.
+fn rasterize_into<'py, L>(
+    py: Python<'py>,
+    mut image: PyReadwriteArray2<'py, L>,
+    shapes: &[PyShape<'_>],
+    labels: &[Bound<'py, PyAny>],
+    mode: Option<PyRasterMode>,
+) -> PyResult<()>
+where
+    L: NumpyLabel + for<'a> FromPyObject<'a, 'py>,
+{
+    if labels.len() != shapes.len() {
+        return Err(PyValueError::new_err(format!(
+            "Expected {} labels, one for each shape, got {}",
+            shapes.len(),
+            labels.len()
+        )));
+    }
+    let labels = labels
+        .iter()
+        .map(|label| label.extract::<L>().map_err(Into::into))
+        .collect::<PyResult<Vec<L>>>()?;
+    let mode = mode.map_or(RasterMode::PixelCenter, |mode| mode.0);
+    let shape = [image.shape()[0], image.shape()[1]];
+    let pixels = image
+        .as_slice_mut()
+        .map_err(|_| PyValueError::new_err("Array `image` must be C-contiguous"))?;
+    if shapes.iter().any(|shape| any_f64(shape.rings())) {
+        let shapes_: Vec<Vec<Vec<Point<f64>>>> =
+            shapes.iter().map(PyShape::to_rust_polygons).collect();
+        py.detach(|| rasterize_shapes_rust(&shapes_, &labels, pixels, shape, mode));
+    } else {
+        let shapes_: Vec<Vec<Vec<Point<f32>>>> =
+            shapes.iter().map(PyShape::to_rust_polygons).collect();
+        py.detach(|| rasterize_shapes_rust(&shapes_, &labels, pixels, shape, mode));
+    }
+    Ok(())
+}
+
 #[pymodule]
 fn _bermuda(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(triangulate_path_edge, m)?)?;
+    m.add_function(wrap_pyfunction!(triangulate_path_edge_dashed, m)?)?;
+    m.add_function(wrap_pyfunction!(triangulate_path_ribbon_3d, m)?)?;
+    m.add_function(wrap_pyfunction!(triangulate_path_tube_3d, m)?)?;
     m.add_function(wrap_pyfunction!(triangulate_polygons_with_edge, m)?)?;
     m.add_function(wrap_pyfunction!(triangulate_polygons_face, m)?)?;
     m.add_function(wrap_pyfunction!(triangulate_polygons_face_3d, m)?)?;
     m.add_function(wrap_pyfunction!(split_polygons_on_repeated_edges, m)?)?;
+    m.add_function(wrap_pyfunction!(polygon_boolean, m)?)?;
+    m.add_function(wrap_pyfunction!(offset_polygons, m)?)?;
+    m.add_function(wrap_pyfunction!(simplify_polygons, m)?)?;
+    m.add_function(wrap_pyfunction!(triangulate_shapes_face, m)?)?;
+    m.add_function(wrap_pyfunction!(label_contours, m)?)?;
+    m.add_function(wrap_pyfunction!(trace_label_boundaries, m)?)?;
+    m.add_function(wrap_pyfunction!(triangulate_labels_face, m)?)?;
+    m.add_function(wrap_pyfunction!(triangulate_paths_edge, m)?)?;
+    m.add_function(wrap_pyfunction!(rasterize_polygons, m)?)?;
+    m.add_function(wrap_pyfunction!(rasterize_shapes, m)?)?;
+    m.add(
+        "TriangulationError",
+        m.py().get_type::<TriangulationError>(),
+    )?;
     Ok(())
 }
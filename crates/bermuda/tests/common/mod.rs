@@ -0,0 +1,33 @@
+//! Fixtures shared by integration tests.
+
+#![allow(dead_code)]
+
+use triangulation::point::{Float, Point};
+
+/// Counterclockwise rectangle with the lower left corner at `(x, y)`.
+pub fn rectangle<T: Float>(x: T, y: T, width: T, height: T) -> Vec<Point<T>> {
+    vec![
+        Point::new(x, y),
+        Point::new(x + width, y),
+        Point::new(x + width, y + height),
+        Point::new(x, y + height),
+    ]
+}
+
+/// Signed area of the rings, positive for counterclockwise ones.
+///
+/// It is the area of the polygon when holes are clockwise.
+pub fn area<T: Float>(rings: &[Vec<Point<T>>]) -> f64 {
+    rings
+        .iter()
+        .map(|ring| {
+            (0..ring.len())
+                .map(|i| {
+                    let (p, q) = (ring[i], ring[(i + 1) % ring.len()]);
+                    p.x.to_f64() * q.y.to_f64() - q.x.to_f64() * p.y.to_f64()
+                })
+                .sum::<f64>()
+                / 2.0
+        })
+        .sum()
+}
@@ -0,0 +1,182 @@
+mod common;
+
+use common::{area, rectangle};
+use rstest::rstest;
+use triangulation::boolean::{polygon_boolean, BooleanOp};
+use triangulation::intersection::{calc_edges, find_intersections};
+use triangulation::point::Point;
+
+#[rstest]
+#[case::union(BooleanOp::Union, 7.0, 1)]
+#[case::intersection(BooleanOp::Intersection, 1.0, 1)]
+#[case::difference(BooleanOp::Difference, 3.0, 1)]
+#[case::xor(BooleanOp::Xor, 6.0, 2)]
+fn test_polygon_boolean_overlapping_squares(
+    #[case] op: BooleanOp,
+    #[case] expected_area: f64,
+    #[case] expected_rings: usize,
+) {
+    let result = polygon_boolean(
+        &[rectangle(0.0, 0.0, 2.0, 2.0)],
+        &[rectangle(1.0, 1.0, 2.0, 2.0)],
+        op,
+    )
+    .unwrap();
+    assert_eq!(result.len(), expected_rings);
+    assert_eq!(area(&result), expected_area);
+}
+
+#[rstest]
+fn test_polygon_boolean_union_shared_edge() {
+    let result = polygon_boolean(
+        &[rectangle(0.0, 0.0, 1.0, 1.0)],
+        &[rectangle(1.0, 0.0, 1.0, 1.0)],
+        BooleanOp::Union,
+    )
+    .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(area(&result), 2.0);
+    // shared edge is removed, collinear vertices are kept
+    assert_eq!(result[0].len(), 6);
+    assert!(!result[0].contains(&Point::new(1.5, 0.5)));
+}
+
+#[rstest]
+fn test_polygon_boolean_touching_corner() {
+    let result = polygon_boolean(
+        &[rectangle(0.0, 0.0, 1.0, 1.0)],
+        &[rectangle(1.0, 1.0, 1.0, 1.0)],
+        BooleanOp::Union,
+    )
+    .unwrap();
+    assert_eq!(result.len(), 2);
+    assert!(result.iter().all(|ring| ring.len() == 4));
+    assert_eq!(area(&result), 2.0);
+
+    let result = polygon_boolean(
+        &[rectangle(0.0, 0.0, 1.0, 1.0)],
+        &[rectangle(1.0, 1.0, 1.0, 1.0)],
+        BooleanOp::Intersection,
+    )
+    .unwrap();
+    assert!(result.is_empty());
+}
+
+#[rstest]
+#[case::union(BooleanOp::Union, 7.0)]
+#[case::intersection(BooleanOp::Intersection, 1.0)]
+#[case::difference(BooleanOp::Difference, 3.0)]
+fn test_polygon_boolean_empty_ring(#[case] op: BooleanOp, #[case] expected_area: f64) {
+    // empty rings are ignored, and do not move other rings between subject and clip
+    let result = polygon_boolean(
+        &[rectangle(0.0, 0.0, 2.0, 2.0), vec![]],
+        &[vec![], rectangle(1.0, 1.0, 2.0, 2.0)],
+        op,
+    )
+    .unwrap();
+    assert_eq!(area(&result), expected_area);
+}
+
+#[rstest]
+fn test_polygon_boolean_holes() {
+    // square with a hole, given with the even-odd rule
+    let subject = vec![rectangle(0.0, 0.0, 4.0, 4.0), rectangle(1.0, 1.0, 2.0, 2.0)];
+    let clip = vec![rectangle(2.0, -1.0, 3.0, 6.0)];
+
+    let union = polygon_boolean(&subject, &clip, BooleanOp::Union).unwrap();
+    assert_eq!(area(&union), 12.0 + 18.0 - 6.0);
+
+    let difference = polygon_boolean(&subject, &clip, BooleanOp::Difference).unwrap();
+    assert_eq!(difference.len(), 1);
+    assert_eq!(area(&difference), 6.0);
+
+    // hole fully inside of the result stays a clockwise ring
+    let union =
+        polygon_boolean(&subject, &[rectangle(5.0, 5.0, 1.0, 1.0)], BooleanOp::Union).unwrap();
+    assert_eq!(union.len(), 3);
+    assert_eq!(
+        union
+            .iter()
+            .filter(|ring| area(&[ring.to_vec()]) < 0.0)
+            .count(),
+        1
+    );
+    assert_eq!(area(&union), 13.0);
+}
+
+#[rstest]
+fn test_polygon_boolean_identical() {
+    let square = vec![rectangle(0.0, 0.0, 1.0, 1.0)];
+    assert_eq!(
+        area(&polygon_boolean(&square, &square, BooleanOp::Union).unwrap()),
+        1.0
+    );
+    assert_eq!(
+        area(&polygon_boolean(&square, &square, BooleanOp::Intersection).unwrap()),
+        1.0
+    );
+    assert!(polygon_boolean(&square, &square, BooleanOp::Difference)
+        .unwrap()
+        .is_empty());
+    assert!(polygon_boolean(&square, &square, BooleanOp::Xor)
+        .unwrap()
+        .is_empty());
+}
+
+#[rstest]
+fn test_polygon_boolean_f64() {
+    let square = |x: f64| {
+        vec![
+            Point::new(x, 0.0),
+            Point::new(x + 2.0, 0.0),
+            Point::new(x + 2.0, 2.0),
+            Point::new(x, 2.0),
+        ]
+    };
+    let result = polygon_boolean(&[square(0.0)], &[square(1.0)], BooleanOp::Xor).unwrap();
+    assert_eq!(result.len(), 2);
+    assert!(result.iter().all(|ring| ring.len() == 4));
+}
+
+fn random_polygon(state: &mut u64, size: usize) -> Vec<Point> {
+    (0..size)
+        .map(|_| {
+            let mut coordinate = || {
+                *state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (*state >> 40) as f32 / (1u64 << 24) as f32 * 100.0
+            };
+            Point::new(coordinate(), coordinate())
+        })
+        .collect()
+}
+
+#[rstest]
+fn test_polygon_boolean_random_area_identities() {
+    let mut state: u64 = 0x853C_49E6_748F_EA9B;
+    for _ in 0..50 {
+        let a = vec![random_polygon(&mut state, 8)];
+        let b = vec![random_polygon(&mut state, 8)];
+        let run = |subject: &[Vec<Point>], clip: &[Vec<Point>], op| {
+            let result = polygon_boolean(subject, clip, op).unwrap();
+            assert!(find_intersections(&calc_edges(&result)).is_empty());
+            area(&result)
+        };
+        // union with an empty set gives the even-odd area of a self-intersecting polygon
+        let (area_a, area_b) = (
+            run(&a, &[], BooleanOp::Union),
+            run(&b, &[], BooleanOp::Union),
+        );
+        let union = run(&a, &b, BooleanOp::Union);
+        let intersection = run(&a, &b, BooleanOp::Intersection);
+        let difference = run(&a, &b, BooleanOp::Difference);
+        let xor = run(&a, &b, BooleanOp::Xor);
+
+        let tolerance = 1e-3 * (area_a + area_b);
+        assert!(intersection >= 0.0 && intersection <= area_a.min(area_b) + tolerance);
+        assert!((union + intersection - area_a - area_b).abs() < tolerance);
+        assert!((difference - (area_a - intersection)).abs() < tolerance);
+        assert!((xor - (union - intersection)).abs() < tolerance);
+    }
+}
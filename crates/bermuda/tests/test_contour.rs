@@ -0,0 +1,183 @@
+mod common;
+
+use common::area;
+use rstest::rstest;
+use triangulation::contour::{label_contours, trace_label_boundaries};
+use triangulation::face_triangulation::triangulate_shape_face;
+use triangulation::rectilinear::triangulate_rectilinear_face;
+use triangulation::Point;
+
+fn triangulated_area(rings: &[Vec<Point<f64>>]) -> f64 {
+    let (triangles, points) = triangulate_shape_face(rings).unwrap();
+    triangles
+        .iter()
+        .map(|t| {
+            let (p, q, r) = (points[t.x], points[t.y], points[t.z]);
+            ((q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)).abs() / 2.0
+        })
+        .sum()
+}
+
+fn image(rows: &[&str]) -> (Vec<u32>, [usize; 2]) {
+    let labels = rows
+        .iter()
+        .flat_map(|row| row.chars().map(|c| c.to_digit(10).unwrap()))
+        .collect();
+    (labels, [rows.len(), rows[0].len()])
+}
+
+#[rstest]
+fn test_single_pixel() {
+    let (labels, shape) = image(&["000", "010", "000"]);
+    let contours = label_contours::<_, f64>(&labels, shape, 0);
+    assert_eq!(
+        contours[&1],
+        vec![vec![
+            Point::new(0.5, 1.0),
+            Point::new(1.0, 0.5),
+            Point::new(1.5, 1.0),
+            Point::new(1.0, 1.5),
+        ]]
+    );
+}
+
+#[rstest]
+#[case::block(&["1111", "1111", "1111"], 1, 8, 12.0 - 0.5)]
+#[case::touching_border(&["11", "11"], 1, 8, 4.0 - 0.5)]
+#[case::with_hole(&["11111", "11111", "11011", "11111", "11111"], 2, 8 + 4, 25.0 - 0.5 - 0.5)]
+#[case::diagonal(&["10", "01"], 2, 8, 1.0)]
+#[case::l_shape(&["100", "100", "111"], 1, 10, 5.0 - 5.0 / 8.0 + 1.0 / 8.0)]
+fn test_label_contours(
+    #[case] rows: &[&str],
+    #[case] ring_count: usize,
+    #[case] vertex_count: usize,
+    #[case] expected_area: f64,
+) {
+    let (labels, shape) = image(rows);
+    let contours = label_contours::<_, f64>(&labels, shape, 0);
+    assert_eq!(contours.len(), 1);
+    let rings = &contours[&1];
+    assert_eq!(rings.len(), ring_count);
+    assert_eq!(rings.iter().map(Vec::len).sum::<usize>(), vertex_count);
+    assert!((area(rings) - expected_area).abs() < 1e-12);
+    // holes are clockwise, so the signed area is the area of the region
+    assert!((triangulated_area(rings) - expected_area).abs() < 1e-9);
+}
+
+#[rstest]
+fn test_label_contours_adjacent_labels() {
+    let (labels, shape) = image(&["1122", "1122", "3333"]);
+    let contours = label_contours::<_, f32>(&labels, shape, 0);
+    assert_eq!(contours.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    // every label is contoured as if the others were background
+    assert!(contours.values().all(|rings| rings.len() == 1));
+    assert!(contours[&1][0].contains(&Point::new(0.0, 1.5)));
+    assert!(contours[&2][0].contains(&Point::new(0.0, 1.5)));
+
+    // background value may be any label
+    let contours = label_contours::<_, f32>(&labels, shape, 3);
+    assert_eq!(contours.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[rstest]
+fn test_label_contours_mask_and_empty() {
+    let mask = [false, true, true, false];
+    let contours = label_contours::<_, f32>(&mask, [2, 2], false);
+    assert_eq!(contours.len(), 1);
+    assert_eq!(contours[&true].len(), 2);
+
+    let contours = label_contours::<u8, f32>(&[], [0, 5], 0);
+    assert!(contours.is_empty());
+}
+
+#[rstest]
+fn test_label_contours_random_image_triangulates() {
+    let mut seed = 7u64;
+    let (rows, columns) = (40, 30);
+    let labels: Vec<u8> = (0..rows * columns)
+        .map(|_| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % 4) as u8
+        })
+        .collect();
+    let contours = label_contours::<_, f64>(&labels, [rows, columns], 0);
+    assert_eq!(contours.len(), 3);
+    for rings in contours.values() {
+        let expected = area(rings);
+        assert!(expected > 0.0);
+        assert!((triangulated_area(rings) - expected).abs() < 1e-6);
+    }
+}
+
+#[rstest]
+#[case::single_pixel(&["000", "010", "000"], 1, 4, 1.0)]
+#[case::block(&["1111", "1111", "1111"], 1, 4, 12.0)]
+#[case::with_hole(&["11111", "11111", "11011", "11111", "11111"], 2, 8, 24.0)]
+#[case::diagonal(&["10", "01"], 2, 8, 2.0)]
+#[case::hole_touching_outside(&["111", "101", "110"], 1, 6 + 4, 7.0)]
+#[case::l_shape(&["100", "100", "111"], 1, 6, 5.0)]
+fn test_trace_label_boundaries(
+    #[case] rows: &[&str],
+    #[case] ring_count: usize,
+    #[case] vertex_count: usize,
+    #[case] expected_area: f64,
+) {
+    let (labels, shape) = image(rows);
+    let boundaries = trace_label_boundaries::<_, f64>(&labels, shape, 0);
+    assert_eq!(boundaries.len(), 1);
+    let rings = &boundaries[&1];
+    assert_eq!(rings.len(), ring_count);
+    assert_eq!(rings.iter().map(Vec::len).sum::<usize>(), vertex_count);
+    assert!((area(rings) - expected_area).abs() < 1e-12);
+    let (triangles, points) = triangulate_rectilinear_face(rings).unwrap();
+    let triangulated: f64 = triangles
+        .iter()
+        .map(|t| {
+            let (p, q, r) = (points[t.x], points[t.y], points[t.z]);
+            (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)
+        })
+        .sum::<f64>()
+        / 2.0;
+    assert!((triangulated - expected_area).abs() < 1e-12);
+}
+
+#[rstest]
+fn test_trace_label_boundaries_pixel_corners() {
+    let (labels, shape) = image(&["0220", "0210"]);
+    let boundaries = trace_label_boundaries::<_, f32>(&labels, shape, 0);
+    assert_eq!(
+        boundaries[&1],
+        vec![vec![
+            Point::new(0.5, 1.5),
+            Point::new(1.5, 1.5),
+            Point::new(1.5, 2.5),
+            Point::new(0.5, 2.5),
+        ]]
+    );
+    assert_eq!(boundaries[&2][0].len(), 6);
+    assert!((area(std::slice::from_ref(&boundaries[&2][0])) - 3.0).abs() < 1e-12);
+}
+
+#[rstest]
+fn test_trace_label_boundaries_random_image_triangulates() {
+    let mut seed = 11u64;
+    let (rows, columns) = (40, 30);
+    let labels: Vec<u8> = (0..rows * columns)
+        .map(|_| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % 4) as u8
+        })
+        .collect();
+    let boundaries = trace_label_boundaries::<_, f64>(&labels, [rows, columns], 0);
+    for (label, rings) in &boundaries {
+        let pixels = labels.iter().filter(|&l| l == label).count() as f64;
+        assert!((area(rings) - pixels).abs() < 1e-9);
+        assert!((triangulated_area(rings) - pixels).abs() < 1e-6);
+        let (triangles, _) = triangulate_rectilinear_face(rings).unwrap();
+        assert!(triangles.len() <= 2 * pixels as usize);
+    }
+}
@@ -1,10 +1,19 @@
+#![allow(clippy::excessive_precision)]
+
 use rstest::rstest;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
-use triangulation::face_triangulation::sweeping_line_triangulation;
+use triangulation::face_triangulation::{
+    sweeping_line_triangulation, triangulate_shape_face, triangulate_shape_face_styled,
+    triangulate_shape_face_with_fill_rule, triangulate_shapes_face, FaceStyle,
+    TriangulationQuality,
+};
 use triangulation::point::{calc_dedup_edges, Point, Triangle};
-use triangulation::split_polygons_on_repeated_edges;
+use triangulation::{
+    split_polygons_on_repeated_edges, split_polygons_with_fill_rule, FillRule, Refinement,
+    TriangulationError,
+};
 
 const DIAMOND: [Point; 4] = [
     Point::new(1.0, 0.0),
@@ -16,7 +25,7 @@ const DIAMOND: [Point; 4] = [
 #[rstest]
 fn test_diamond() {
     let (triangles, points) =
-        sweeping_line_triangulation(calc_dedup_edges(&vec![DIAMOND.to_vec()]));
+        sweeping_line_triangulation(calc_dedup_edges(&[DIAMOND.to_vec()]).unwrap()).unwrap();
     assert_eq!(triangles.len(), 2);
     assert_eq!(points.len(), 4);
     assert_eq!(
@@ -37,9 +46,9 @@ fn renumerate_triangles(
         .iter()
         .map(|t| {
             [
-                point_num[&points[t.x as usize]],
-                point_num[&points[t.y as usize]],
-                point_num[&points[t.z as usize]],
+                point_num[&points[t.x]],
+                point_num[&points[t.y]],
+                point_num[&points[t.z]],
             ]
         })
         .collect()
@@ -96,7 +105,8 @@ fn test_triangulate_polygon_non_convex(
     #[case] polygon: Vec<Point>,
     #[case] expected: Vec<[usize; 3]>,
 ) {
-    let (new_polygons, segments) = split_polygons_on_repeated_edges(&vec![polygon.clone()]);
+    let (new_polygons, segments) =
+        split_polygons_on_repeated_edges(std::slice::from_ref(&polygon)).unwrap();
     assert_eq!(
         new_polygons
             .iter()
@@ -104,7 +114,7 @@ fn test_triangulate_polygon_non_convex(
             .collect::<HashSet<_>>(),
         polygon.iter().cloned().collect::<HashSet<_>>()
     );
-    let (triangles, points) = sweeping_line_triangulation(segments);
+    let (triangles, points) = sweeping_line_triangulation(segments).unwrap();
     let triangles_ = renumerate_triangles(&polygon, &points, &triangles);
     assert_eq!(triangles_, expected);
 }
@@ -138,8 +148,54 @@ fn test_triangulate_polygon_segfault1() {
         Point::new(208.037506, 1491.5376),
         Point::new(205.912506, 1489.83752),
     ];
-    let (_new_polygons, segments) = split_polygons_on_repeated_edges(&vec![polygon]);
-    sweeping_line_triangulation(segments);
+    let (_new_polygons, segments) = split_polygons_on_repeated_edges(&[polygon]).unwrap();
+    sweeping_line_triangulation(segments).unwrap();
+}
+
+#[rstest]
+fn test_sweeping_line_triangulation_crossing_edges() {
+    // edges of overlapping squares, which are not split at their intersections
+    let squares = [square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)];
+    let err = sweeping_line_triangulation(calc_dedup_edges(&squares).unwrap()).unwrap_err();
+    assert!(
+        matches!(err, TriangulationError::InvalidSweepState { .. }),
+        "{err:?}"
+    );
+    assert!(err.vertex().is_some());
+}
+
+#[rstest]
+#[case::nan(Point::new(f32::NAN, 1.0))]
+#[case::infinity(Point::new(1.0, f32::INFINITY))]
+fn test_triangulate_shape_face_non_finite_vertex(#[case] vertex: Point) {
+    let polygon = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(2.0, 1.0),
+        vertex,
+        Point::new(0.0, 4.0),
+    ];
+    let err = triangulate_shape_face(&[square(10.0, 10.0, 1.0), polygon]).unwrap_err();
+    assert_eq!(err.polygon_index(), Some(1));
+    assert_eq!(format!("{:?}", err.vertex()), format!("{:?}", Some(vertex)));
+}
+
+#[rstest]
+fn test_triangulate_polygon_nested_merge_points() {
+    // merge point (-5.5, 5.5) closes an interval whose helper
+    // is the merge point (-2, 9), so a diagonal between them is needed
+    let polygon = vec![
+        Point::new(0.0, 11.0),
+        Point::new(-2.0, 9.0),
+        Point::new(-6.5, 10.0),
+        Point::new(-5.5, 5.5),
+        Point::new(-9.0, 6.0),
+        Point::new(-10.0, 2.0),
+        Point::new(11.0, -2.0),
+    ];
+    let (triangles, points) = triangulate_shape_face(std::slice::from_ref(&polygon)).unwrap();
+    assert_eq!(triangles.len(), polygon.len() - 2);
+    assert_eq!(triangles_area(&points, &triangles), 128.625);
 }
 
 #[rstest]
@@ -244,7 +300,429 @@ fn test_triangulate_polygon_country() {
         .iter()
         .map(|&p| Point::new(p[0], p[1]))
         .collect::<Vec<_>>();
-    let (_new_polygons, segments) = split_polygons_on_repeated_edges(&vec![polygon]);
+    let (_new_polygons, segments) = split_polygons_on_repeated_edges(&[polygon]).unwrap();
     assert_eq!(_new_polygons.len(), 2);
-    sweeping_line_triangulation(segments);
+    sweeping_line_triangulation(segments).unwrap();
+}
+
+fn triangles_area(points: &[Point], triangles: &[Triangle]) -> f32 {
+    triangles
+        .iter()
+        .map(|t| {
+            let (p1, p2, p3) = (points[t.x], points[t.y], points[t.z]);
+            ((p2.x - p1.x) * (p3.y - p1.y) - (p2.y - p1.y) * (p3.x - p1.x)).abs() / 2.0
+        })
+        .sum()
+}
+
+fn square(x: f32, y: f32, size: f32) -> Vec<Point> {
+    vec![
+        Point::new(x, y),
+        Point::new(x + size, y),
+        Point::new(x + size, y + size),
+        Point::new(x, y + size),
+    ]
+}
+
+#[rstest]
+fn test_triangulate_shapes_face_overlapping() {
+    // Overlapping shapes triangulated together would create a hole (even-odd rule),
+    // triangulated as separate shapes each keeps its full area.
+    let shapes = vec![vec![square(0.0, 0.0, 2.0)], vec![square(1.0, 1.0, 2.0)]];
+    let result = triangulate_shapes_face(&shapes).unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result.vertex_offsets, vec![0, 4, 8]);
+    assert_eq!(result.triangle_offsets, vec![0, 2, 4]);
+    for i in 0..2 {
+        let triangles =
+            &result.triangles[result.triangle_offsets[i]..result.triangle_offsets[i + 1]];
+        assert!(triangles.iter().all(|t| {
+            [t.x, t.y, t.z]
+                .iter()
+                .all(|&idx| result.vertex_offsets[i] <= idx && idx < result.vertex_offsets[i + 1])
+        }));
+        assert_eq!(triangles_area(&result.points, triangles), 4.0);
+    }
+}
+
+#[rstest]
+fn test_triangulate_shapes_face_with_hole() {
+    let shapes = vec![
+        vec![square(0.0, 0.0, 10.0), square(4.0, 4.0, 2.0)],
+        vec![square(20.0, 0.0, 1.0)],
+    ];
+    let result = triangulate_shapes_face(&shapes).unwrap();
+    assert_eq!(result.vertex_offsets, vec![0, 8, 12]);
+    assert_eq!(result.triangle_offsets, vec![0, 8, 10]);
+    assert_eq!(triangles_area(&result.points, &result.triangles[..8]), 96.0);
+}
+
+#[rstest]
+fn test_triangulate_shapes_face_empty() {
+    let result = triangulate_shapes_face::<f32>(&[]).unwrap();
+    assert!(result.is_empty());
+    assert!(result.points.is_empty());
+    assert_eq!(result.vertex_offsets, vec![0]);
+}
+
+#[rstest]
+fn test_triangulate_shapes_face_error_in_shape() {
+    let invalid = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(2.0, 1.0),
+        Point::new(f32::INFINITY, 1.0),
+        Point::new(0.0, 4.0),
+    ];
+    let mut shapes: Vec<Vec<Vec<Point>>> = (0..5)
+        .map(|i| vec![square((i * 3) as f32, 0.0, 2.0)])
+        .collect();
+    shapes[2].push(invalid);
+    let err = triangulate_shapes_face(&shapes).unwrap_err();
+    assert_eq!(err.shape_index(), Some(2));
+    // polygon index refers to the rings of the failing shape
+    assert_eq!(err.polygon_index(), Some(1));
+    assert_eq!(err.vertex(), Some(Point::new(f32::INFINITY, 1.0)));
+    assert!(err.to_string().starts_with("Shape at index 2:"), "{err}");
+}
+
+#[rstest]
+fn test_triangulate_shapes_face_keeps_order() {
+    // shapes of different sizes, so each produces a different number of vertices
+    let shapes: Vec<Vec<Vec<Point>>> = (0..64)
+        .map(|i| {
+            let x = (i * 3) as f32;
+            if i % 2 == 0 {
+                vec![square(x, 0.0, 2.0)]
+            } else {
+                vec![square(x, 0.0, 2.0), square(x + 0.5, 0.5, 1.0)]
+            }
+        })
+        .collect();
+    let result = triangulate_shapes_face(&shapes).unwrap();
+    assert_eq!(result.len(), shapes.len());
+    for (i, shape) in shapes.iter().enumerate() {
+        let (triangles, points) = triangulate_shape_face(shape).unwrap();
+        let vertex_start = result.vertex_offsets[i];
+        assert_eq!(
+            &result.points[vertex_start..result.vertex_offsets[i + 1]],
+            points.as_slice()
+        );
+        let batched: Vec<_> = result.triangles
+            [result.triangle_offsets[i]..result.triangle_offsets[i + 1]]
+            .iter()
+            .map(|t| (t.x - vertex_start, t.y - vertex_start, t.z - vertex_start))
+            .collect();
+        let single: Vec<_> = triangles.iter().map(|t| (t.x, t.y, t.z)).collect();
+        assert_eq!(batched, single);
+    }
+}
+
+#[rstest]
+fn test_triangulate_shape_face_f64_matches_f32() {
+    let shape = vec![square(0.0, 0.0, 4.0), square(1.0, 1.0, 2.0)];
+    let shape_f64: Vec<Vec<Point<f64>>> = shape
+        .iter()
+        .map(|ring| ring.iter().map(|p| p.cast()).collect())
+        .collect();
+    let (triangles, points) = triangulate_shape_face(&shape).unwrap();
+    let (triangles_f64, points_f64) = triangulate_shape_face(&shape_f64).unwrap();
+    let to_tuples =
+        |triangles: &[Triangle]| -> Vec<_> { triangles.iter().map(|t| (t.x, t.y, t.z)).collect() };
+    assert_eq!(to_tuples(&triangles_f64), to_tuples(&triangles));
+    let points_f64: Vec<Point> = points_f64.into_iter().map(Point::cast).collect();
+    assert_eq!(points_f64, points);
+}
+
+#[rstest]
+fn test_sweeping_line_triangulation_f64_precision() {
+    // vertices differ by less than float32 resolution at this magnitude
+    let polygon: Vec<Point<f64>> = vec![
+        Point::new(1e8, 0.0),
+        Point::new(1e8 + 1.0, 0.0),
+        Point::new(1e8 + 1.0, 1.0),
+        Point::new(1e8, 1.0),
+    ];
+    let edges = calc_dedup_edges(std::slice::from_ref(&polygon)).unwrap();
+    let (triangles, points) = sweeping_line_triangulation(edges).unwrap();
+    assert_eq!(triangles.len(), 2);
+    let unique: HashSet<_> = points.into_iter().collect();
+    assert_eq!(unique, polygon.into_iter().collect());
+}
+
+fn reversed(mut polygon: Vec<Point>) -> Vec<Point> {
+    polygon.reverse();
+    polygon
+}
+
+#[rstest]
+#[case::even_odd(FillRule::EvenOdd, [6.0, 12.0, 12.0])]
+#[case::non_zero(FillRule::NonZero, [7.0, 16.0, 12.0])]
+#[case::positive(FillRule::Positive, [7.0, 16.0, 12.0])]
+#[case::negative(FillRule::Negative, [0.0, 0.0, 0.0])]
+fn test_triangulate_shape_face_fill_rule(#[case] fill_rule: FillRule, #[case] expected: [f32; 3]) {
+    let shapes = [
+        // overlapping counterclockwise squares
+        vec![square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)],
+        // square nested in a square with the same orientation
+        vec![square(0.0, 0.0, 4.0), square(1.0, 1.0, 2.0)],
+        // square nested in a square with the opposite orientation
+        vec![square(0.0, 0.0, 4.0), reversed(square(1.0, 1.0, 2.0))],
+    ];
+    for (shape, expected) in shapes.iter().zip(expected) {
+        let (triangles, points) = triangulate_shape_face_with_fill_rule(shape, fill_rule).unwrap();
+        assert_eq!(triangles_area(&points, &triangles), expected);
+    }
+}
+
+#[rstest]
+fn test_triangulate_shape_face_fill_rule_orientation() {
+    let shape = vec![
+        reversed(square(0.0, 0.0, 2.0)),
+        reversed(square(1.0, 1.0, 2.0)),
+    ];
+    let area = |fill_rule| {
+        let (triangles, points) = triangulate_shape_face_with_fill_rule(&shape, fill_rule).unwrap();
+        triangles_area(&points, &triangles)
+    };
+    assert_eq!(area(FillRule::Positive), 0.0);
+    assert_eq!(area(FillRule::Negative), 7.0);
+    // single convex polygon is filled only for the matching sign
+    let triangle = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 1.0),
+        Point::new(1.0, 0.0),
+    ];
+    let (triangles, _) =
+        triangulate_shape_face_with_fill_rule(std::slice::from_ref(&triangle), FillRule::Positive)
+            .unwrap();
+    assert!(triangles.is_empty());
+    let (triangles, _) =
+        triangulate_shape_face_with_fill_rule(&[triangle], FillRule::Negative).unwrap();
+    assert_eq!(triangles.len(), 1);
+}
+
+#[rstest]
+#[case::non_zero(FillRule::NonZero)]
+#[case::positive(FillRule::Positive)]
+fn test_fill_rule_degenerate_rings(#[case] fill_rule: FillRule) {
+    // empty rings and rings with fewer than 3 distinct points are ignored
+    let shape = vec![
+        square(0.0, 0.0, 2.0),
+        vec![],
+        vec![Point::new(5.0, 5.0)],
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(3.0, 3.0),
+        ],
+        square(1.0, 1.0, 2.0),
+    ];
+    let (rings, _) = split_polygons_with_fill_rule(&shape, fill_rule).unwrap();
+    assert_eq!(rings.len(), 1);
+    let (triangles, points) = triangulate_shape_face_with_fill_rule(&shape, fill_rule).unwrap();
+    assert_eq!(triangles_area(&points, &triangles), 7.0);
+}
+
+#[rstest]
+fn test_triangulate_shape_face_fill_rule_self_intersecting() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    // pentagram, the inner pentagon has winding number 2
+    let pentagram: Vec<Point> = (0..5)
+        .map(|i| {
+            let angle = FRAC_PI_2 + i as f32 * 4.0 * PI / 5.0;
+            Point::new(10.0 * angle.cos(), 10.0 * angle.sin())
+        })
+        .collect();
+    let shape = [pentagram];
+    let (triangles, points) = triangulate_shape_face(&shape).unwrap();
+    let even_odd = triangles_area(&points, &triangles);
+    let (triangles, points) =
+        triangulate_shape_face_with_fill_rule(&shape, FillRule::NonZero).unwrap();
+    let non_zero = triangles_area(&points, &triangles);
+    // area of the pentagon with vertices at the inner intersections of the star
+    let inner_radius = 10.0 * (0.4 * PI).cos() / (0.2 * PI).cos();
+    let pentagon = 2.5 * inner_radius * inner_radius * (0.4 * PI).sin();
+    assert!(
+        (non_zero - even_odd - pentagon).abs() < 1e-3,
+        "{non_zero} {even_odd} {pentagon}"
+    );
+}
+
+/// Smallest angle of the triangles, in degrees.
+fn min_angle(points: &[Point], triangles: &[Triangle]) -> f32 {
+    triangles
+        .iter()
+        .flat_map(|t| {
+            let p = [points[t.x], points[t.y], points[t.z]];
+            (0..3).map(move |k| {
+                let (a, b, c) = (p[k], p[(k + 1) % 3], p[(k + 2) % 3]);
+                let (u, v) = (b - a, c - a);
+                let cos = (u.x * v.x + u.y * v.y) / (u.x.hypot(u.y) * v.x.hypot(v.y));
+                cos.clamp(-1.0, 1.0).acos().to_degrees()
+            })
+        })
+        .fold(180.0, f32::min)
+}
+
+/// Checks that no vertex lies inside of the circumcircle of a triangle
+/// neighboring it over an edge which is not an edge of `shape`.
+fn assert_locally_delaunay(shape: &[Vec<Point>], points: &[Point], triangles: &[Triangle]) {
+    let index: HashMap<Point, usize> = points.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+    let key = |a: usize, b: usize| (a.min(b), a.max(b));
+    let constraints: HashSet<(usize, usize)> = shape
+        .iter()
+        .flat_map(|ring| (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])))
+        .map(|(a, b)| key(index[&a], index[&b]))
+        .collect();
+    let mut opposite: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+    for (i, t) in triangles.iter().enumerate() {
+        for [a, b, c] in [[t.x, t.y, t.z], [t.y, t.z, t.x], [t.z, t.x, t.y]] {
+            opposite.entry(key(a, b)).or_default().push((i, c));
+        }
+    }
+    for (edge, sides) in opposite {
+        if constraints.contains(&edge) || sides.len() < 2 {
+            continue;
+        }
+        let (t, d) = (&triangles[sides[0].0], points[sides[1].1]);
+        let [a, b, c] = [points[t.x], points[t.y], points[t.z]].map(|p| {
+            let (x, y) = ((p.x - d.x) as f64, (p.y - d.y) as f64);
+            (x, y, x * x + y * y)
+        });
+        let det = a.2 * (b.0 * c.1 - c.0 * b.1)
+            + b.2 * (c.0 * a.1 - a.0 * c.1)
+            + c.2 * (a.0 * b.1 - b.0 * a.1);
+        let orientation = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+        let scale = [a, b, c].iter().map(|p| p.2).fold(0.0, f64::max);
+        assert!(
+            det * orientation.signum() <= 1e-6 * scale * scale,
+            "edge {edge:?} is not locally Delaunay"
+        );
+    }
+}
+
+fn ellipse(count: usize, radius: f32) -> Vec<Point> {
+    (0..count)
+        .map(|i| {
+            let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+            Point::new(radius * angle.cos(), 0.5 * radius * angle.sin())
+        })
+        .collect()
+}
+
+fn comb(teeth: usize) -> Vec<Point> {
+    let mut polygon = vec![Point::new(0.0, 0.0), Point::new(teeth as f32 * 2.0, 0.0)];
+    for i in (0..teeth).rev() {
+        let x = i as f32 * 2.0;
+        polygon.extend([
+            Point::new(x + 1.5, 10.0),
+            Point::new(x + 1.0, 1.0),
+            Point::new(x + 0.5, 10.0),
+        ]);
+    }
+    polygon
+}
+
+#[rstest]
+#[case::ellipse(vec![ellipse(32, 10.0)])]
+#[case::comb(vec![comb(8)])]
+#[case::square_with_hole(vec![square(0.0, 0.0, 10.0), square(4.0, 3.0, 2.0)])]
+#[case::ring(vec![ellipse(24, 10.0), ellipse(24, 9.0)])]
+fn test_triangulate_shape_face_delaunay(#[case] shape: Vec<Vec<Point>>) {
+    let (fast_triangles, fast_points) = triangulate_shape_face(&shape).unwrap();
+    let style = FaceStyle::default().with_quality(TriangulationQuality::Delaunay);
+    let (triangles, points) = triangulate_shape_face_styled(&shape, &style).unwrap();
+    assert_eq!(points, fast_points);
+    assert_eq!(triangles.len(), fast_triangles.len());
+    let area = triangles_area(&points, &triangles);
+    let fast_area = triangles_area(&fast_points, &fast_triangles);
+    assert!(
+        (area - fast_area).abs() <= fast_area * 1e-5,
+        "{area} != {fast_area}"
+    );
+    assert!(min_angle(&points, &triangles) >= min_angle(&fast_points, &fast_triangles));
+    assert_locally_delaunay(&shape, &points, &triangles);
+}
+
+fn max_triangle_area(points: &[Point], triangles: &[Triangle]) -> f32 {
+    triangles
+        .iter()
+        .map(|t| triangles_area(points, std::slice::from_ref(t)))
+        .fold(0.0, f32::max)
+}
+
+#[rstest]
+#[case::ellipse(vec![ellipse(32, 10.0)])]
+#[case::thin_rectangle(vec![vec![
+    Point::new(0.0, 0.0),
+    Point::new(20.0, 0.0),
+    Point::new(20.0, 0.5),
+    Point::new(0.0, 0.5),
+]])]
+#[case::square_with_hole(vec![square(0.0, 0.0, 10.0), square(4.0, 3.0, 2.0)])]
+#[case::ring(vec![ellipse(24, 10.0), ellipse(24, 9.0)])]
+fn test_triangulate_shape_face_refined(#[case] shape: Vec<Vec<Point>>) {
+    let (fast_triangles, fast_points) = triangulate_shape_face(&shape).unwrap();
+    let fast_area = triangles_area(&fast_points, &fast_triangles);
+    let max_area = fast_area / 50.0;
+    let refinement = Refinement::new(25.0).with_max_area(max_area as f64);
+    let style = FaceStyle::default().with_quality(TriangulationQuality::Refined(refinement));
+    let (triangles, points) = triangulate_shape_face_styled(&shape, &style).unwrap();
+    // input points come first, followed by Steiner points
+    assert_eq!(points[..fast_points.len()], fast_points[..]);
+    assert!(points.len() > fast_points.len());
+    assert_eq!(
+        points.iter().collect::<HashSet<_>>().len(),
+        points.len(),
+        "duplicated Steiner points"
+    );
+    let area = triangles_area(&points, &triangles);
+    assert!(
+        (area - fast_area).abs() <= fast_area * 1e-4,
+        "{area} != {fast_area}"
+    );
+    assert!(min_angle(&points, &triangles) >= 25.0 - 1e-2);
+    assert!(max_triangle_area(&points, &triangles) <= max_area * (1.0 + 1e-4));
+}
+
+#[rstest]
+#[case::comb(vec![comb(8)])]
+#[case::wedge(vec![vec![
+    Point::new(0.0, 0.0),
+    Point::new(10.0, 0.3),
+    Point::new(10.0, 1.0),
+]])]
+fn test_triangulate_shape_face_refined_small_input_angles(#[case] shape: Vec<Vec<Point>>) {
+    let (fast_triangles, fast_points) = triangulate_shape_face(&shape).unwrap();
+    let refinement = Refinement::new(30.0);
+    let style = FaceStyle::default().with_quality(TriangulationQuality::Refined(refinement));
+    let (triangles, points) = triangulate_shape_face_styled(&shape, &style).unwrap();
+    // refinement finishes, triangles at the small angles are kept
+    assert!(points.len() < 100 * fast_points.len());
+    let (area, fast_area) = (
+        triangles_area(&points, &triangles),
+        triangles_area(&fast_points, &fast_triangles),
+    );
+    assert!(
+        (area - fast_area).abs() <= fast_area * 1e-4,
+        "{area} != {fast_area}"
+    );
+    assert!(min_angle(&points, &triangles) > min_angle(&fast_points, &fast_triangles));
+}
+
+#[rstest]
+#[case::zero(0.0)]
+#[case::negative(-1.0)]
+#[case::tiny(1e-9)]
+fn test_triangulate_shape_face_refined_steiner_budget(#[case] max_area: f64) {
+    // bounds which cannot be met stop at the limit of Steiner points
+    let shape = vec![DIAMOND.to_vec()];
+    let refinement = Refinement::default()
+        .with_max_area(max_area)
+        .with_max_steiner_points(500);
+    let style = FaceStyle::default().with_quality(TriangulationQuality::Refined(refinement));
+    let (triangles, points) = triangulate_shape_face_styled(&shape, &style).unwrap();
+    assert!(points.len() <= DIAMOND.len() + 500);
+    assert!((triangles_area(&points, &triangles) - 2.0).abs() < 1e-4);
 }
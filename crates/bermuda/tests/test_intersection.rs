@@ -1,3 +1,5 @@
+#![allow(clippy::excessive_precision)]
+
 use rstest::rstest;
 use std::collections::HashSet;
 use triangulation::intersection;
@@ -329,7 +331,7 @@ fn test_split_polygon_on_repeated_edges(
     #[case] polygon_count: usize,
     #[case] edge_count: usize,
 ) {
-    let (polygons, edges) = intersection::split_polygons_on_repeated_edges(&polygon_list);
+    let (polygons, edges) = intersection::split_polygons_on_repeated_edges(&polygon_list).unwrap();
     assert_eq!(polygons.len(), polygon_count);
     assert_eq!(edges.len(), edge_count);
 }
@@ -350,7 +352,7 @@ fn test_split_polygon_on_repeated_edges_merge_two_polygons() {
             Point::new(1.0, 1.0),
         ],
     ];
-    let (polygons, edges) = intersection::split_polygons_on_repeated_edges(&polygon_list);
+    let (polygons, edges) = intersection::split_polygons_on_repeated_edges(&polygon_list).unwrap();
     assert_eq!(polygons.len(), 1);
     assert_eq!(edges.len(), 8);
 }
@@ -367,7 +369,245 @@ fn test_split_polygons_on_repeated_edges() {
     ];
 
     let (sub_polygons, _edges) =
-        intersection::split_polygons_on_repeated_edges(&vec![polygon.clone()]);
+        intersection::split_polygons_on_repeated_edges(std::slice::from_ref(&polygon)).unwrap();
     assert_eq!(sub_polygons.len(), 1);
     assert_eq!(sub_polygons[0].len(), 6);
 }
+
+#[rstest]
+fn test_split_polygons_on_repeated_edges_borrowed_rings() {
+    let square = [
+        Point::new(0.0, 0.0),
+        Point::new(3.0, 0.0),
+        Point::new(3.0, 3.0),
+        Point::new(0.0, 3.0),
+    ];
+    let bow = [
+        Point::new(1.0, -1.0),
+        Point::new(2.0, 4.0),
+        Point::new(2.0, -1.0),
+    ];
+    let (_, borrowed) =
+        intersection::split_polygons_on_repeated_edges(&[&square[..], &bow[..]]).unwrap();
+    let (_, owned) =
+        intersection::split_polygons_on_repeated_edges(&[square.to_vec(), bow.to_vec()]).unwrap();
+    // edges are collected from a hash map, so their order is not fixed
+    assert_eq!(
+        borrowed.into_iter().collect::<HashSet<_>>(),
+        owned.into_iter().collect::<HashSet<_>>()
+    );
+}
+
+/// Self-intersecting polygons with vertices from a fixed pseudo-random sequence.
+fn random_polygons(count: usize, size: usize) -> Vec<Vec<Point>> {
+    let mut state: u64 = 0x853C_49E6_748F_EA9B;
+    let mut coordinate = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 40) as f32 / (1u64 << 24) as f32 * 100.0
+    };
+    (0..count)
+        .map(|_| {
+            (0..size)
+                .map(|_| Point::new(coordinate(), coordinate()))
+                .collect()
+        })
+        .collect()
+}
+
+#[rstest]
+#[case::closed_ring(true)]
+#[case::single_point_ring(false)]
+fn test_find_intersection_points_rings_after_fewer_edges(#[case] closed: bool) {
+    // rings with fewer edges than points must not shift edges of the following rings
+    let square = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ];
+    let rectangle = vec![
+        Point::new(2.0, 1.0),
+        Point::new(6.0, 1.0),
+        Point::new(6.0, 3.0),
+        Point::new(2.0, 3.0),
+    ];
+    let expected = intersection::find_intersection_points(&[square.clone(), rectangle.clone()]);
+    let mut rings = vec![square.clone(), rectangle];
+    if closed {
+        rings[0].push(square[0]);
+    } else {
+        rings.insert(0, vec![Point::new(8.0, 8.0)]);
+    }
+    let result = intersection::find_intersection_points(&rings);
+    assert_eq!(result.last(), expected.last());
+    assert_eq!(result[result.len() - 2], expected[0]);
+}
+
+#[rstest]
+fn test_split_polygons_edges_do_not_cross() {
+    for polygon in random_polygons(20, 25) {
+        let (_, edges) =
+            intersection::split_polygons_on_repeated_edges(std::slice::from_ref(&polygon)).unwrap();
+        assert!(intersection::find_intersections(&edges).is_empty());
+    }
+}
+
+#[rstest]
+fn test_split_polygons_with_snap_rounding_grid() {
+    let grid = 1.0 / 64.0;
+    let snap = intersection::SnapRounding::new(grid, 0.0);
+    for polygon in random_polygons(20, 25) {
+        let (polygons, edges, diagnostics) =
+            intersection::split_polygons_with_snap_rounding(std::slice::from_ref(&polygon), &snap)
+                .unwrap();
+        assert!(intersection::find_intersections(&edges).is_empty());
+        assert!(polygons
+            .iter()
+            .flatten()
+            .all(|p| (p.x / grid).fract() == 0.0 && (p.y / grid).fract() == 0.0));
+        assert!(diagnostics.inserted_points > 0);
+        assert!(diagnostics.snapped_points > 0);
+        assert!(diagnostics.max_displacement <= grid * std::f32::consts::FRAC_1_SQRT_2);
+    }
+}
+
+#[rstest]
+fn test_snap_round_polygons_tolerance() {
+    // second polygon crosses the first one very close to its vertex (1, 1)
+    let polygon_list = vec![
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 0.0),
+        ],
+        vec![
+            Point::new(0.999, 0.5),
+            Point::new(0.999, 2.0),
+            Point::new(-1.0, 2.0),
+        ],
+    ];
+    let snap = intersection::SnapRounding::new(0.0, 0.01);
+    let (polygons, diagnostics) = intersection::snap_round_polygons(&polygon_list, &snap).unwrap();
+    // only the crossing far from vertices is inserted into the first polygon
+    assert_eq!(polygons[0].len(), 4);
+    assert!(polygons[1].contains(&Point::new(1.0, 1.0)));
+    assert_eq!(diagnostics.snapped_points, 1);
+    assert!(diagnostics.max_displacement < 0.01);
+}
+
+#[rstest]
+fn test_snap_round_polygons_unresolved() {
+    let polygon = vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 2.0),
+    ];
+    let snap = intersection::SnapRounding::default().with_max_iterations(0);
+    let err = intersection::snap_round_polygons(&[polygon], &snap).unwrap_err();
+    assert!(matches!(
+        err,
+        triangulation::TriangulationError::UnresolvedIntersection { .. }
+    ));
+}
+
+#[rstest]
+fn test_snap_round_polygons_last_pass() {
+    let bowtie = vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 2.0),
+    ];
+    // a single pass inserting points is verified by the final check
+    let snap = intersection::SnapRounding::default().with_max_iterations(1);
+    let (polygons, diagnostics) =
+        intersection::snap_round_polygons(std::slice::from_ref(&bowtie), &snap).unwrap();
+    assert_eq!(polygons[0].len(), 6);
+    assert_eq!(diagnostics.iterations, 1);
+
+    // squares sharing an edge, which overlaps itself but needs no new points
+    let squares = [
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ],
+        vec![
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+        ],
+    ];
+    let (polygons, diagnostics) = intersection::snap_round_polygons(&squares, &snap).unwrap();
+    assert_eq!(polygons, squares);
+    assert_eq!(diagnostics.iterations, 0);
+}
+
+/// Reference implementation of `find_intersections` testing all pairs.
+fn brute_force_intersections(segments: &[Segment]) -> HashSet<intersection::OrderedPair> {
+    let mut result = HashSet::new();
+    for i in 0..segments.len() {
+        for j in i + 1..segments.len() {
+            if intersection::do_intersect(&segments[i], &segments[j])
+                && !intersection::do_share_endpoint(&segments[i], &segments[j])
+            {
+                result.insert(intersection::OrderedPair::new(i, j));
+            }
+        }
+    }
+    result
+}
+
+/// Segments with endpoints from a fixed pseudo-random sequence of values
+/// in `0..range` multiplied by `scale`.
+fn random_segments(count: usize, range: u64, scale: f32, seed: u64) -> Vec<Segment> {
+    let mut state = seed;
+    let mut coordinate = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % range) as f32 * scale
+    };
+    let mut segments = Vec::new();
+    while segments.len() < count {
+        let p1 = Point::new(coordinate(), coordinate());
+        let p2 = Point::new(coordinate(), coordinate());
+        if p1 != p2 {
+            segments.push(Segment::new(p1, p2));
+        }
+    }
+    segments
+}
+
+#[rstest]
+// small grid creates shared endpoints, overlaps, T-junctions and
+// many segments crossing at a single point
+#[case::small_grid(random_segments(60, 6, 1.0, 1))]
+#[case::grid(random_segments(200, 20, 1.0, 2))]
+#[case::fine_grid(random_segments(300, 1 << 20, 1.0 / 1024.0, 3))]
+#[case::star(
+    (0..32)
+        .map(|i| {
+            let angle = i as f32 / 32.0 * std::f32::consts::PI;
+            let (sin, cos) = angle.sin_cos();
+            seg_f(-cos, -sin, cos * 2.0, sin * 2.0)
+        })
+        .collect()
+)]
+#[case::horizontal_lines(
+    (0..40)
+        .map(|i| seg(i % 7, i % 5, i % 7 + 3, i % 5))
+        .chain((0..10).map(|i| seg(i, -1, 10 - i, 6)))
+        .collect()
+)]
+fn test_find_intersections_matches_brute_force(#[case] segments: Vec<Segment>) {
+    assert_eq!(
+        intersection::find_intersections(&segments),
+        brute_force_intersections(&segments)
+    );
+}
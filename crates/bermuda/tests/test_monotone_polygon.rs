@@ -1,6 +1,7 @@
 use rstest::rstest;
 use triangulation::monotone_polygon::{triangulate_monotone_polygon, MonotonePolygon};
 use triangulation::point::{Point, PointTriangle};
+use triangulation::TriangulationError;
 
 #[rstest]
 fn test_monotone_polygon_simple() {
@@ -15,12 +16,24 @@ fn test_monotone_polygon_simple() {
     assert!(!poly.finished());
     poly.bottom = Option::from(bottom);
     assert!(poly.finished());
-    let result = triangulate_monotone_polygon(&poly);
+    let result = triangulate_monotone_polygon(&poly).unwrap();
     assert_eq!(result.len(), 2);
     assert_eq!(result[0], PointTriangle::new(right, top, left));
     assert_eq!(result[1], PointTriangle::new(bottom, left, right));
 }
 
+#[rstest]
+fn test_monotone_polygon_unfinished() {
+    let top = Point::new(0.0, 10.0);
+    let mut poly = MonotonePolygon::new_top(top);
+    poly.left.push(Point::new(-1.0, 7.0));
+    poly.right.push(Point::new(1.0, 5.0));
+    assert!(matches!(
+        triangulate_monotone_polygon(&poly),
+        Err(TriangulationError::InvalidSweepState { vertex, .. }) if vertex == top
+    ));
+}
+
 #[rstest]
 #[case::diamond(
     MonotonePolygon::new(Point::new(1.0, 2.0), Point::new(1.0, 0.0), vec![Point::new(0.0, 1.0)], vec![Point::new(2.0, 1.0)]),
@@ -62,6 +75,6 @@ fn test_monotone_polygon_simple() {
     ]
 )]
 fn test_monotone_polygon(#[case] poly: MonotonePolygon, #[case] expected: Vec<PointTriangle>) {
-    let result = triangulate_monotone_polygon(&poly);
+    let result = triangulate_monotone_polygon(&poly).unwrap();
     assert_eq!(result, expected);
 }
@@ -0,0 +1,160 @@
+mod common;
+
+use common::{area, rectangle};
+use rstest::rstest;
+use triangulation::offset::{offset_polygons, OffsetStyle};
+use triangulation::path_triangulation::JoinStyle;
+use triangulation::{Point, TriangulationError};
+
+#[rstest]
+#[case::miter(JoinStyle::Miter, 4, 36.0)]
+#[case::bevel(JoinStyle::Bevel, 8, 36.0 - 4.0 * 0.5)]
+#[case::round(JoinStyle::Round { segments: 8 }, 4 * 5, 16.0 + 16.0 + 8.0 * (std::f64::consts::PI / 8.0).sin())]
+fn test_offset_square_grow(
+    #[case] join: JoinStyle,
+    #[case] vertex_count: usize,
+    #[case] expected_area: f64,
+) {
+    let result = offset_polygons(
+        &[rectangle(0.0, 0.0, 4.0, 4.0)],
+        1.0,
+        &OffsetStyle::new(join),
+    )
+    .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].len(), vertex_count);
+    assert!((area(&result) - expected_area).abs() < 1e-9);
+    assert!(result[0]
+        .iter()
+        .all(|p| p.x >= -1.0 && p.y >= -1.0 && p.x <= 5.0 && p.y <= 5.0));
+}
+
+#[rstest]
+fn test_offset_miter_limit() {
+    // sharp corner of a thin triangle is bevelled when the miter is too long
+    let triangle = vec![
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(0.0, 1.0),
+    ];
+    let mitered = offset_polygons(
+        std::slice::from_ref(&triangle),
+        0.5,
+        &OffsetStyle::default().with_limit(50.0),
+    )
+    .unwrap();
+    assert_eq!(mitered[0].len(), 3);
+    let bevelled = offset_polygons(&[triangle], 0.5, &OffsetStyle::default()).unwrap();
+    assert_eq!(bevelled[0].len(), 4);
+    assert!(area(&bevelled) < area(&mitered));
+}
+
+#[rstest]
+#[case::miter(JoinStyle::Miter)]
+#[case::round(JoinStyle::round())]
+fn test_offset_square_shrink(#[case] join: JoinStyle) {
+    let style = OffsetStyle::new(join);
+    let result = offset_polygons(&[rectangle(0.0, 0.0, 4.0, 4.0)], -1.0, &style).unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].len(), 4);
+    assert!((area(&result) - 4.0).abs() < 1e-12);
+    for p in rectangle(1.0, 1.0, 2.0, 2.0) {
+        assert!(result[0].contains(&p));
+    }
+
+    // polygon narrower than twice the distance vanishes
+    let result = offset_polygons(&[rectangle(0.0, 0.0, 4.0, 4.0)], -2.5, &style).unwrap();
+    assert!(result.is_empty());
+}
+
+#[rstest]
+fn test_offset_with_hole() {
+    let mut hole = rectangle(4.0, 4.0, 2.0, 2.0);
+    // orientation of input rings does not matter
+    hole.reverse();
+    let polygons = [rectangle(0.0, 0.0, 10.0, 10.0), hole];
+
+    // hole grows while the polygon shrinks
+    let result = offset_polygons(&polygons, -1.0, &OffsetStyle::default()).unwrap();
+    assert_eq!(result.len(), 2);
+    assert!((area(&result) - (64.0 - 16.0)).abs() < 1e-12);
+
+    // and closes when the polygon grows
+    let result = offset_polygons(&polygons, 1.5, &OffsetStyle::default()).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!((area(&result) - 169.0).abs() < 1e-12);
+}
+
+#[rstest]
+fn test_offset_merge_and_split() {
+    // nearby polygons merge when they grow
+    let polygons = [rectangle(0.0, 0.0, 2.0, 2.0), rectangle(3.0, 0.0, 2.0, 2.0)];
+    let result = offset_polygons(&polygons, 1.0, &OffsetStyle::default()).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!((area(&result) - 7.0 * 4.0).abs() < 1e-12);
+
+    // dumbbell splits at its narrow neck when it shrinks
+    let dumbbell = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 1.5),
+        Point::new(6.0, 1.5),
+        Point::new(6.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(10.0, 4.0),
+        Point::new(6.0, 4.0),
+        Point::new(6.0, 2.5),
+        Point::new(4.0, 2.5),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ];
+    let result = offset_polygons(&[dumbbell], -1.0, &OffsetStyle::default()).unwrap();
+    assert_eq!(result.len(), 2);
+    assert!((area(&result) - 2.0 * 4.0).abs() < 1e-12);
+}
+
+#[rstest]
+fn test_offset_concave_grow() {
+    // inner corner of an L shape stays sharp, outer corners are bevelled
+    let l_shape = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 2.0),
+        Point::new(2.0, 2.0),
+        Point::new(2.0, 4.0),
+        Point::new(0.0, 4.0),
+    ];
+    let result = offset_polygons(&[l_shape], 1.0, &OffsetStyle::new(JoinStyle::Bevel)).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains(&Point::new(3.0, 3.0)));
+    // L shape, strips along its edges, five bevelled corners and the overlap of strips
+    let expected = 12.0 + 16.0 + 5.0 * 0.5 - 1.0;
+    assert!((area(&result) - expected).abs() < 1e-12);
+}
+
+#[rstest]
+fn test_offset_zero_distance_and_f32() {
+    let mut square = rectangle(0.0, 0.0, 4.0, 4.0);
+    square.reverse();
+    let result = offset_polygons(&[square], 0.0, &OffsetStyle::default()).unwrap();
+    assert!((area(&result) - 16.0).abs() < 1e-12);
+
+    let square: Vec<Point<f32>> = rectangle(0.0, 0.0, 4.0, 4.0)
+        .into_iter()
+        .map(|p| p.cast())
+        .collect();
+    let result = offset_polygons(&[square], 0.5, &OffsetStyle::default()).unwrap();
+    assert!(result[0].contains(&Point::new(-0.5f32, -0.5)));
+}
+
+#[rstest]
+#[case::nan(f64::NAN)]
+#[case::infinity(f64::INFINITY)]
+#[case::negative_infinity(f64::NEG_INFINITY)]
+fn test_offset_non_finite_distance(#[case] distance: f64) {
+    let square = rectangle(0.0, 0.0, 4.0, 4.0);
+    let err = offset_polygons(&[square], distance, &OffsetStyle::default()).unwrap_err();
+    assert!(matches!(err, TriangulationError::NonFiniteDistance { .. }));
+    assert_eq!(err.polygon_index(), None);
+    assert!(err.to_string().contains("not finite"));
+}
@@ -1,6 +1,12 @@
+#![allow(clippy::excessive_precision)]
+
 use rstest::rstest;
 
-use triangulation::path_triangulation::triangulate_path_edge;
+use triangulation::path_triangulation::{
+    triangulate_path_edge, triangulate_path_edge_dashed, triangulate_path_edge_styled,
+    triangulate_path_edge_with_widths, triangulate_paths_edge_styled, CapStyle, DashPattern,
+    JoinStyle, PathStyle, PathTriangulation,
+};
 use triangulation::point::Point;
 
 #[rstest]
@@ -13,7 +19,440 @@ fn test_path_non_convex_polygon() {
         Point::new(19.27325521, 7.66883038),
         Point::new(15.83450076, 10.5778984),
     ];
-    let result = triangulate_path_edge(&polygon, true, 3.0, false);
+    let result = triangulate_path_edge(&polygon, true, 3.0, JoinStyle::Miter);
     assert_eq!(result.centers.len(), 16);
     assert_eq!(result.triangles.len(), 14);
 }
+
+#[rstest]
+fn test_paths_edge_styled() {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+        Point::new(10.0, 0.0),
+    ];
+    let styles = [
+        PathStyle::new(true, 3.0, JoinStyle::Miter),
+        PathStyle::new(false, 3.0, JoinStyle::Miter),
+        PathStyle::new(true, 3.0, JoinStyle::Bevel),
+    ];
+    let (result, vertex_offsets) =
+        triangulate_paths_edge_styled(&[path.clone(), path.clone(), path], &styles);
+    assert_eq!(vertex_offsets, vec![0, 10, 18, 32]);
+    assert_eq!(result.centers.len(), 32);
+    assert_eq!(result.offsets.len(), 32);
+    assert_eq!(result.triangles.len(), 8 + 6 + 12);
+    // triangles of each path refer only to its own vertices
+    let mut triangle_start = 0;
+    for (i, triangle_count) in [8, 6, 12].into_iter().enumerate() {
+        for triangle in &result.triangles[triangle_start..triangle_start + triangle_count] {
+            for idx in [triangle.x, triangle.y, triangle.z] {
+                assert!(vertex_offsets[i] <= idx && idx < vertex_offsets[i + 1]);
+            }
+        }
+        triangle_start += triangle_count;
+    }
+}
+
+fn vertices(result: &PathTriangulation) -> Vec<Point> {
+    result
+        .centers
+        .iter()
+        .zip(result.offsets.iter())
+        .map(|(&center, &offset)| center + offset * 2.0)
+        .collect()
+}
+
+fn triangles_area(result: &PathTriangulation) -> f32 {
+    let points = vertices(result);
+    result
+        .triangles
+        .iter()
+        .map(|t| {
+            let (p1, p2, p3) = (points[t.x], points[t.y], points[t.z]);
+            ((p2.x - p1.x) * (p3.y - p1.y) - (p2.y - p1.y) * (p3.x - p1.x)).abs() / 2.0
+        })
+        .sum()
+}
+
+#[rstest]
+#[case::butt(CapStyle::Butt, 20.0)]
+#[case::square(CapStyle::Square, 24.0)]
+// each cap is half of a regular 12-gon inscribed in a unit circle
+#[case::round(CapStyle::Round { segments: 6 }, 23.0)]
+fn test_path_cap_area(#[case] cap: CapStyle, #[case] expected: f32) {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    let style = PathStyle::new(false, 3.0, JoinStyle::Miter).with_cap(cap);
+    let result = triangulate_path_edge_styled(&path, &style);
+    assert!((triangles_area(&result) - expected).abs() < 1e-4);
+}
+
+#[rstest]
+fn test_path_square_cap() {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    let style = PathStyle::new(false, 3.0, JoinStyle::Miter).with_cap(CapStyle::Square);
+    let result = triangulate_path_edge_styled(&path, &style);
+    let butt = triangulate_path_edge(&path, false, 3.0, JoinStyle::Miter);
+    assert_eq!(result.centers.len(), butt.centers.len());
+    assert_eq!(result.triangles.len(), butt.triangles.len());
+    // with width 2 the path is extended by 1 on both ends
+    let xs: Vec<f32> = vertices(&result).iter().map(|p| p.x).collect();
+    assert_eq!(xs, vec![-1.0, -1.0, 11.0, 11.0]);
+}
+
+#[rstest]
+#[case::start(0.0, -1.0)]
+#[case::end(10.0, 1.0)]
+fn test_path_round_cap(#[case] end_x: f32, #[case] direction: f32) {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    let style =
+        PathStyle::new(false, 3.0, JoinStyle::Miter).with_cap(CapStyle::Round { segments: 6 });
+    let result = triangulate_path_edge_styled(&path, &style);
+    // 4 strip vertices plus center and 5 inner arc vertices for each cap
+    assert_eq!(result.centers.len(), 4 + 2 * 6);
+    assert_eq!(result.triangles.len(), 2 + 2 * 6);
+    let cap_vertices: Vec<Point> = result
+        .centers
+        .iter()
+        .zip(vertices(&result))
+        .filter(|(center, _)| center.x == end_x)
+        .map(|(_, vertex)| vertex)
+        .collect();
+    assert_eq!(cap_vertices.len(), 2 + 6);
+    for vertex in cap_vertices {
+        let dist = ((vertex.x - end_x).powi(2) + vertex.y.powi(2)).sqrt();
+        assert!(dist < 1e-5 || (dist - 1.0).abs() < 1e-5);
+        assert!((vertex.x - end_x) * direction >= -1e-5);
+    }
+}
+
+#[rstest]
+fn test_path_cap_ignored_for_closed_path() {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+    ];
+    let style = PathStyle::new(true, 3.0, JoinStyle::Miter).with_cap(CapStyle::round());
+    let result = triangulate_path_edge_styled(&path, &style);
+    let butt = triangulate_path_edge(&path, true, 3.0, JoinStyle::Miter);
+    assert_eq!(result.centers, butt.centers);
+    assert_eq!(result.triangles.len(), butt.triangles.len());
+}
+
+#[rstest]
+#[case::miter(JoinStyle::Miter, 10, 8, 80.0)]
+// inner vertex of bevel join is moved closer to the path
+#[case::bevel(JoinStyle::Bevel, 14, 12, 61.0)]
+// round join adds circular segments to the bevel join,
+// each corner of 90 degrees uses 4 of 8 half circle segments
+#[case::round(
+    JoinStyle::Round { segments: 8 },
+    14 + 4 * 3,
+    12 + 4 * 3,
+    61.0 + 4.0 * (2.0 * std::f32::consts::FRAC_PI_8.sin() - 0.5)
+)]
+fn test_path_join_style(
+    #[case] join: JoinStyle,
+    #[case] vertex_count: usize,
+    #[case] triangle_count: usize,
+    #[case] area: f32,
+) {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+        Point::new(10.0, 0.0),
+    ];
+    let result = triangulate_path_edge(&path, true, 3.0, join);
+    assert_eq!(result.centers.len(), vertex_count);
+    assert_eq!(result.triangles.len(), triangle_count);
+    assert!((triangles_area(&result) - area).abs() < 1e-3);
+}
+
+#[rstest]
+fn test_path_round_join_offsets_on_circle() {
+    let path: Vec<Point> = vec![
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(0.0, 1.0),
+    ];
+    let result = triangulate_path_edge(&path, false, 3.0, JoinStyle::round());
+    let bevel = triangulate_path_edge(&path, false, 3.0, JoinStyle::Bevel);
+    // sharp turn close to half circle uses all segments
+    assert_eq!(result.centers.len(), bevel.centers.len() + 7);
+    for offset in &result.offsets[5..12] {
+        let length = (offset.x * offset.x + offset.y * offset.y).sqrt();
+        assert!((length - 0.5).abs() < 1e-5);
+    }
+}
+
+#[rstest]
+fn test_path_constant_widths_scale_offsets() {
+    let path: Vec<Point> = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+        Point::new(10.0, 0.0),
+    ];
+    let style = PathStyle::new(true, 3.0, JoinStyle::Miter);
+    let unit = triangulate_path_edge_styled(&path, &style);
+    let result = triangulate_path_edge_with_widths(&path, &[3.0; 4], &style);
+    assert_eq!(result.centers, unit.centers);
+    for (offset, unit_offset) in result.offsets.iter().zip(unit.offsets.iter()) {
+        assert!((offset.x - unit_offset.x * 3.0).abs() < 1e-5);
+        assert!((offset.y - unit_offset.y * 3.0).abs() < 1e-5);
+    }
+}
+
+fn is_on_line(point: Point, start: Point, end: Point) -> bool {
+    let (a, b) = (end - start, point - start);
+    (a.x * b.y - a.y * b.x).abs() < 1e-4
+}
+
+#[rstest]
+fn test_path_variable_width_miter_join() {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(10.0, 10.0),
+    ];
+    let widths = [2.0, 4.0, 8.0];
+    let style = PathStyle::new(false, 10.0, JoinStyle::Miter);
+    let result = triangulate_path_edge_with_widths(&path, &widths, &style);
+    assert_eq!(result.centers.len(), 6);
+    // ends of the path have offsets of half of the local width
+    assert_eq!((result.offsets[0].x, result.offsets[0].y), (0.0, -1.0));
+    assert_eq!((result.offsets[4].x, result.offsets[4].y), (4.0, 0.0));
+    // miter vertices lie on edges of both segments
+    for (vertex, side) in [(2, 1.0), (3, -1.0)] {
+        let miter = result.centers[vertex] + result.offsets[vertex];
+        assert!(is_on_line(
+            miter,
+            Point::new(0.0, -side),
+            Point::new(10.0, -2.0 * side)
+        ));
+        assert!(is_on_line(
+            miter,
+            Point::new(10.0 + 2.0 * side, 0.0),
+            Point::new(10.0 + 4.0 * side, 10.0)
+        ));
+    }
+}
+
+#[rstest]
+#[should_panic(expected = "Number of path points and widths must be equal")]
+fn test_path_widths_wrong_length() {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    triangulate_path_edge_with_widths(&path, &[1.0], &PathStyle::default());
+}
+
+#[rstest]
+#[case::no_phase(0.0, vec![(0.0, 2.0), (3.0, 5.0), (6.0, 8.0), (9.0, 10.0)])]
+#[case::phase(1.0, vec![(0.0, 1.0), (2.0, 4.0), (5.0, 7.0), (8.0, 10.0)])]
+#[case::negative_phase(-1.0, vec![(1.0, 3.0), (4.0, 6.0), (7.0, 9.0)])]
+fn test_path_dashed(#[case] phase: f32, #[case] expected: Vec<(f32, f32)>) {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    let dash = DashPattern::new(vec![2.0, 1.0], phase);
+    let result = triangulate_path_edge_dashed(&path, &PathStyle::default(), &dash);
+    let arc_lengths = result.arc_lengths.unwrap();
+    assert_eq!(result.centers.len(), 4 * expected.len());
+    assert_eq!(result.triangles.len(), 2 * expected.len());
+    for (i, (start, end)) in expected.into_iter().enumerate() {
+        assert_eq!(arc_lengths[4 * i..4 * i + 4], [start, start, end, end]);
+        assert_eq!(result.centers[4 * i].x, start);
+        assert_eq!(result.centers[4 * i + 2].x, end);
+    }
+}
+
+#[rstest]
+fn test_path_dotted() {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    let dash = DashPattern::new(vec![0.0, 2.0], 0.0);
+    let style = PathStyle::default().with_cap(CapStyle::Round { segments: 4 });
+    let result = triangulate_path_edge_dashed(&path, &style, &dash);
+    // dots at 0, 2, 4, 6, 8 and 10, each is a full circle
+    assert_eq!(result.triangles.len(), 6 * (2 + 2 * 4));
+    let butt = triangulate_path_edge_dashed(&path, &PathStyle::default(), &dash);
+    assert!(butt.triangles.is_empty());
+}
+
+#[rstest]
+fn test_path_dashed_closed() {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+        Point::new(10.0, 0.0),
+    ];
+    let style = PathStyle::new(true, 3.0, JoinStyle::Miter);
+    let dash = DashPattern::new(vec![4.0, 2.0], 0.0);
+    let result = triangulate_path_edge_dashed(&path, &style, &dash);
+    // dashes crossing a corner (the merged one through the first point and 18..22)
+    // have additional join, other 4 dashes are single quads
+    assert_eq!(result.triangles.len(), 2 * 4 + 4 * 2);
+    let arc_lengths = result.arc_lengths.unwrap();
+    assert_eq!(arc_lengths.iter().copied().fold(0.0, f32::max), 44.0);
+}
+
+#[rstest]
+fn test_path_dashed_empty_pattern() {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+    ];
+    let style = PathStyle::default();
+    let result = triangulate_path_edge_dashed(&path, &style, &DashPattern::default());
+    let solid = triangulate_path_edge_styled(&path, &style);
+    assert_eq!(result.centers, solid.centers);
+    assert_eq!(
+        result.arc_lengths.unwrap(),
+        vec![0.0, 0.0, 10.0, 10.0, 20.0, 20.0]
+    );
+}
+
+#[rstest]
+#[case::miter(JoinStyle::Miter)]
+#[case::bevel(JoinStyle::Bevel)]
+#[case::round(JoinStyle::round())]
+fn test_path_attributes(#[case] join: JoinStyle) {
+    let path: Vec<Point> = vec![
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(10.0, 10.0),
+    ];
+    let style = PathStyle::new(false, 3.0, join).with_attributes(true);
+    let result = triangulate_path_edge_styled(&path, &style);
+    let arc_lengths = result.arc_lengths.unwrap();
+    let sides = result.sides.unwrap();
+    assert_eq!(arc_lengths.len(), result.centers.len());
+    assert_eq!(sides.len(), result.centers.len());
+    for ((center, arc_length), (offset, side)) in result
+        .centers
+        .iter()
+        .zip(arc_lengths)
+        .zip(result.offsets.iter().zip(sides))
+    {
+        // path turns left, so the inner side of the corner is the left one
+        let expected_arc_length = if center.y == 0.0 {
+            center.x
+        } else {
+            10.0 + center.y
+        };
+        assert_eq!(arc_length, expected_arc_length);
+        let expected_side = if center.x == 10.0 && center.y == 0.0 {
+            // corner, left vertices are inside the path
+            if offset.x < 0.0 {
+                1.0
+            } else {
+                -1.0
+            }
+        } else if center.y == 0.0 {
+            offset.y.signum()
+        } else {
+            -offset.x.signum()
+        };
+        assert_eq!(side, expected_side);
+    }
+}
+
+#[rstest]
+fn test_path_attributes_round_cap() {
+    let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+    let style = PathStyle::default()
+        .with_cap(CapStyle::Round { segments: 4 })
+        .with_attributes(true);
+    let result = triangulate_path_edge_styled(&path, &style);
+    // strip, then end cap (center and 3 arc vertices), then start cap
+    assert_eq!(
+        result.sides.unwrap(),
+        vec![-1.0, 1.0, -1.0, 1.0, 0.0, -1.0, 0.0, 1.0, 0.0, -1.0, 0.0, 1.0]
+    );
+    assert_eq!(
+        result.arc_lengths.unwrap(),
+        vec![0.0, 0.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 0.0, 0.0, 0.0, 0.0]
+    );
+}
+
+#[rstest]
+fn test_path_attributes_closed() {
+    let path = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+        Point::new(10.0, 0.0),
+    ];
+    let style = PathStyle::new(true, 3.0, JoinStyle::Miter).with_attributes(true);
+    let result = triangulate_path_edge_styled(&path, &style);
+    assert_eq!(
+        result.arc_lengths.unwrap(),
+        vec![0.0, 0.0, 10.0, 10.0, 20.0, 20.0, 30.0, 30.0, 40.0, 40.0]
+    );
+    // path is clockwise, so vertices inside the square are on the right side
+    for ((center, offset), side) in result
+        .centers
+        .iter()
+        .zip(result.offsets.iter())
+        .zip(result.sides.unwrap())
+    {
+        let vertex = *center + *offset;
+        let inside = 0.0 < vertex.x && vertex.x < 10.0 && 0.0 < vertex.y && vertex.y < 10.0;
+        assert_eq!(side, if inside { -1.0 } else { 1.0 });
+    }
+}
+
+#[rstest]
+fn test_paths_edge_styled_attributes() {
+    let path = vec![Point::new(0.0, 0.0), Point::new(0.0, 10.0)];
+    let styles = [
+        PathStyle::default().with_attributes(true),
+        PathStyle::default().with_attributes(true),
+    ];
+    let (result, _) = triangulate_paths_edge_styled(&[path.clone(), path.clone()], &styles);
+    assert_eq!(
+        result.arc_lengths.unwrap(),
+        vec![0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 10.0]
+    );
+    assert_eq!(result.sides.unwrap().len(), 8);
+    // attributes are dropped when not computed for all paths
+    let styles = [
+        PathStyle::default().with_attributes(true),
+        PathStyle::default(),
+    ];
+    let (result, _) = triangulate_paths_edge_styled(&[path.clone(), path], &styles);
+    assert!(result.arc_lengths.is_none());
+    assert!(result.sides.is_none());
+}
+
+#[rstest]
+#[case::miter(JoinStyle::Miter)]
+#[case::bevel(JoinStyle::Bevel)]
+#[case::round(JoinStyle::round())]
+fn test_path_f64_matches_f32(#[case] join: JoinStyle) {
+    let path: Vec<Point> = vec![
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(10.0, 10.0),
+        Point::new(2.0, 3.0),
+    ];
+    let path_f64: Vec<Point<f64>> = path.iter().map(|p| p.cast()).collect();
+    let style = PathStyle::new(false, 3.0, join).with_attributes(true);
+    let result = triangulate_path_edge_styled(&path, &style);
+    let result_f64 = triangulate_path_edge_styled(&path_f64, &style);
+    let triangles: Vec<_> = result.triangles.iter().map(|t| (t.x, t.y, t.z)).collect();
+    let triangles_f64: Vec<_> = result_f64
+        .triangles
+        .iter()
+        .map(|t| (t.x, t.y, t.z))
+        .collect();
+    assert_eq!(triangles_f64, triangles);
+    assert_eq!(result_f64.centers.len(), result.centers.len());
+    for (offset, offset_f64) in result.offsets.iter().zip(&result_f64.offsets) {
+        assert!((offset.x as f64 - offset_f64.x).abs() < 1e-5);
+        assert!((offset.y as f64 - offset_f64.y).abs() < 1e-5);
+    }
+    let sides: Vec<f64> = result.sides.unwrap().into_iter().map(f64::from).collect();
+    assert_eq!(result_f64.sides.unwrap(), sides);
+}
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use rstest::rstest;
+
+use triangulation::path_triangulation_3d::{
+    triangulate_path_ribbon, triangulate_path_tube, TubeStyle, TubeTriangulation,
+};
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn vertices(result: &TubeTriangulation<f64>, radius: f64) -> Vec<[f64; 3]> {
+    result
+        .centers
+        .iter()
+        .zip(&result.offsets)
+        .map(|(c, o)| [0, 1, 2].map(|k| c[k] + radius * o[k]))
+        .collect()
+}
+
+/// Volume enclosed by the triangles, positive if they are oriented outwards.
+fn volume(result: &TubeTriangulation<f64>, radius: f64) -> f64 {
+    let points = vertices(result, radius);
+    result
+        .triangles
+        .iter()
+        .map(|t| dot(points[t.x], cross(points[t.y], points[t.z])) / 6.0)
+        .sum()
+}
+
+/// Checks that each edge of a triangle is shared with exactly one other triangle,
+/// which uses it in the opposite direction.
+fn assert_closed_surface(result: &TubeTriangulation<f64>) {
+    let mut edges = HashMap::new();
+    for t in &result.triangles {
+        for edge in [(t.x, t.y), (t.y, t.z), (t.z, t.x)] {
+            *edges.entry(edge).or_insert(0) += 1;
+        }
+    }
+    for (&(a, b), &count) in &edges {
+        assert_eq!(count, 1, "edge {a}-{b} is repeated");
+        assert_eq!(edges.get(&(b, a)), Some(&1), "edge {a}-{b} is not shared");
+    }
+}
+
+fn polygon_area(segments: usize, radius: f64) -> f64 {
+    let angle = std::f64::consts::TAU / segments as f64;
+    segments as f64 / 2.0 * radius * radius * angle.sin()
+}
+
+#[rstest]
+#[case::x([1.0, 0.0, 0.0])]
+#[case::z([0.0, 0.0, 1.0])]
+#[case::oblique([1.0, -2.0, 3.0])]
+fn test_tube_straight(#[case] direction: [f64; 3]) {
+    let start = [1.0, 2.0, 3.0];
+    let path = vec![start, [0, 1, 2].map(|k| start[k] + 4.0 * direction[k])];
+    let style = TubeStyle::new(false, 6).with_caps(true);
+    let result = triangulate_path_tube(&path, &style);
+    assert_eq!(result.centers.len(), 2 * 6 + 2);
+    assert_eq!(result.triangles.len(), 2 * 6 + 2 * 6);
+    assert_closed_surface(&result);
+
+    // ring vertices lie on a circle around the path
+    for (center, offset) in result.centers.iter().zip(&result.offsets).take(12) {
+        assert!((dot(*offset, *offset) - 1.0).abs() < 1e-12);
+        assert!(dot(*offset, direction).abs() < 1e-12);
+        assert!(path.contains(center));
+    }
+    let length = 4.0 * dot(direction, direction).sqrt();
+    let expected = polygon_area(6, 0.5) * length;
+    assert!((volume(&result, 0.5) - expected).abs() < 1e-9);
+}
+
+#[rstest]
+fn test_tube_miter_joint() {
+    let path = vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 3.0, 0.0]];
+    let style = TubeStyle::new(false, 8).with_caps(true);
+    let result = triangulate_path_tube(&path, &style);
+    // a single ring at the joint
+    assert_eq!(result.centers.len(), 3 * 8 + 2);
+    assert_closed_surface(&result);
+
+    // the joint ring keeps the radius of both segments
+    for offset in &result.offsets[8..16] {
+        assert!((offset[1] * offset[1] + offset[2] * offset[2] - 1.0).abs() < 1e-12);
+        assert!((offset[0] * offset[0] + offset[2] * offset[2] - 1.0).abs() < 1e-12);
+    }
+    // mitered segments have the volume of straight ones with the same length
+    let expected = polygon_area(8, 1.0) * 7.0;
+    assert!((volume(&result, 1.0) - expected).abs() < 1e-9);
+}
+
+#[rstest]
+#[case::sharp_turn(vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 0.5, 0.0]], 3.0)]
+#[case::limit(vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 3.0, 0.0]], 1.2)]
+#[case::reversal(vec![[0.0, 0.0, 0.0], [0.0, 0.0, 4.0], [0.0, 0.0, 1.0]], 3.0)]
+fn test_tube_bevel_joint(#[case] path: Vec<[f64; 3]>, #[case] limit: f32) {
+    let style = TubeStyle::new(false, 8).with_limit(limit).with_caps(true);
+    let result = triangulate_path_tube(&path, &style);
+    // two rings at the joint, connected with a band of triangles
+    assert_eq!(result.centers.len(), 4 * 8 + 2);
+    assert_eq!(result.triangles.len(), 3 * 2 * 8 + 2 * 8);
+    assert_closed_surface(&result);
+    assert!(result.offsets.iter().all(|o| dot(*o, *o) <= 1.0 + 1e-12));
+}
+
+#[rstest]
+#[case::square(vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 4.0, 0.0], [0.0, 4.0, 0.0]], 4 * 8)]
+#[case::repeated_first(
+    vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 4.0, 0.0], [0.0, 0.0, 0.0]],
+    3 * 8
+)]
+#[case::skew(vec![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [4.0, 4.0, 1.0], [0.0, 4.0, 3.0]], 4 * 8)]
+#[case::bevelled_first(
+    vec![[0.0, 0.0, 0.0], [4.0, 0.5, 0.0], [4.0, -0.5, 0.0]],
+    4 * 8
+)]
+fn test_tube_closed(#[case] path: Vec<[f64; 3]>, #[case] vertex_count: usize) {
+    // caps are ignored for closed paths
+    let style = TubeStyle::new(true, 8).with_caps(true);
+    let result = triangulate_path_tube(&path, &style);
+    assert_eq!(result.centers.len(), vertex_count);
+    assert_closed_surface(&result);
+    assert!(volume(&result, 0.1) > 0.0);
+
+    // without twist, edges along the tube connect vertices at the same angle
+    for t in &result.triangles {
+        for (a, b) in [(t.x, t.y), (t.y, t.z), (t.z, t.x)] {
+            let axis = sub(result.centers[b], result.centers[a]);
+            if a % 8 != b % 8 || axis == [0.0; 3] {
+                continue;
+            }
+            let across = sub(result.offsets[b], result.offsets[a]);
+            let along = dot(across, axis) / dot(axis, axis);
+            let across = [0, 1, 2].map(|k| across[k] - along * axis[k]);
+            assert!(dot(across, across).sqrt() < 0.5, "twisted band {a}-{b}");
+        }
+    }
+}
+
+#[rstest]
+fn test_tube_f32_and_minimal_segments() {
+    let path: Vec<[f32; 3]> = vec![[0.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let result = triangulate_path_tube(&path, &TubeStyle::new(false, 1));
+    assert_eq!(result.centers.len(), 2 * 3);
+    assert_eq!(result.triangles.len(), 2 * 3);
+}
+
+#[rstest]
+#[case::empty(vec![])]
+#[case::single(vec![[1.0, 2.0, 3.0]])]
+#[case::repeated(vec![[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]])]
+fn test_path_3d_degenerate(#[case] path: Vec<[f64; 3]>) {
+    let tube = triangulate_path_tube(&path, &TubeStyle::default());
+    assert!(tube.centers.is_empty() && tube.triangles.is_empty());
+    let ribbon = triangulate_path_ribbon(&path, false);
+    assert!(ribbon.centers.is_empty() && ribbon.triangles.is_empty());
+}
+
+#[rstest]
+fn test_ribbon() {
+    let path = vec![
+        [0.0, 0.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [2.0, 2.0, 0.0],
+        [2.0, 2.0, 2.0],
+    ];
+    let result = triangulate_path_ribbon(&path, false);
+    assert_eq!(result.centers.len(), 2 * path.len());
+    // the repeated point does not produce triangles
+    assert_eq!(result.triangles.len(), 2 * 3);
+    for (i, p) in path.iter().enumerate() {
+        assert_eq!(result.centers[2 * i], *p);
+        assert_eq!(result.centers[2 * i + 1], *p);
+        assert_eq!(result.sides[2 * i..2 * i + 2], [-1.0, 1.0]);
+    }
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let expected_tangents = [
+        [1.0, 0.0, 0.0],
+        [s, s, 0.0],
+        [s, s, 0.0],
+        [0.0, s, s],
+        [0.0, 0.0, 1.0],
+    ];
+    for (i, expected) in expected_tangents.iter().enumerate() {
+        let (tangent, normal) = (result.tangents[2 * i], result.normals[2 * i]);
+        assert!((0..3).all(|k| (tangent[k] - expected[k]).abs() < 1e-12));
+        assert!((dot(normal, normal) - 1.0).abs() < 1e-12);
+        assert!(dot(normal, tangent).abs() < 1e-12);
+    }
+    // every triangle connects both sides of a segment
+    for t in &result.triangles {
+        let sides: f64 = [t.x, t.y, t.z].iter().map(|&i| result.sides[i]).sum();
+        assert_eq!(sides.abs(), 1.0);
+    }
+}
+
+#[rstest]
+#[case::open(false, 2 * 3)]
+#[case::closed(true, 2 * 4)]
+fn test_ribbon_closed(#[case] closed: bool, #[case] triangle_count: usize) {
+    let path: Vec<[f32; 3]> = vec![
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    let result = triangulate_path_ribbon(&path, closed);
+    assert_eq!(result.triangles.len(), triangle_count);
+    // on a planar path normals stay in its plane
+    assert!(result.normals.iter().all(|n| n[2] == 0.0));
+}
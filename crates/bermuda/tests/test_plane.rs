@@ -0,0 +1,126 @@
+use rstest::rstest;
+use triangulation::face_triangulation::triangulate_shape_face;
+use triangulation::plane::{project_to_plane, Plane};
+use triangulation::TriangulationError;
+
+/// Square with a square hole, on the plane through `origin` spanned by `a` and `b`.
+fn square_with_hole(origin: [f64; 3], a: [f64; 3], b: [f64; 3]) -> Vec<Vec<[f64; 3]>> {
+    let point = |s: f64, t: f64| [0, 1, 2].map(|k| origin[k] + s * a[k] + t * b[k]);
+    vec![
+        vec![
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 4.0),
+            point(0.0, 4.0),
+        ],
+        vec![
+            point(1.0, 1.0),
+            point(3.0, 1.0),
+            point(3.0, 3.0),
+            point(1.0, 3.0),
+        ],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[rstest]
+#[case::axis_x([5.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0])]
+#[case::axis_y([0.0, 5.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0])]
+#[case::axis_z([0.0, 0.0, 5.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0])]
+#[case::tilted([1.0, 2.0, 3.0], [0.6, 0.0, 0.8], [0.0, 1.0, 0.0])]
+#[case::oblique([100.0, -50.0, 20.0], [2.0, 1.0, -2.0], [1.0, 2.0, 2.0])]
+fn test_project_to_plane(#[case] origin: [f64; 3], #[case] a: [f64; 3], #[case] b: [f64; 3]) {
+    let rings = square_with_hole(origin, a, b);
+    let (projected, plane) = project_to_plane(&rings, None).unwrap();
+    assert_eq!(projected.len(), 2);
+
+    // frame is orthonormal and the plane contains the vertices
+    let Plane { normal, u, v, .. } = plane;
+    for (x, y, expected) in [
+        (normal, normal, 1.0),
+        (u, u, 1.0),
+        (v, v, 1.0),
+        (u, v, 0.0),
+        (normal, u, 0.0),
+        (normal, a, 0.0),
+        (normal, b, 0.0),
+    ] {
+        assert!((dot(x, y) - expected).abs() < 1e-12);
+    }
+    for (ring, projected) in rings.iter().zip(&projected) {
+        for (p, q) in ring.iter().zip(projected) {
+            assert!(plane.distance(*p).abs() < 1e-9);
+            let back = plane.unproject([q.x, q.y]);
+            assert!((0..3).all(|k| (back[k] - p[k]).abs() < 1e-9));
+        }
+    }
+
+    // projected shape keeps its area, so it triangulates in the same way
+    let (triangles, points) = triangulate_shape_face(&projected).unwrap();
+    assert_eq!(triangles.len(), 8);
+    let area: f64 = triangles
+        .iter()
+        .map(|t| {
+            let (p, q, r) = (points[t.x], points[t.y], points[t.z]);
+            ((q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)).abs() / 2.0
+        })
+        .sum();
+    let scale = dot(a, a).sqrt() * dot(b, b).sqrt();
+    assert!((area - 12.0 * scale).abs() < 1e-9 * scale);
+}
+
+#[rstest]
+#[case::x(0, [1, 2])]
+#[case::y(1, [0, 2])]
+#[case::z(2, [0, 1])]
+fn test_project_to_plane_axis_aligned_is_exact(#[case] axis: usize, #[case] kept: [usize; 2]) {
+    // coordinates which are not exact after rotation
+    let ring: Vec<[f32; 3]> = [[0.1f32, 0.3], [7.7, 0.2], [3.3, 9.1]]
+        .iter()
+        .map(|&[s, t]| {
+            let mut p = [0.0f32; 3];
+            let others: Vec<usize> = (0..3).filter(|&k| k != axis).collect();
+            p[axis] = 2.5;
+            p[others[0]] = s;
+            p[others[1]] = t;
+            p
+        })
+        .collect();
+    let (projected, plane) = project_to_plane(std::slice::from_ref(&ring), None).unwrap();
+    for (p, q) in ring.iter().zip(&projected[0]) {
+        assert_eq!([q.x, q.y], kept.map(|k| p[k]));
+        let back = plane.unproject([q.x as f64, q.y as f64]);
+        assert_eq!(back, p.map(|c| c as f64));
+    }
+}
+
+#[rstest]
+fn test_project_to_plane_not_planar() {
+    let mut rings = square_with_hole([0.0; 3], [1.0, 0.0, 1.0], [0.0, 1.0, 0.0]);
+    rings[1][2][2] += 0.5;
+    let err = project_to_plane(&rings, None).unwrap_err();
+    let TriangulationError::NotPlanar { polygon, deviation } = err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert_eq!(polygon, 1);
+    assert!(deviation > 0.1 && deviation < 0.5);
+    assert!(err.to_string().contains("not planar"));
+    assert_eq!(err.polygon_index(), Some(1));
+
+    // larger tolerance accepts the polygons
+    assert!(project_to_plane(&rings, Some(0.5)).is_ok());
+}
+
+#[rstest]
+fn test_project_to_plane_tolerates_rounding() {
+    // vertices of an oblique plane rounded to f32
+    let rings: Vec<Vec<[f32; 3]>> =
+        square_with_hole([1000.0, 2000.0, 3000.0], [0.3, 0.4, 1.2], [-0.4, 0.3, 0.0])
+            .into_iter()
+            .map(|ring| ring.into_iter().map(|p| p.map(|c| c as f32)).collect())
+            .collect();
+    assert!(project_to_plane(&rings, None).is_ok());
+}
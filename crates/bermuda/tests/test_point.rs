@@ -1,6 +1,7 @@
 use rstest::rstest;
 use std::collections::HashSet;
 use triangulation::point::{calc_dedup_edges, orientation, Orientation, Point, Segment, Vector};
+use triangulation::TriangulationError;
 
 #[rstest]
 fn test_segment_order() {
@@ -114,7 +115,7 @@ fn test_orientation(
     6
 )]
 fn test_calc_dedup_edges(#[case] input: &[Vec<Point>], #[case] expected_edge_count: usize) {
-    let result = calc_dedup_edges(input);
+    let result = calc_dedup_edges(input).unwrap();
     assert_eq!(result.len(), expected_edge_count);
 }
 
@@ -132,7 +133,7 @@ fn test_edge_deduplication() {
         Point::new(0.5, 1.0),
     ];
 
-    let edges = calc_dedup_edges(&[polygon1, polygon2]);
+    let edges = calc_dedup_edges(&[polygon1, polygon2]).unwrap();
 
     // The shared edge should be removed (appears twice)
     let expected_edges = {
@@ -169,7 +170,7 @@ fn test_edge_triple_occurrence() {
         ],
     ];
 
-    let edges = calc_dedup_edges(&polygons);
+    let edges = calc_dedup_edges(&polygons).unwrap();
 
     // Any edge that appears three times should be present in the final result once
     // Count edges connecting to Point(0.5, 1.0)
@@ -181,3 +182,73 @@ fn test_edge_triple_occurrence() {
 
     assert_eq!(edges_to_center, 4);
 }
+
+#[rstest]
+fn test_calc_dedup_edges_too_few_points() {
+    let polygons = vec![
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.5, 1.0),
+        ],
+        vec![Point::new(1.0, 0.0), Point::new(2.0, 0.0)],
+    ];
+    assert_eq!(
+        calc_dedup_edges(&polygons),
+        Err(TriangulationError::TooFewPoints {
+            polygon: 1,
+            count: 2
+        })
+    );
+}
+
+#[rstest]
+fn test_calc_dedup_edges_collinear() {
+    let polygons = vec![vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 2.0),
+    ]];
+    let err = calc_dedup_edges(&polygons).unwrap_err();
+    assert_eq!(err.polygon_index(), Some(0));
+    assert_eq!(err.vertex(), Some(Point::new(0.0, 0.0)));
+}
+
+#[rstest]
+fn test_point_cast() {
+    let point = Point::new(0.1_f64, -2.5);
+    assert_eq!(point.cast::<f32>(), Point::new(0.1_f32, -2.5));
+    assert_eq!(
+        point.cast::<f32>().cast::<f64>(),
+        Point::new(0.1_f32 as f64, -2.5)
+    );
+}
+
+#[rstest]
+fn test_point_coords_views() {
+    let coords = [1.0_f64, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let points = Point::slice_from_coords(&coords).unwrap();
+    assert_eq!(
+        points,
+        [
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 4.0),
+            Point::new(5.0, 6.0)
+        ]
+    );
+    assert_eq!(Point::slice_as_coords(points), coords);
+    assert!(Point::slice_from_coords(&coords[..5]).is_none());
+    assert!(Point::<f32>::slice_from_coords(&[]).unwrap().is_empty());
+}
+
+#[rstest]
+fn test_vec_into_coords() {
+    let mut points = Vec::with_capacity(5);
+    points.extend([Point::new(1.0_f32, 2.0), Point::new(3.0, 4.0)]);
+    let coords = Point::vec_into_coords(points);
+    assert_eq!(coords, [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(coords.capacity(), 10);
+
+    let vectors = vec![Vector::new(-1.0_f64, 0.5)];
+    assert_eq!(Vector::vec_into_coords(vectors), [-1.0, 0.5]);
+}
@@ -0,0 +1,217 @@
+//! Near-degenerate inputs for geometric predicates and algorithms using them.
+//!
+//! Inputs are generated with a fixed seed, so failures are reproducible.
+
+mod common;
+
+use common::area;
+use rstest::rstest;
+
+use triangulation::face_triangulation::{sweeping_line_triangulation, triangulate_shape_face};
+use triangulation::intersection::{do_intersect, find_intersection, Intersection};
+use triangulation::point::{calc_dedup_edges, orientation, Orientation, Point, Segment};
+use triangulation::predicates::orient2d;
+use triangulation::{is_convex, split_polygons_on_repeated_edges};
+
+/// Small deterministic xorshift generator.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Integer in `-range..=range`.
+    fn int(&mut self, range: i64) -> i64 {
+        (self.next() % (2 * range as u64 + 1)) as i64 - range
+    }
+}
+
+/// Point on a grid with spacing `2^-10`, so coordinates are exact in `f32`.
+fn grid_point(x: i64, y: i64) -> Point {
+    Point::new(x as f32 / 1024.0, y as f32 / 1024.0)
+}
+
+/// Exact orientation of grid points computed with integers.
+fn exact_orientation(p: (i64, i64), q: (i64, i64), r: (i64, i64)) -> Orientation {
+    let det = (q.0 - p.0) as i128 * (r.1 - p.1) as i128 - (q.1 - p.1) as i128 * (r.0 - p.0) as i128;
+    match det.signum() {
+        0 => Orientation::Collinear,
+        1 => Orientation::CounterClockwise,
+        _ => Orientation::Clockwise,
+    }
+}
+
+/// Triple of grid points, where the last one is close to the line through the first two.
+fn near_collinear_triple(rng: &mut Rng) -> [(i64, i64); 3] {
+    let p = (rng.int(1 << 20), rng.int(1 << 20));
+    let step = (rng.int(1 << 12), rng.int(1 << 12));
+    let q = (p.0 + step.0, p.1 + step.1);
+    let scale = rng.int(300);
+    let r = (
+        p.0 + step.0 * scale + rng.int(1),
+        p.1 + step.1 * scale + rng.int(1),
+    );
+    [p, q, r]
+}
+
+#[rstest]
+fn test_orient2d_near_collinear_grid() {
+    // points near (0.5, 0.5) on a grid of the smallest representable steps
+    let step = f64::EPSILON / 2.0;
+    let q = Point::new(12.0, 12.0);
+    let r = Point::new(24.0, 24.0);
+    for i in 0..64 {
+        for j in 0..64 {
+            let p = Point::new(0.5 + i as f64 * step, 0.5 + j as f64 * step);
+            let expected = match j.cmp(&i) {
+                std::cmp::Ordering::Equal => Orientation::Collinear,
+                std::cmp::Ordering::Greater => Orientation::CounterClockwise,
+                std::cmp::Ordering::Less => Orientation::Clockwise,
+            };
+            assert_eq!(orientation(p, q, r), expected, "i={i}, j={j}");
+            assert_eq!(orientation(q, r, p), expected, "i={i}, j={j}");
+        }
+    }
+}
+
+#[rstest]
+fn test_orient2d_matches_exact_integer_arithmetic() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    let mut collinear = 0;
+    for _ in 0..20000 {
+        let [p, q, r] = near_collinear_triple(&mut rng);
+        let expected = exact_orientation(p, q, r);
+        if expected == Orientation::Collinear {
+            collinear += 1;
+        }
+        let (p, q, r) = (
+            grid_point(p.0, p.1),
+            grid_point(q.0, q.1),
+            grid_point(r.0, r.1),
+        );
+        assert_eq!(orientation(p, q, r), expected, "{p} {q} {r}");
+        // orientation is invariant to rotation and flips on swap of points
+        assert_eq!(orientation(q, r, p), expected, "{p} {q} {r}");
+        let expected_flipped = match expected {
+            Orientation::Clockwise => Orientation::CounterClockwise,
+            Orientation::CounterClockwise => Orientation::Clockwise,
+            Orientation::Collinear => Orientation::Collinear,
+        };
+        assert_eq!(orientation(q, p, r), expected_flipped, "{p} {q} {r}");
+        assert_eq!(orient2d(p, r, q) == 0.0, expected == Orientation::Collinear);
+    }
+    // the corpus exercises the exactly collinear case
+    assert!(collinear > 1000);
+}
+
+#[rstest]
+fn test_find_intersection_near_degenerate() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..20000 {
+        let [p, q, r] = near_collinear_triple(&mut rng);
+        let s = (rng.int(1 << 20), rng.int(1 << 20));
+        if p == r || q == s {
+            continue;
+        }
+        let s1 = Segment::new(grid_point(p.0, p.1), grid_point(r.0, r.1));
+        let s2 = Segment::new(grid_point(q.0, q.1), grid_point(s.0, s.1));
+        let intersect = do_intersect(&s1, &s2);
+        assert_eq!(intersect, do_intersect(&s2, &s1), "{s1} {s2}");
+        match find_intersection(&s1, &s2) {
+            Intersection::PointIntersection(point) => {
+                assert!(intersect, "{s1} {s2}");
+                for segment in [&s1, &s2] {
+                    assert!(segment.bottom.y <= point.y && point.y <= segment.top.y);
+                    assert!(
+                        segment.bottom.x.min(segment.top.x) <= point.x
+                            && point.x <= segment.bottom.x.max(segment.top.x)
+                    );
+                }
+            }
+            Intersection::CollinearWithOverlap(_) => assert!(intersect, "{s1} {s2}"),
+            Intersection::NoIntersection | Intersection::CollinearNoOverlap => {
+                assert!(!intersect, "{s1} {s2}")
+            }
+        }
+    }
+}
+
+/// Fan of nearly collinear vertices closed by a point far below them.
+fn near_collinear_fan(rng: &mut Rng) -> Vec<Point> {
+    let count = 3 + (rng.next() % 20) as i64;
+    let slope = (rng.int(64), 64 + rng.int(32));
+    let mut polygon: Vec<Point> = (0..count)
+        .map(|i| {
+            let x = i * slope.1 * 16;
+            grid_point(x, i * slope.0 * 16 + rng.int(1))
+        })
+        .collect();
+    polygon.push(grid_point(count * slope.1 * 8, -(1 << 16)));
+    polygon
+}
+
+/// Star-shaped polygon with vertices snapped to a coarse grid,
+/// which creates collinear and nearly collinear edges.
+fn snapped_star(rng: &mut Rng) -> Vec<Point> {
+    let count = 5 + (rng.next() % 30) as usize;
+    let mut polygon: Vec<Point> = (0..count)
+        .map(|i| {
+            let angle = i as f64 / count as f64 * std::f64::consts::TAU;
+            let radius = 8.0 + rng.int(4) as f64;
+            let snap = |value: f64| (value * 2.0).round() as i64 * 512;
+            grid_point(snap(radius * angle.cos()), snap(radius * angle.sin()))
+        })
+        .collect();
+    polygon.dedup();
+    polygon
+}
+
+#[rstest]
+fn test_is_convex_near_collinear_fan() {
+    let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+    for _ in 0..2000 {
+        let polygon = near_collinear_fan(&mut rng);
+        let expected = polygon.windows(3).all(|window| {
+            orientation(window[0], window[1], window[2]) != Orientation::CounterClockwise
+        });
+        if expected {
+            assert!(is_convex(&polygon), "{polygon:?}");
+        }
+    }
+}
+
+#[rstest]
+#[case::fan(near_collinear_fan as fn(&mut Rng) -> Vec<Point>)]
+#[case::star(snapped_star as fn(&mut Rng) -> Vec<Point>)]
+fn test_triangulation_near_degenerate_corpus(#[case] generate: fn(&mut Rng) -> Vec<Point>) {
+    let mut rng = Rng(0x8CB9_2BA7_2F3D_8DD7);
+    for _ in 0..2000 {
+        let polygon = generate(&mut rng);
+        if polygon.len() < 3 {
+            continue;
+        }
+        // none of the functions may panic, input may be rejected with an error
+        if calc_dedup_edges(std::slice::from_ref(&polygon)).is_err() {
+            continue;
+        }
+        let (triangles, points) = triangulate_shape_face(std::slice::from_ref(&polygon))
+            .unwrap_or_else(|err| panic!("{err} for {polygon:?}"));
+        let triangulated: f64 = triangles
+            .iter()
+            .map(|t| area(&[vec![points[t.x], points[t.y], points[t.z]]]).abs())
+            .sum();
+        let expected = area(std::slice::from_ref(&polygon)).abs();
+        assert!(
+            (triangulated - expected).abs() <= expected * 1e-5,
+            "area {triangulated} != {expected} for {polygon:?}"
+        );
+
+        let (_, segments) = split_polygons_on_repeated_edges(std::slice::from_ref(&polygon))
+            .unwrap_or_else(|err| panic!("{err} for {polygon:?}"));
+        sweeping_line_triangulation(segments).unwrap_or_else(|err| panic!("{err} for {polygon:?}"));
+    }
+}
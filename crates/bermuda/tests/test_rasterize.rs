@@ -0,0 +1,163 @@
+mod common;
+
+use common::rectangle;
+use rstest::rstest;
+use triangulation::contour::trace_label_boundaries;
+use triangulation::rasterize::{rasterize_polygons, rasterize_shapes, RasterMode};
+use triangulation::Point;
+
+fn rasterize(polygons: &[Vec<Point<f64>>], shape: [usize; 2], mode: RasterMode) -> Vec<u8> {
+    let mut image = vec![0; shape[0] * shape[1]];
+    rasterize_polygons(polygons, 1, &mut image, shape, mode);
+    image
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter, [1, 1, 1, 1, 1, 0, 1, 0, 0])]
+#[case::any_touch(RasterMode::AnyTouch, [1, 1, 1, 1, 1, 1, 1, 1, 0])]
+fn test_rasterize_triangle(#[case] mode: RasterMode, #[case] expected: [u8; 9]) {
+    let triangle = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 3.0),
+        Point::new(3.0, 0.0),
+    ];
+    assert_eq!(rasterize(&[triangle], [3, 3], mode), expected);
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter, 0)]
+#[case::any_touch(RasterMode::AnyTouch, 1)]
+fn test_rasterize_inside_pixel(#[case] mode: RasterMode, #[case] expected: u8) {
+    // small square which does not contain the center of its pixel
+    let image = rasterize(&[rectangle(1.1, 1.1, 0.2, 0.2)], [3, 3], mode);
+    assert_eq!(image[4], expected);
+    assert_eq!(
+        image.iter().map(|&v| v as usize).sum::<usize>(),
+        expected as usize
+    );
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter, 16 - 4)]
+#[case::any_touch(RasterMode::AnyTouch, 16 - 4)]
+fn test_rasterize_hole(#[case] mode: RasterMode, #[case] expected: usize) {
+    let outer = rectangle(-0.5, -0.5, 4.0, 4.0);
+    let mut hole = rectangle(0.5, 0.5, 2.0, 2.0);
+    hole.reverse();
+    let image = rasterize(&[outer, hole], [4, 4], mode);
+    assert_eq!(image.iter().filter(|&&v| v == 1).count(), expected);
+    assert_eq!(image[5], 0);
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter)]
+#[case::any_touch(RasterMode::AnyTouch)]
+fn test_rasterize_repeated_ring(#[case] mode: RasterMode) {
+    // identical rings cancel with the even-odd rule
+    let square = rectangle(0.2, 0.2, 2.0, 2.0);
+    let image = rasterize(&[square.clone(), square], [3, 3], mode);
+    assert!(image.iter().all(|&v| v == 0));
+}
+
+#[rstest]
+fn test_rasterize_shapes_sharing_edge() {
+    // pixel centers on the shared edge belong to one of the shapes
+    let shapes = [
+        vec![rectangle(0.0, 0.0, 4.0, 2.0)],
+        vec![rectangle(0.0, 2.0, 4.0, 2.0)],
+    ];
+    let mut image = vec![0u16; 16];
+    rasterize_shapes(
+        &shapes,
+        &[1, 2],
+        &mut image,
+        [4, 4],
+        RasterMode::PixelCenter,
+    );
+    assert_eq!(image.iter().filter(|&&v| v == 1).count(), 8);
+    assert_eq!(image.iter().filter(|&&v| v == 2).count(), 8);
+    assert_eq!(&image[..4], &[1, 1, 2, 2]);
+
+    // pixels overlapping both shapes get the label of the later one
+    let mut image = vec![0u16; 16];
+    rasterize_shapes(&shapes[..1], &[1], &mut image, [4, 4], RasterMode::AnyTouch);
+    assert_eq!(&image[..4], &[1, 1, 1, 0]);
+    rasterize_shapes(&shapes, &[1, 2], &mut image, [4, 4], RasterMode::AnyTouch);
+    assert_eq!(&image[..4], &[1, 1, 2, 2]);
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter)]
+#[case::any_touch(RasterMode::AnyTouch)]
+fn test_rasterize_clips_to_image(#[case] mode: RasterMode) {
+    let mut image = vec![7i32; 6];
+    rasterize_polygons(
+        &[rectangle(-10.0, 0.5, 20.0, 20.0)],
+        -1,
+        &mut image,
+        [2, 3],
+        mode,
+    );
+    assert_eq!(image, [7, -1, -1, 7, -1, -1]);
+    let mut image = vec![false; 6];
+    rasterize_polygons(
+        &[rectangle(5.0, 5.0, 1.0, 1.0)],
+        true,
+        &mut image,
+        [2, 3],
+        mode,
+    );
+    assert!(image.iter().all(|&v| !v));
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter)]
+#[case::any_touch(RasterMode::AnyTouch)]
+fn test_rasterize_traced_boundaries(#[case] mode: RasterMode) {
+    // pixel-exact boundaries of labels are rasterized back into the same image
+    let mut seed = 5u64;
+    let (rows, columns) = (30, 20);
+    let labels: Vec<u8> = (0..rows * columns)
+        .map(|_| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % 4) as u8
+        })
+        .collect();
+    let boundaries = trace_label_boundaries::<_, f32>(&labels, [rows, columns], 0);
+    let mut image = vec![0u8; rows * columns];
+    for (&label, rings) in &boundaries {
+        rasterize_polygons(rings, label, &mut image, [rows, columns], mode);
+    }
+    assert_eq!(image, labels);
+}
+
+#[rstest]
+#[case::pixel_center(RasterMode::PixelCenter)]
+#[case::any_touch(RasterMode::AnyTouch)]
+fn test_rasterize_non_finite_vertices(#[case] mode: RasterMode) {
+    // vertices with NaN or infinite coordinates are skipped
+    let mut square = rectangle(-0.5, -0.5, 2.0, 2.0);
+    square.insert(2, Point::new(f64::NAN, 1.0));
+    square.insert(4, Point::new(f64::INFINITY, f64::NAN));
+    let image = rasterize(
+        &[square, vec![Point::new(f64::NAN, f64::NAN); 3]],
+        [3, 3],
+        mode,
+    );
+    assert_eq!(image, [1, 1, 0, 1, 1, 0, 0, 0, 0]);
+}
+
+#[rstest]
+#[should_panic(expected = "number of labels")]
+fn test_rasterize_shapes_label_count() {
+    let mut image = vec![0u8; 4];
+    rasterize_shapes(
+        &[vec![rectangle(0.0, 0.0, 1.0, 1.0)]],
+        &[1, 2],
+        &mut image,
+        [2, 2],
+        RasterMode::PixelCenter,
+    );
+}
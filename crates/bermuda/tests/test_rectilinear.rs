@@ -0,0 +1,83 @@
+mod common;
+
+use common::rectangle;
+use rstest::rstest;
+use triangulation::rectilinear::triangulate_rectilinear_face;
+use triangulation::{Point, TriangulationError};
+
+/// Signed area of the triangles, positive for counterclockwise ones.
+fn triangulated_area(rings: &[Vec<Point<f64>>]) -> f64 {
+    let (triangles, points) = triangulate_rectilinear_face(rings).unwrap();
+    triangles
+        .iter()
+        .map(|t| {
+            let (p, q, r) = (points[t.x], points[t.y], points[t.z]);
+            (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+#[rstest]
+fn test_rectangle() {
+    let (triangles, points) =
+        triangulate_rectilinear_face(&[rectangle(0.0, 0.0, 3.0, 2.0)]).unwrap();
+    assert_eq!(triangles.len(), 2);
+    assert_eq!(points.len(), 4);
+}
+
+#[rstest]
+#[case::hole(vec![rectangle(0.0, 0.0, 4.0, 4.0), rectangle(1.0, 1.0, 2.0, 2.0)], 12.0)]
+#[case::overlapping(vec![rectangle(0.0, 0.0, 2.0, 2.0), rectangle(1.0, 1.0, 2.0, 2.0)], 6.0)]
+#[case::touching(vec![rectangle(0.0, 0.0, 2.0, 2.0), rectangle(2.0, 0.0, 1.0, 2.0)], 6.0)]
+#[case::repeated(vec![rectangle(0.0, 0.0, 2.0, 2.0), rectangle(0.0, 0.0, 2.0, 2.0)], 0.0)]
+fn test_even_odd(#[case] rings: Vec<Vec<Point<f64>>>, #[case] expected_area: f64) {
+    assert!((triangulated_area(&rings) - expected_area).abs() < 1e-12);
+}
+
+#[rstest]
+fn test_merges_rectangles() {
+    // collinear vertices and a clockwise ring do not change the result
+    let ring = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 2.0),
+        Point::new(0.0, 2.0),
+        Point::new(5.0, 2.0),
+        Point::new(5.0, 0.0),
+        Point::new(3.0, 0.0),
+    ];
+    let (triangles, points) = triangulate_rectilinear_face(&[ring]).unwrap();
+    assert_eq!(triangles.len(), 2);
+    assert_eq!(points.len(), 4);
+}
+
+#[rstest]
+fn test_not_rectilinear() {
+    let ring = vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 2.0),
+    ];
+    let result = triangulate_rectilinear_face(&[rectangle(5.0, 5.0, 1.0, 1.0), ring]);
+    assert_eq!(
+        result.unwrap_err(),
+        TriangulationError::NotRectilinear {
+            polygon: 1,
+            vertex: Point::new(2.0, 2.0)
+        }
+    );
+}
+
+#[rstest]
+fn test_empty_and_f32() {
+    let (triangles, points) = triangulate_rectilinear_face::<f32>(&[]).unwrap();
+    assert!(triangles.is_empty() && points.is_empty());
+
+    let square: Vec<Point<f32>> = rectangle(0.5, 0.5, 1.0, 1.0)
+        .into_iter()
+        .map(|p| p.cast())
+        .collect();
+    let (triangles, points) = triangulate_rectilinear_face(&[square]).unwrap();
+    assert_eq!(triangles.len(), 2);
+    assert!(points.contains(&Point::new(1.5f32, 1.5)));
+}
@@ -0,0 +1,151 @@
+use rstest::rstest;
+use triangulation::intersection::{calc_edges, find_intersections};
+use triangulation::simplify::{simplify_path, simplify_polygons, SimplifyMethod, SimplifyStyle};
+use triangulation::Point;
+
+fn points(coords: &[(f64, f64)]) -> Vec<Point<f64>> {
+    coords.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+/// Contour of pixels below the diagonal of a square, like a contour of a label.
+fn staircase(size: usize) -> Vec<Point<f64>> {
+    let mut ring = vec![Point::new(0.0, 0.0), Point::new(size as f64, 0.0)];
+    for k in (1..size).rev() {
+        ring.push(Point::new((k + 1) as f64, (size - k) as f64));
+        ring.push(Point::new(k as f64, (size - k) as f64));
+    }
+    ring.push(Point::new(1.0, size as f64));
+    ring.push(Point::new(0.0, size as f64));
+    ring
+}
+
+#[rstest]
+#[case::douglas_peucker(SimplifyMethod::DouglasPeucker, 0.5)]
+#[case::visvalingam_whyatt(SimplifyMethod::VisvalingamWhyatt, 0.5)]
+fn test_simplify_open_path(#[case] method: SimplifyMethod, #[case] tolerance: f64) {
+    let path = points(&[(0.0, 0.0), (1.0, 0.2), (2.0, -0.2), (3.0, 0.0), (3.0, 3.0)]);
+    let simplified = simplify_path(&path, false, tolerance, &SimplifyStyle::new(method));
+    assert_eq!(simplified, points(&[(0.0, 0.0), (3.0, 0.0), (3.0, 3.0)]));
+}
+
+#[rstest]
+#[case::douglas_peucker(SimplifyMethod::DouglasPeucker)]
+#[case::visvalingam_whyatt(SimplifyMethod::VisvalingamWhyatt)]
+fn test_simplify_zero_tolerance(#[case] method: SimplifyMethod) {
+    // only collinear and repeated vertices are removed
+    let path = points(&[
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (1.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (0.0, 1.0),
+        (0.0, 0.0),
+    ]);
+    let style = SimplifyStyle::new(method);
+    let simplified = simplify_path(&path, true, 0.0, &style);
+    assert_eq!(
+        simplified,
+        points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)])
+    );
+    let simplified = simplify_path(&path, false, 0.0, &style);
+    assert_eq!(simplified.len(), 5);
+}
+
+#[rstest]
+#[case::douglas_peucker(SimplifyMethod::DouglasPeucker, 1.0)]
+#[case::visvalingam_whyatt(SimplifyMethod::VisvalingamWhyatt, 1.0)]
+fn test_simplify_staircase(#[case] method: SimplifyMethod, #[case] tolerance: f64) {
+    let ring = staircase(100);
+    assert_eq!(ring.len(), 202);
+    let simplified = simplify_polygons(&[ring], tolerance, &SimplifyStyle::new(method));
+    assert_eq!(simplified.len(), 1);
+    assert!(simplified[0].len() <= 4, "{:?}", simplified[0]);
+    assert!(simplified[0].contains(&Point::new(0.0, 0.0)));
+    assert!(simplified[0].contains(&Point::new(100.0, 0.0)));
+}
+
+#[rstest]
+#[case::douglas_peucker(SimplifyMethod::DouglasPeucker)]
+#[case::visvalingam_whyatt(SimplifyMethod::VisvalingamWhyatt)]
+fn test_simplify_keeps_triangle(#[case] method: SimplifyMethod) {
+    let ring = points(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    let simplified = simplify_polygons(&[ring], 100.0, &SimplifyStyle::new(method));
+    assert_eq!(simplified[0].len(), 3);
+}
+
+fn crossing_count(rings: &[Vec<Point<f64>>]) -> usize {
+    find_intersections(&calc_edges(rings)).len()
+}
+
+#[rstest]
+#[case::douglas_peucker(SimplifyMethod::DouglasPeucker, 1.0)]
+#[case::visvalingam_whyatt(SimplifyMethod::VisvalingamWhyatt, 1.0)]
+fn test_simplify_preserve_topology_self_intersection(
+    #[case] method: SimplifyMethod,
+    #[case] tolerance: f64,
+) {
+    // small bump of the bottom edge contains the tip of a notch from the top edge
+    let ring = points(&[
+        (0.0, 0.0),
+        (4.0, 0.0),
+        (5.0, -0.5),
+        (6.0, 0.0),
+        (10.0, 0.0),
+        (10.0, 10.0),
+        (5.5, 10.0),
+        (5.0, -0.2),
+        (4.5, 10.0),
+        (0.0, 10.0),
+    ]);
+    let polygons = [ring];
+    assert_eq!(crossing_count(&polygons), 0);
+
+    let style = SimplifyStyle::new(method);
+    let simplified = simplify_polygons(&polygons, tolerance, &style);
+    assert!(!simplified[0].contains(&Point::new(5.0, -0.5)));
+    assert!(crossing_count(&simplified) > 0);
+
+    let simplified = simplify_polygons(&polygons, tolerance, &style.with_preserve_topology(true));
+    assert!(simplified[0].contains(&Point::new(5.0, -0.5)));
+    assert_eq!(crossing_count(&simplified), 0);
+    assert!(simplified[0].len() < polygons[0].len());
+}
+
+#[rstest]
+fn test_simplify_preserve_topology_between_rings() {
+    // hole in a small bump of the outer ring
+    let outer = points(&[
+        (0.0, 0.0),
+        (4.0, 0.0),
+        (4.0, -0.5),
+        (6.0, -0.5),
+        (6.0, 0.0),
+        (10.0, 0.0),
+        (10.0, 10.0),
+        (0.0, 10.0),
+    ]);
+    let hole = points(&[(4.5, -0.3), (5.5, -0.3), (5.5, 0.3), (4.5, 0.3)]);
+    let polygons = [outer, hole];
+
+    let style = SimplifyStyle::default();
+    let simplified = simplify_polygons(&polygons, 1.0, &style);
+    assert_eq!(simplified[0].len(), 4);
+    assert_eq!(simplified[1].len(), 3);
+    assert!(crossing_count(&simplified) > 0);
+
+    let simplified = simplify_polygons(&polygons, 1.0, &style.with_preserve_topology(true));
+    assert!(simplified[0].contains(&Point::new(4.0, -0.5)));
+    assert_eq!(crossing_count(&simplified), 0);
+}
+
+#[rstest]
+fn test_simplify_f32_and_short_paths() {
+    let path: Vec<Point<f32>> = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)];
+    assert_eq!(
+        simplify_path(&path, false, 1.0, &SimplifyStyle::default()),
+        path
+    );
+    let empty: Vec<Point<f32>> = vec![];
+    assert!(simplify_path(&empty, true, 1.0, &SimplifyStyle::default()).is_empty());
+}
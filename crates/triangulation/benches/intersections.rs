@@ -0,0 +1,66 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use triangulation::intersection::{calc_edges, find_intersections};
+use triangulation::point::Point;
+use triangulation::split_polygons_on_repeated_edges;
+
+const EDGES: usize = 100_000;
+
+/// Long, nearly horizontal closed contour, like a traced boundary of an elongated cell.
+/// Most of its edges cross any horizontal line within its height.
+fn horizontal_contour(size: usize) -> Vec<Vec<Point>> {
+    let half = size / 2;
+    let wiggle = |i: usize| ((i * 7919) % 13) as f32 * 0.05;
+    let top = (0..half).map(|i| Point::new(i as f32, 10.0 + wiggle(i)));
+    let bottom = (0..half)
+        .rev()
+        .map(|i| Point::new(i as f32 + 0.5, wiggle(i + 1)));
+    vec![top.chain(bottom).collect()]
+}
+
+/// Grid of overlapping circles, each crossing its neighbors.
+fn overlapping_circles(size: usize) -> Vec<Vec<Point>> {
+    let vertices = 100;
+    let side = ((size / vertices) as f64).sqrt().ceil() as usize;
+    (0..size / vertices)
+        .map(|i| {
+            let center = ((i % side) as f32 * 3.0, (i / side) as f32 * 3.0);
+            (0..vertices)
+                .map(|j| {
+                    let angle = j as f32 / vertices as f32 * std::f32::consts::TAU;
+                    Point::new(center.0 + 2.0 * angle.cos(), center.1 + 2.0 * angle.sin())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn bench_intersections(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_intersections");
+    group.sample_size(10);
+    for (name, polygons) in [
+        ("horizontal_contour", horizontal_contour(EDGES)),
+        ("overlapping_circles", overlapping_circles(EDGES)),
+    ] {
+        let edges = calc_edges(&polygons);
+        group.bench_with_input(BenchmarkId::new(name, edges.len()), &edges, |b, edges| {
+            b.iter(|| find_intersections(black_box(edges)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("split_polygons_on_repeated_edges");
+    group.sample_size(10);
+    for (name, polygons) in [
+        ("horizontal_contour", horizontal_contour(EDGES)),
+        ("overlapping_circles", overlapping_circles(EDGES)),
+    ] {
+        group.bench_with_input(BenchmarkId::new(name, EDGES), &polygons, |b, polygons| {
+            b.iter(|| split_polygons_on_repeated_edges(black_box(polygons)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_intersections);
+criterion_main!(benches);
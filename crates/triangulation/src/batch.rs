@@ -0,0 +1,27 @@
+//! Helpers for processing independent elements of batched functions.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Applies `f` to every element of `items` and collects results.
+///
+/// When the `parallel` feature is enabled elements are processed in parallel.
+/// The order of results always matches the order of `items`,
+/// so the output is deterministic regardless of the feature.
+#[cfg(feature = "parallel")]
+pub(crate) fn map_in_order<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    items.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn map_in_order<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    F: Fn(&T) -> R,
+{
+    items.iter().map(f).collect()
+}
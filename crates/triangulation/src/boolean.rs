@@ -0,0 +1,120 @@
+//! Boolean operations on sets of polygons.
+//!
+//! Each set is a list of rings interpreted with the even-odd rule, so holes
+//! are given as additional rings, like for face triangulation.
+
+use crate::error::TriangulationError;
+use crate::planar_graph::{join_rings, label_left_sides, ring_edges, split_rings};
+use crate::point::{Float, Point, Segment};
+use std::collections::HashMap;
+
+type Polygons<T> = Vec<Vec<Point<T>>>;
+
+/// Operation combining two sets of polygons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// Area covered by any of the sets.
+    Union,
+    /// Area covered by both sets.
+    Intersection,
+    /// Area covered by the subject set and not by the clip set.
+    Difference,
+    /// Area covered by exactly one of the sets.
+    Xor,
+}
+
+impl BooleanOp {
+    fn apply(self, subject: bool, clip: bool) -> bool {
+        match self {
+            BooleanOp::Union => subject || clip,
+            BooleanOp::Intersection => subject && clip,
+            BooleanOp::Difference => subject && !clip,
+            BooleanOp::Xor => subject != clip,
+        }
+    }
+}
+
+/// Computes a boolean operation on two sets of polygons.
+///
+/// Edges of both sets are split at their intersections, then each edge is labeled
+/// with the membership of the area on its left side, which is propagated from
+/// the neighboring edge on the left in a sweep line. Edges separating area inside
+/// and outside of the result are joined into rings.
+///
+/// # Arguments
+/// * `subject` - Rings of the first set of polygons
+/// * `clip` - Rings of the second set of polygons
+/// * `op` - Operation to compute
+///
+/// # Returns
+/// Rings of the result, with the area on the left side of each ring,
+/// so outer rings are counterclockwise and holes are clockwise.
+/// Rings touching at a vertex are returned separately.
+///
+/// # Errors
+/// Returns [`TriangulationError::UnresolvedIntersection`] if edges
+/// of the polygons cannot be split into non-crossing edges.
+///
+/// # Example
+/// ```
+/// use triangulation::boolean::{polygon_boolean, BooleanOp};
+/// use triangulation::Point;
+///
+/// let square = |x: f32, y: f32| {
+///     vec![Point::new(x, y), Point::new(x + 2.0, y), Point::new(x + 2.0, y + 2.0), Point::new(x, y + 2.0)]
+/// };
+/// let result = polygon_boolean(&[square(0.0, 0.0)], &[square(1.0, 1.0)], BooleanOp::Intersection).unwrap();
+/// assert_eq!(result.len(), 1);
+/// assert_eq!(result[0].len(), 4);
+/// assert!(result[0].contains(&Point::new(1.0, 1.0)) && result[0].contains(&Point::new(2.0, 2.0)));
+/// ```
+pub fn polygon_boolean<T: Float>(
+    subject: &[impl AsRef<[Point<T>]>],
+    clip: &[impl AsRef<[Point<T>]>],
+    op: BooleanOp,
+) -> Result<Polygons<T>, TriangulationError<T>> {
+    let rings = split_rings(
+        subject
+            .iter()
+            .map(AsRef::as_ref)
+            .chain(clip.iter().map(AsRef::as_ref)),
+    )?;
+
+    // even-odd membership of edges, edges repeated an even number of times cancel out
+    let mut membership: HashMap<Segment<T>, (bool, bool)> = HashMap::new();
+    for (index, ring) in rings.iter().enumerate() {
+        for (start, end) in ring_edges(ring) {
+            let entry = membership.entry(Segment::new(start, end)).or_default();
+            if index < subject.len() {
+                entry.0 = !entry.0;
+            } else {
+                entry.1 = !entry.1;
+            }
+        }
+    }
+    let mut edges: Vec<(Segment<T>, (bool, bool))> = membership
+        .into_iter()
+        .filter(|(_, (in_subject, in_clip))| *in_subject || *in_clip)
+        .collect();
+    // hash map iteration order is random, keep the result deterministic
+    edges.sort_by_key(|(segment, _)| (segment.top, segment.bottom));
+    let (segments, membership): (Vec<_>, Vec<_>) = edges.into_iter().unzip();
+
+    let left_sides = label_left_sides(&segments, (false, false), |i, (subject, clip)| {
+        (subject != membership[i].0, clip != membership[i].1)
+    });
+
+    let boundary = segments.iter().zip(membership).zip(left_sides).filter_map(
+        |((segment, (in_subject, in_clip)), (subject_left, clip_left))| {
+            let inside_left = op.apply(subject_left, clip_left);
+            let inside_right = op.apply(subject_left != in_subject, clip_left != in_clip);
+            // keep the result on the left side of the edge
+            match (inside_left, inside_right) {
+                (true, false) => Some((segment.bottom, segment.top)),
+                (false, true) => Some((segment.top, segment.bottom)),
+                _ => None,
+            }
+        },
+    );
+    Ok(join_rings(boundary))
+}
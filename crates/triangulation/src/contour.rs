@@ -0,0 +1,297 @@
+//! Contours of regions of label images, extracted with marching squares
+//! or traced along pixel edges.
+
+use std::collections::BTreeMap;
+
+use crate::point::{Float, Point};
+
+type Polygons<T> = Vec<Vec<Point<T>>>;
+/// Point with doubled coordinates, so midpoints between pixel centers are integers.
+type GridPoint = (i64, i64);
+
+/// Corners of the marching squares cell with the top left pixel at `(row, column)`,
+/// counterclockwise in `(row, column)` coordinates.
+fn cell_corners(row: i64, column: i64) -> [GridPoint; 4] {
+    [
+        (row, column),
+        (row + 1, column),
+        (row + 1, column + 1),
+        (row, column + 1),
+    ]
+}
+
+/// Directed contour segments of `label` in the cell with corners `corners`,
+/// with the pixels of the label on the left side.
+///
+/// Contours cross cell edges at their midpoints. In cells where the label covers
+/// two diagonal corners, the corners are separated, so regions are 4-connected.
+fn cell_segments<L: Copy + PartialEq>(
+    corners: [GridPoint; 4],
+    values: [Option<L>; 4],
+    label: L,
+    mut add: impl FnMut(GridPoint, GridPoint),
+) {
+    let inside = values.map(|value| value == Some(label));
+    let midpoint = |edge: usize| {
+        let (a, b) = (corners[edge], corners[(edge + 1) % 4]);
+        (a.0 + b.0, a.1 + b.1)
+    };
+    for exit in 0..4 {
+        if !inside[exit] || inside[(exit + 1) % 4] {
+            continue;
+        }
+        // the contour leaves the cell through the closest edge entering the label
+        // clockwise from the exit edge, which cuts off the corner of the exit edge
+        let entry = (1..4)
+            .map(|k| (exit + 4 - k) % 4)
+            .find(|&edge| !inside[edge] && inside[(edge + 1) % 4])
+            .unwrap();
+        add(midpoint(exit), midpoint(entry));
+    }
+}
+
+/// Joins directed segments into rings, removing vertices between collinear segments.
+fn join_segments<T: Float>(mut next: BTreeMap<GridPoint, GridPoint>) -> Polygons<T> {
+    let mut rings = Vec::new();
+    while let Some((start, mut current)) = next.pop_first() {
+        let mut ring = vec![start];
+        while current != start {
+            ring.push(current);
+            current = next.remove(&current).unwrap();
+        }
+        let n = ring.len();
+        let is_corner = |i: usize| {
+            let (a, b, c) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            (b.0 - a.0) * (c.1 - b.1) != (b.1 - a.1) * (c.0 - b.0)
+        };
+        let to_point = |(x, y): GridPoint| {
+            Point::new(T::from_f64(x as f64 / 2.0), T::from_f64(y as f64 / 2.0))
+        };
+        rings.push(
+            (0..n)
+                .filter(|&i| is_corner(i))
+                .map(|i| to_point(ring[i]))
+                .collect(),
+        );
+    }
+    rings
+}
+
+/// Extracts contours of all labeled regions of a 2D label image with marching squares.
+///
+/// Pixel `(row, column)` is `labels[row * columns + column]` and lies at point
+/// `Point::new(row, column)`, so points follow the `(row, column)` order of image
+/// coordinates. Contours pass through midpoints between pixels of the label and
+/// their neighbors, like iso-lines at level 0.5 of the label mask. Pixels touching
+/// only diagonally belong to separate regions, and vertices between collinear
+/// contour segments are removed.
+///
+/// # Arguments
+/// * `labels` - Label of each pixel, in row-major order
+/// * `shape` - Number of rows and columns of the image
+/// * `background` - Label of pixels which are not part of any region
+///
+/// # Returns
+/// Rings of contours of each label, in increasing order of labels. Rings do not
+/// touch or cross each other. Outer boundaries of regions are counterclockwise and
+/// boundaries of holes are clockwise, so the area of the label is on the left side
+/// of each ring, and the rings can be triangulated with the even-odd rule.
+///
+/// # Panics
+/// Panics if the length of `labels` is not equal to the number of pixels.
+///
+/// # Example
+/// ```
+/// use triangulation::contour::label_contours;
+/// use triangulation::Point;
+///
+/// let labels = [
+///     0, 1, 1,
+///     0, 1, 2,
+/// ];
+/// let contours = label_contours::<_, f32>(&labels, [2, 3], 0);
+/// assert_eq!(contours.len(), 2);
+/// assert_eq!(
+///     contours[&2],
+///     vec![vec![
+///         Point::new(0.5, 2.0), Point::new(1.0, 1.5), Point::new(1.5, 2.0), Point::new(1.0, 2.5)
+///     ]]
+/// );
+/// ```
+pub fn label_contours<L: Copy + Ord, T: Float>(
+    labels: &[L],
+    shape: [usize; 2],
+    background: L,
+) -> BTreeMap<L, Polygons<T>> {
+    let [rows, columns] = shape;
+    assert_eq!(
+        labels.len(),
+        rows * columns,
+        "labels do not match the image shape"
+    );
+    let value = |(row, column): GridPoint| {
+        let inside = (0..rows as i64).contains(&row) && (0..columns as i64).contains(&column);
+        inside
+            .then(|| labels[row as usize * columns + column as usize])
+            .filter(|&label| label != background)
+    };
+
+    let mut segments: BTreeMap<L, BTreeMap<GridPoint, GridPoint>> = BTreeMap::new();
+    // cells cover the image with a margin of one pixel, so all contours are closed
+    for row in -1..rows as i64 {
+        for column in -1..columns as i64 {
+            let corners = cell_corners(row, column);
+            let values = corners.map(value);
+            for (k, label) in values.iter().enumerate() {
+                let Some(label) = *label else { continue };
+                if values[..k].contains(&Some(label)) {
+                    continue;
+                }
+                let next = segments.entry(label).or_default();
+                cell_segments(corners, values, label, |start, end| {
+                    next.insert(start, end);
+                });
+            }
+        }
+    }
+    segments
+        .into_iter()
+        .map(|(label, next)| (label, join_segments(next)))
+        .collect()
+}
+
+/// Unit steps along pixel edges in doubled coordinates, counterclockwise
+/// in `(row, column)` coordinates, so `(direction + 1) % 4` turns left.
+const STEPS: [GridPoint; 4] = [(2, 0), (0, 2), (-2, 0), (0, -2)];
+
+/// Removes and returns the outgoing edge at `vertex` reached by an edge in direction
+/// `incoming`, preferring a left turn, then going straight, then a right turn.
+///
+/// At vertices shared by two diagonal pixels of the label the left turn keeps
+/// following the same pixel, so the pixels are not joined into one region.
+fn take_turn(
+    edges: &mut BTreeMap<GridPoint, Vec<usize>>,
+    vertex: GridPoint,
+    incoming: usize,
+) -> Option<usize> {
+    let outgoing = edges.get_mut(&vertex)?;
+    let k = [1, 0, 3]
+        .into_iter()
+        .find_map(|turn| {
+            let direction = (incoming + turn) % 4;
+            outgoing.iter().position(|&d| d == direction)
+        })
+        .unwrap();
+    let direction = outgoing.swap_remove(k);
+    if outgoing.is_empty() {
+        edges.remove(&vertex);
+    }
+    Some(direction)
+}
+
+/// Joins directed pixel edges into rings, keeping only vertices where the direction changes.
+fn join_pixel_edges<T: Float>(mut edges: BTreeMap<GridPoint, Vec<usize>>) -> Polygons<T> {
+    let to_point =
+        |(x, y): GridPoint| Point::new(T::from_f64(x as f64 / 2.0), T::from_f64(y as f64 / 2.0));
+    let mut rings = Vec::new();
+    // the smallest vertex is a convex corner with a single outgoing edge,
+    // as all pixels of the label lie above or to the right of it
+    while let Some((&start, directions)) = edges.iter().next() {
+        let first = directions[0];
+        take_turn(&mut edges, start, (first + 3) % 4);
+        let mut ring = vec![to_point(start)];
+        let (mut vertex, mut incoming) = (start, first);
+        loop {
+            vertex = (vertex.0 + STEPS[incoming].0, vertex.1 + STEPS[incoming].1);
+            let Some(direction) = take_turn(&mut edges, vertex, incoming) else {
+                break;
+            };
+            if direction != incoming {
+                ring.push(to_point(vertex));
+            }
+            incoming = direction;
+        }
+        rings.push(ring);
+    }
+    rings
+}
+
+/// Traces the pixel boundaries of all labeled regions of a 2D label image.
+///
+/// Pixels are unit squares centered at `Point::new(row, column)`, like in
+/// [`label_contours`], so the rings enclose exactly the pixels of each label.
+/// Rings are rectilinear, with vertices only at corners, and can be triangulated
+/// with [`triangulate_rectilinear_face`](crate::rectilinear::triangulate_rectilinear_face).
+/// Pixels touching only diagonally belong to separate rings, which touch at the
+/// common corner.
+///
+/// # Arguments
+/// * `labels` - Label of each pixel, in row-major order
+/// * `shape` - Number of rows and columns of the image
+/// * `background` - Label of pixels which are not part of any region
+///
+/// # Returns
+/// Rings of the boundaries of each label, in increasing order of labels. Outer
+/// boundaries of regions are counterclockwise and boundaries of holes are clockwise.
+///
+/// # Panics
+/// Panics if the length of `labels` is not equal to the number of pixels.
+///
+/// # Example
+/// ```
+/// use triangulation::contour::trace_label_boundaries;
+/// use triangulation::Point;
+///
+/// let labels = [
+///     0, 1, 1,
+///     0, 1, 0,
+/// ];
+/// let boundaries = trace_label_boundaries::<_, f32>(&labels, [2, 3], 0);
+/// assert_eq!(boundaries[&1][0].len(), 6);
+/// assert!(boundaries[&1][0].contains(&Point::new(-0.5, 2.5)));
+/// ```
+pub fn trace_label_boundaries<L: Copy + Ord, T: Float>(
+    labels: &[L],
+    shape: [usize; 2],
+    background: L,
+) -> BTreeMap<L, Polygons<T>> {
+    let [rows, columns] = shape;
+    assert_eq!(
+        labels.len(),
+        rows * columns,
+        "labels do not match the image shape"
+    );
+    let value = |row: i64, column: i64| {
+        let inside = (0..rows as i64).contains(&row) && (0..columns as i64).contains(&column);
+        inside.then(|| labels[row as usize * columns + column as usize])
+    };
+
+    let mut edges: BTreeMap<L, BTreeMap<GridPoint, Vec<usize>>> = BTreeMap::new();
+    for row in 0..rows as i64 {
+        for column in 0..columns as i64 {
+            let label = labels[row as usize * columns + column as usize];
+            if label == background {
+                continue;
+            }
+            // sides of the pixel counterclockwise from the bottom left corner,
+            // with the neighbor across each side
+            let (x, y) = (2 * row - 1, 2 * column - 1);
+            let sides = [
+                ((x, y), (row, column - 1)),
+                ((x + 2, y), (row + 1, column)),
+                ((x + 2, y + 2), (row, column + 1)),
+                ((x, y + 2), (row - 1, column)),
+            ];
+            for (direction, (start, (r, c))) in sides.into_iter().enumerate() {
+                if value(r, c) != Some(label) {
+                    let label_edges = edges.entry(label).or_default();
+                    label_edges.entry(start).or_default().push(direction);
+                }
+            }
+        }
+    }
+    edges
+        .into_iter()
+        .map(|(label, edges)| (label, join_pixel_edges(edges)))
+        .collect()
+}
@@ -0,0 +1,693 @@
+//! Constrained Delaunay triangulation by edge flipping.
+//!
+//! Triangulations produced by the sweep line contain many thin triangles.
+//! Flipping edges which are not locally Delaunay (Lawson's algorithm) turns any
+//! triangulation into a constrained Delaunay triangulation of the same points,
+//! which maximizes the minimum angle among triangulations keeping the constraint edges.
+//!
+//! Delaunay refinement (Ruppert's algorithm with Chew's circumcenter insertion)
+//! goes further and inserts Steiner points until triangles satisfy bounds on
+//! their minimum angle and maximum area.
+
+use crate::point::{orientation, Float, Index, Orientation, Point, Segment, Triangle};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const NONE: usize = usize::MAX;
+
+/// Half of the `f64` machine epsilon, the relative rounding error of a single operation.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ICC_ERR_BOUND_A: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// Undirected edge between two points, given by their indices.
+type Edge = (Index, Index);
+
+fn edge(a: Index, b: Index) -> Edge {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Whether `d` lies inside of the circumcircle of the counterclockwise triangle `a`, `b`, `c`.
+///
+/// Returns `false` when rounding errors could change the answer, so points on
+/// the circle are never reported and flips based on the answer cannot cycle.
+fn is_in_circumcircle<T: Float>(a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>) -> bool {
+    let (adx, ady) = (a.x.to_f64() - d.x.to_f64(), a.y.to_f64() - d.y.to_f64());
+    let (bdx, bdy) = (b.x.to_f64() - d.x.to_f64(), b.y.to_f64() - d.y.to_f64());
+    let (cdx, cdy) = (c.x.to_f64() - d.x.to_f64(), c.y.to_f64() - d.y.to_f64());
+
+    let (bdx_cdy, cdx_bdy) = (bdx * cdy, cdx * bdy);
+    let (cdx_ady, adx_cdy) = (cdx * ady, adx * cdy);
+    let (adx_bdy, bdx_ady) = (adx * bdy, bdx * ady);
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+
+    let det =
+        a_lift * (bdx_cdy - cdx_bdy) + b_lift * (cdx_ady - adx_cdy) + c_lift * (adx_bdy - bdx_ady);
+    let permanent = (bdx_cdy.abs() + cdx_bdy.abs()) * a_lift
+        + (cdx_ady.abs() + adx_cdy.abs()) * b_lift
+        + (adx_bdy.abs() + bdx_ady.abs()) * c_lift;
+    det > ICC_ERR_BOUND_A * permanent
+}
+
+/// Triangle mesh with adjacency of triangles over their edges.
+struct Mesh<T> {
+    points: Vec<Point<T>>,
+    /// Triangles as indices of points in counterclockwise order.
+    triangles: Vec<[Index; 3]>,
+    /// Triangles on both sides of each edge, [`NONE`] on the boundary.
+    adjacent: HashMap<Edge, [usize; 2]>,
+    constraints: HashSet<Edge>,
+    /// Triangles changed by flips and insertions, collected for refinement.
+    touched: Vec<usize>,
+}
+
+impl<T: Float> Mesh<T> {
+    fn new(points: Vec<Point<T>>, triangles: &[Triangle], constraints: &[Segment<T>]) -> Self {
+        let triangles: Vec<[Index; 3]> = triangles
+            .iter()
+            .map(|t| {
+                if orientation(points[t.x], points[t.y], points[t.z]) == Orientation::Clockwise {
+                    [t.x, t.z, t.y]
+                } else {
+                    [t.x, t.y, t.z]
+                }
+            })
+            .collect();
+        let mut adjacent: HashMap<Edge, [usize; 2]> = HashMap::new();
+        for (i, triangle) in triangles.iter().enumerate() {
+            for k in 0..3 {
+                let sides = adjacent
+                    .entry(edge(triangle[k], triangle[(k + 1) % 3]))
+                    .or_insert([NONE; 2]);
+                if sides[0] == NONE {
+                    sides[0] = i;
+                } else {
+                    sides[1] = i;
+                }
+            }
+        }
+        let indices: HashMap<Point<T>, Index> =
+            points.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+        let constraints = constraints
+            .iter()
+            .filter_map(|segment| {
+                Some(edge(
+                    *indices.get(&segment.bottom)?,
+                    *indices.get(&segment.top)?,
+                ))
+            })
+            .collect();
+        Mesh {
+            points,
+            triangles,
+            adjacent,
+            constraints,
+            touched: Vec::new(),
+        }
+    }
+
+    /// Vertices `[a, b, c]` of triangle `t` ordered so that `a -> b` is the edge `e`.
+    fn rotated(&self, t: usize, e: Edge) -> [Index; 3] {
+        let triangle = self.triangles[t];
+        let k = (0..3)
+            .find(|&k| triangle[k] != e.0 && triangle[k] != e.1)
+            .unwrap();
+        [triangle[(k + 1) % 3], triangle[(k + 2) % 3], triangle[k]]
+    }
+
+    fn is_degenerate(&self, t: usize) -> bool {
+        let [a, b, c] = self.triangles[t];
+        orientation(self.points[a], self.points[b], self.points[c]) != Orientation::CounterClockwise
+    }
+
+    /// Edges of triangle `t` in counterclockwise order.
+    fn directed_edges(&self, t: usize) -> [(Index, Index); 3] {
+        let [a, b, c] = self.triangles[t];
+        [(a, b), (b, c), (c, a)]
+    }
+
+    /// Triangle on the other side of edge `e` of triangle `t`, [`NONE`] on the boundary.
+    fn neighbor(&self, t: usize, e: Edge) -> usize {
+        match self.adjacent.get(&e) {
+            Some(&[t1, t2]) if t1 == t => t2,
+            Some(&[t1, _]) => t1,
+            None => NONE,
+        }
+    }
+
+    fn replace_adjacent(&mut self, e: Edge, from: usize, to: usize) {
+        if let Some(sides) = self.adjacent.get_mut(&e) {
+            for side in sides.iter_mut().filter(|side| **side == from) {
+                *side = to;
+            }
+        }
+    }
+
+    /// Bowyer-Watson cavity of point `p`, the triangles whose circumcircle contains `p`
+    /// reachable from triangles `start` without crossing constraints.
+    ///
+    /// Returns triangles of the cavity and its boundary as counterclockwise
+    /// edges together with the triangle of the cavity they belong to.
+    fn cavity(&self, start: &[usize], p: Point<T>) -> (Vec<usize>, Vec<(Index, Index, usize)>) {
+        let mut cavity = start.to_vec();
+        let mut inside: HashSet<usize> = start.iter().copied().collect();
+        let mut k = 0;
+        while k < cavity.len() {
+            let t = cavity[k];
+            k += 1;
+            for (u, v) in self.directed_edges(t) {
+                let e = edge(u, v);
+                let n = self.neighbor(t, e);
+                if self.constraints.contains(&e)
+                    || n == NONE
+                    || inside.contains(&n)
+                    || self.is_degenerate(n)
+                {
+                    continue;
+                }
+                let [a, b, c] = self.triangles[n];
+                if is_in_circumcircle(self.points[a], self.points[b], self.points[c], p) {
+                    inside.insert(n);
+                    cavity.push(n);
+                }
+            }
+        }
+        let boundary = cavity
+            .iter()
+            .flat_map(|&t| self.directed_edges(t).map(|(u, v)| (u, v, t)))
+            .filter(|&(u, v, t)| !inside.contains(&self.neighbor(t, edge(u, v))))
+            .collect();
+        (cavity, boundary)
+    }
+
+    /// Replaces triangles of `cavity` by triangles connecting the new point `p`
+    /// to edges of its `boundary`, except for the edge `skip` on which `p` lies.
+    ///
+    /// Edges of the cavity have to be visible from `p`.
+    fn insert(
+        &mut self,
+        p: Point<T>,
+        cavity: &[usize],
+        boundary: &[(Index, Index, usize)],
+        skip: Option<Edge>,
+    ) -> Index {
+        let index = self.points.len();
+        self.points.push(p);
+        let inside: HashSet<usize> = cavity.iter().copied().collect();
+        for &t in cavity {
+            for (u, v) in self.directed_edges(t) {
+                let e = edge(u, v);
+                if Some(e) == skip || inside.contains(&self.neighbor(t, e)) {
+                    self.adjacent.remove(&e);
+                }
+            }
+        }
+        let mut slots = cavity.iter().copied();
+        let mut stack = Vec::new();
+        for &(u, v, old) in boundary {
+            if Some(edge(u, v)) == skip {
+                continue;
+            }
+            let t = slots.next().unwrap_or_else(|| {
+                self.triangles.push([u, v, index]);
+                self.triangles.len() - 1
+            });
+            self.triangles[t] = [u, v, index];
+            self.replace_adjacent(edge(u, v), old, t);
+            for e in [edge(v, index), edge(index, u)] {
+                let sides = self.adjacent.entry(e).or_insert([NONE; 2]);
+                if sides[0] == NONE {
+                    sides[0] = t;
+                } else {
+                    sides[1] = t;
+                }
+            }
+            self.touched.push(t);
+            stack.push(edge(u, v));
+        }
+        // cavity may miss triangles when the circumcircle test is uncertain
+        self.legalize(stack);
+        index
+    }
+
+    /// Flips edges from `stack`, and edges affected by the flips,
+    /// until all of them are locally Delaunay or constrained.
+    fn legalize(&mut self, mut stack: Vec<Edge>) {
+        while let Some(e) = stack.pop() {
+            if self.constraints.contains(&e) {
+                continue;
+            }
+            let Some(&[t1, t2]) = self.adjacent.get(&e) else {
+                continue;
+            };
+            if t2 == NONE || self.is_degenerate(t1) || self.is_degenerate(t2) {
+                continue;
+            }
+            let [a, b, c] = self.rotated(t1, e);
+            let [_, _, d] = self.rotated(t2, e);
+            let p = |i: Index| self.points[i];
+            if !is_in_circumcircle(p(a), p(b), p(c), p(d)) {
+                continue;
+            }
+            // quadrilateral has to be convex to flip its diagonal
+            if orientation(p(c), p(a), p(d)) != Orientation::CounterClockwise
+                || orientation(p(d), p(b), p(c)) != Orientation::CounterClockwise
+            {
+                continue;
+            }
+            self.triangles[t1] = [c, a, d];
+            self.triangles[t2] = [d, b, c];
+            self.adjacent.remove(&e);
+            self.adjacent.insert(edge(c, d), [t1, t2]);
+            self.replace_adjacent(edge(a, d), t2, t1);
+            self.replace_adjacent(edge(b, c), t1, t2);
+            self.touched.extend([t1, t2]);
+            stack.extend([edge(a, d), edge(d, b), edge(b, c), edge(c, a)]);
+        }
+    }
+}
+
+/// Flips edges of a triangulation until it is a constrained Delaunay triangulation.
+///
+/// Edges listed in `constraints` are never flipped, so boundaries of polygons
+/// are kept. Triangles of the result are in counterclockwise order.
+/// Points close to being cocircular are left as they are, so the result
+/// may differ from the exact constrained Delaunay triangulation for such points.
+///
+/// # Arguments
+/// * `triangles` - Triangles referring to indices of `points`
+/// * `points` - Vertices of the triangulation
+/// * `constraints` - Edges which have to be kept, usually edges of the triangulated polygons
+///
+/// # Example
+/// ```
+/// use triangulation::delaunay::flip_to_constrained_delaunay;
+/// use triangulation::{Point, Triangle};
+///
+/// // thin triangles split by the longer diagonal of a rhombus are replaced
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(4.0, 1.0),
+///     Point::new(8.0, 0.0),
+///     Point::new(4.0, -1.0),
+/// ];
+/// let triangles = vec![Triangle::new(0, 3, 2), Triangle::new(0, 2, 1)];
+/// let result = flip_to_constrained_delaunay(&triangles, &points, &[]);
+/// // by triangles split by the shorter one
+/// assert!(result.iter().all(|t| [t.x, t.y, t.z].contains(&1) && [t.x, t.y, t.z].contains(&3)));
+/// ```
+pub fn flip_to_constrained_delaunay<T: Float>(
+    triangles: &[Triangle],
+    points: &[Point<T>],
+    constraints: &[Segment<T>],
+) -> Vec<Triangle> {
+    let mut mesh = Mesh::new(points.to_vec(), triangles, constraints);
+    let mut edges: Vec<Edge> = mesh.adjacent.keys().copied().collect();
+    // order of flips decides between nearly cocircular points, keep it deterministic
+    edges.sort_unstable_by(|a, b| b.cmp(a));
+    mesh.legalize(edges);
+    mesh.triangles
+        .into_iter()
+        .map(|[a, b, c]| Triangle::new(a, b, c))
+        .collect()
+}
+
+/// Largest supported minimum angle of [`Refinement`], in degrees.
+///
+/// Refinement is guaranteed to finish for angles up to about 20.7 degrees,
+/// larger angles usually work, but may insert many more points.
+pub const MAX_MIN_ANGLE: f64 = 30.0;
+
+/// Default limit of Steiner points inserted by [`refine_delaunay`].
+pub const DEFAULT_MAX_STEINER_POINTS: usize = 1 << 20;
+
+/// Bounds on the shape and size of triangles created by [`refine_delaunay`].
+///
+/// # Fields
+/// * `min_angle` - Minimum angle of triangles in degrees, clamped to [`MAX_MIN_ANGLE`].
+/// * `max_area` - Maximum area of triangles, `None` for no limit.
+/// * `max_steiner_points` - Largest number of inserted points, after which
+///   refinement stops even if some triangles violate the bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Refinement {
+    pub min_angle: f64,
+    pub max_area: Option<f64>,
+    pub max_steiner_points: usize,
+}
+
+impl Refinement {
+    pub fn new(min_angle: f64) -> Self {
+        Refinement {
+            min_angle,
+            max_area: None,
+            max_steiner_points: DEFAULT_MAX_STEINER_POINTS,
+        }
+    }
+
+    pub fn with_max_area(self, max_area: f64) -> Self {
+        Refinement {
+            max_area: Some(max_area),
+            ..self
+        }
+    }
+
+    pub fn with_max_steiner_points(self, max_steiner_points: usize) -> Self {
+        Refinement {
+            max_steiner_points,
+            ..self
+        }
+    }
+}
+
+impl Default for Refinement {
+    fn default() -> Self {
+        Refinement::new(20.0)
+    }
+}
+
+/// State of Delaunay refinement of a constrained Delaunay triangulation.
+struct Refiner<T> {
+    mesh: Mesh<T>,
+    /// Number of input points, points with larger indices are Steiner points.
+    input_len: usize,
+    indices: HashMap<Point<T>, Index>,
+    /// Input segment containing each constraint edge.
+    origins: HashMap<Edge, Edge>,
+    /// Input segment containing each Steiner point inserted on a segment.
+    on_segment: HashMap<Index, Edge>,
+    /// Constraint edges which cannot be split in the precision of `T`.
+    frozen: HashSet<Edge>,
+    /// Minimum angle in radians.
+    min_angle: f64,
+    max_area: f64,
+    /// Number of points after which no more Steiner points are inserted.
+    max_points: usize,
+    /// Constraint edges to check for encroachment.
+    segments: Vec<Edge>,
+    /// Triangles to check for quality, with their vertices to detect changed triangles.
+    triangles: VecDeque<(usize, [Index; 3])>,
+}
+
+impl<T: Float> Refiner<T> {
+    fn coords(&self, i: Index) -> (f64, f64) {
+        let p = self.mesh.points[i];
+        (p.x.to_f64(), p.y.to_f64())
+    }
+
+    /// Whether the diametral circle of constraint `e` contains the opposite
+    /// vertex of a neighboring triangle.
+    fn is_encroached(&self, e: Edge) -> bool {
+        let Some(sides) = self.mesh.adjacent.get(&e) else {
+            return false;
+        };
+        let ((ax, ay), (bx, by)) = (self.coords(e.0), self.coords(e.1));
+        sides.iter().filter(|&&t| t != NONE).any(|&t| {
+            let (px, py) = self.coords(self.mesh.rotated(t, e)[2]);
+            (ax - px) * (bx - px) + (ay - py) * (by - py) < 0.0
+        })
+    }
+
+    fn is_bad(&self, t: usize) -> bool {
+        if self.mesh.is_degenerate(t) {
+            return false;
+        }
+        let [a, b, c] = self.mesh.triangles[t];
+        let (pa, pb, pc) = (self.coords(a), self.coords(b), self.coords(c));
+        let area = ((pb.0 - pa.0) * (pc.1 - pa.1) - (pb.1 - pa.1) * (pc.0 - pa.0)) / 2.0;
+        if area > self.max_area {
+            return true;
+        }
+        // smallest angle is at the vertex opposite to the shortest edge
+        let (vertices, coords) = ([a, b, c], [pa, pb, pc]);
+        let side = |k: usize| {
+            let (p, q) = (coords[(k + 1) % 3], coords[(k + 2) % 3]);
+            (p.0 - q.0).hypot(p.1 - q.1)
+        };
+        let k = (0..3).min_by(|&i, &j| side(i).total_cmp(&side(j))).unwrap();
+        let (p, q, r) = (coords[(k + 1) % 3], coords[(k + 2) % 3], coords[k]);
+        let (u, v) = ((p.0 - r.0, p.1 - r.1), (q.0 - r.0, q.1 - r.1));
+        let angle = (u.0 * v.1 - u.1 * v.0).abs().atan2(u.0 * v.0 + u.1 * v.1);
+        let (p, q) = (vertices[(k + 1) % 3], vertices[(k + 2) % 3]);
+        angle < self.min_angle && !self.is_on_same_shell(p, q)
+    }
+
+    /// Whether `p` and `q` lie on two segments meeting at an angle below 60 degrees,
+    /// at the same distance from their common endpoint. Thin triangles between such
+    /// points come from the small angle of the input, splitting them would only
+    /// insert points closer and closer to it.
+    fn is_on_same_shell(&self, p: Index, q: Index) -> bool {
+        let (Some(&sp), Some(&sq)) = (self.on_segment.get(&p), self.on_segment.get(&q)) else {
+            return false;
+        };
+        let Some(apex) = [sp.0, sp.1]
+            .into_iter()
+            .find(|&i| sp != sq && (i == sq.0 || i == sq.1))
+        else {
+            return false;
+        };
+        let other = |s: Edge| if s.0 == apex { s.1 } else { s.0 };
+        let vector = |i: Index| {
+            let ((x, y), (ax, ay)) = (self.coords(i), self.coords(apex));
+            (x - ax, y - ay)
+        };
+        let (u, v) = (vector(other(sp)), vector(other(sq)));
+        let angle = (u.0 * v.1 - u.1 * v.0).abs().atan2(u.0 * v.0 + u.1 * v.1);
+        let distance = |i: Index| {
+            let (x, y) = vector(i);
+            x.hypot(y)
+        };
+        let (dp, dq) = (distance(p), distance(q));
+        angle < std::f64::consts::FRAC_PI_3 && (dp - dq).abs() <= 1e-3 * dp.max(dq)
+    }
+
+    /// Queues constraints and triangles changed since the last call.
+    fn check_touched(&mut self) {
+        for t in std::mem::take(&mut self.mesh.touched) {
+            for (u, v) in self.mesh.directed_edges(t) {
+                let e = edge(u, v);
+                if self.mesh.constraints.contains(&e) && self.is_encroached(e) {
+                    self.segments.push(e);
+                }
+            }
+            if self.is_bad(t) {
+                self.triangles.push_back((t, self.mesh.triangles[t]));
+            }
+        }
+    }
+
+    fn new_point(&self, x: f64, y: f64) -> Option<Point<T>> {
+        let p = Point::new(T::from_f64(x), T::from_f64(y));
+        (x.is_finite() && y.is_finite() && !self.indices.contains_key(&p)).then_some(p)
+    }
+
+    /// Splits constraint `e`, returns `false` if the split point cannot be
+    /// represented in the precision of `T` without breaking the mesh.
+    fn split_segment(&mut self, e: Edge) -> bool {
+        if self.frozen.contains(&e) || !self.mesh.constraints.contains(&e) {
+            return false;
+        }
+        let (a, b) = e;
+        let ((ax, ay), (bx, by)) = (self.coords(a), self.coords(b));
+        let is_input = |i: Index| i < self.input_len;
+        let ratio = if is_input(a) != is_input(b) {
+            // split at a power of two distance from the input vertex (concentric shells),
+            // so segments meeting at a small angle are split at the same distances
+            let length = (bx - ax).hypot(by - ay);
+            let shell = (length / 2.0).log2().round().exp2() / length;
+            if is_input(a) {
+                shell
+            } else {
+                1.0 - shell
+            }
+        } else {
+            0.5
+        };
+        let Some(p) = self.new_point(ax + (bx - ax) * ratio, ay + (by - ay) * ratio) else {
+            self.frozen.insert(e);
+            return false;
+        };
+        let start: Vec<usize> = self.mesh.adjacent[&e]
+            .into_iter()
+            .filter(|&t| t != NONE)
+            .collect();
+        self.mesh.constraints.remove(&e);
+        let (cavity, boundary) = self.mesh.cavity(&start, p);
+        let visible = boundary.iter().all(|&(u, v, _)| {
+            edge(u, v) == e
+                || orientation(self.mesh.points[u], self.mesh.points[v], p)
+                    == Orientation::CounterClockwise
+        });
+        if !visible {
+            self.mesh.constraints.insert(e);
+            self.frozen.insert(e);
+            return false;
+        }
+        let origin = self.origins.remove(&e).unwrap_or(e);
+        let index = self.mesh.points.len();
+        for half in [edge(a, index), edge(index, b)] {
+            self.mesh.constraints.insert(half);
+            self.origins.insert(half, origin);
+            self.segments.push(half);
+        }
+        self.on_segment.insert(index, origin);
+        self.indices.insert(p, index);
+        self.mesh.insert(p, &cavity, &boundary, Some(e));
+        true
+    }
+
+    /// Inserts the circumcenter of triangle `t`, or splits the constraints
+    /// it encroaches upon instead.
+    fn split_triangle(&mut self, t: usize) {
+        let [a, b, c] = self.mesh.triangles[t];
+        let ((ax, ay), (bx, by), (cx, cy)) = (self.coords(a), self.coords(b), self.coords(c));
+        let (bx, by, cx, cy) = (bx - ax, by - ay, cx - ax, cy - ay);
+        let (b_lift, c_lift) = (bx * bx + by * by, cx * cx + cy * cy);
+        let det = 2.0 * (bx * cy - by * cx);
+        let x = ax + (cy * b_lift - by * c_lift) / det;
+        let y = ay + (bx * c_lift - cx * b_lift) / det;
+        let Some(p) = self.new_point(x, y) else {
+            return;
+        };
+        let (cavity, boundary) = self.mesh.cavity(&[t], p);
+        let encroached: Vec<Edge> = boundary
+            .iter()
+            .filter(|&&(u, v, _)| {
+                let ((ux, uy), (vx, vy)) = (self.coords(u), self.coords(v));
+                self.mesh.constraints.contains(&edge(u, v))
+                    && ((ux - x) * (vx - x) + (uy - y) * (vy - y) < 0.0
+                        || orientation(self.mesh.points[u], self.mesh.points[v], p)
+                            != Orientation::CounterClockwise)
+            })
+            .map(|&(u, v, _)| edge(u, v))
+            .collect();
+        if encroached.is_empty() {
+            let visible = boundary.iter().all(|&(u, v, _)| {
+                orientation(self.mesh.points[u], self.mesh.points[v], p)
+                    == Orientation::CounterClockwise
+            });
+            if visible {
+                let index = self.mesh.insert(p, &cavity, &boundary, None);
+                self.indices.insert(p, index);
+            }
+            return;
+        }
+        let mut split = false;
+        for e in encroached {
+            split |= self.split_segment(e);
+        }
+        if split {
+            // triangle may still need to be split after its neighborhood changed
+            self.triangles.push_back((t, [a, b, c]));
+        }
+    }
+
+    fn refine(&mut self) {
+        while self.mesh.points.len() < self.max_points {
+            if let Some(e) = self.segments.pop() {
+                if self.mesh.constraints.contains(&e) && self.is_encroached(e) {
+                    self.split_segment(e);
+                    self.check_touched();
+                }
+                continue;
+            }
+            let Some((t, vertices)) = self.triangles.pop_front() else {
+                break;
+            };
+            if self.mesh.triangles[t] == vertices && self.is_bad(t) {
+                self.split_triangle(t);
+                self.check_touched();
+            }
+        }
+    }
+}
+
+/// Refines a triangulation into a constrained Delaunay triangulation
+/// with bounded angles and areas of triangles, by inserting Steiner points.
+///
+/// Uses Ruppert's algorithm: constraint edges encroached upon by a vertex
+/// inside of their diametral circle are split, and circumcenters of triangles
+/// violating the bounds are inserted. Triangles with a small angle of the input
+/// between two constraints cannot be improved and are kept. Refinement stops after
+/// inserting `max_steiner_points` points, so bounds which cannot be met in practice,
+/// like a tiny or non-positive `max_area`, do not make it run indefinitely.
+///
+/// # Arguments
+/// * `triangles` - Triangles referring to indices of `points`
+/// * `points` - Vertices of the triangulation
+/// * `constraints` - Edges which have to be kept, usually edges of the triangulated
+///   polygons, they may be split by Steiner points. Edges on the boundary of
+///   the triangulated area are always kept.
+/// * `refinement` - Bounds on triangles
+///
+/// # Returns
+/// A tuple of triangles and points, which start with `points` followed by Steiner points.
+/// Steiner points are rounded to the precision of `T`, so points splitting constraints
+/// may lie slightly off of them.
+///
+/// # Example
+/// ```
+/// use triangulation::delaunay::{refine_delaunay, Refinement};
+/// use triangulation::{Point, Segment, Triangle};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(8.0, 0.0),
+///     Point::new(8.0, 1.0),
+///     Point::new(0.0, 1.0),
+/// ];
+/// let triangles = vec![Triangle::new(0, 1, 2), Triangle::new(0, 2, 3)];
+/// let edges: Vec<Segment<f32>> = (0..4).map(|i| Segment::new(points[i], points[(i + 1) % 4])).collect();
+/// let (triangles, new_points) = refine_delaunay(&triangles, &points, &edges, &Refinement::new(25.0));
+/// assert!(new_points.len() > points.len());
+/// assert_eq!(&new_points[..4], &points[..]);
+/// assert!(triangles.len() > 2);
+/// ```
+pub fn refine_delaunay<T: Float>(
+    triangles: &[Triangle],
+    points: &[Point<T>],
+    constraints: &[Segment<T>],
+    refinement: &Refinement,
+) -> (Vec<Triangle>, Vec<Point<T>>) {
+    let mut mesh = Mesh::new(points.to_vec(), triangles, constraints);
+    // new points cannot be inserted outside of the triangulated area
+    let boundary: Vec<Edge> = mesh
+        .adjacent
+        .iter()
+        .filter(|(_, sides)| sides[1] == NONE)
+        .map(|(e, _)| *e)
+        .collect();
+    mesh.constraints.extend(boundary);
+    let mut edges: Vec<Edge> = mesh.adjacent.keys().copied().collect();
+    edges.sort_unstable_by(|a, b| b.cmp(a));
+    mesh.legalize(edges);
+
+    let mut segments: Vec<Edge> = mesh.constraints.iter().copied().collect();
+    segments.sort_unstable_by(|a, b| b.cmp(a));
+    mesh.touched = (0..mesh.triangles.len()).collect();
+    let mut refiner = Refiner {
+        input_len: points.len(),
+        indices: points.iter().enumerate().map(|(i, p)| (*p, i)).collect(),
+        origins: segments.iter().map(|e| (*e, *e)).collect(),
+        on_segment: HashMap::new(),
+        frozen: HashSet::new(),
+        min_angle: refinement.min_angle.clamp(0.0, MAX_MIN_ANGLE).to_radians(),
+        max_area: refinement.max_area.unwrap_or(f64::INFINITY),
+        max_points: points.len().saturating_add(refinement.max_steiner_points),
+        segments,
+        triangles: VecDeque::new(),
+        mesh,
+    };
+    refiner.check_touched();
+    refiner.refine();
+    let triangles = refiner
+        .mesh
+        .triangles
+        .into_iter()
+        .map(|[a, b, c]| Triangle::new(a, b, c))
+        .collect();
+    (triangles, refiner.mesh.points)
+}
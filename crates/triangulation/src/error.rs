@@ -0,0 +1,191 @@
+use crate::point::{Coord, Float, Index, Point};
+use std::fmt;
+
+/// Errors reported by triangulation algorithms for input that cannot be processed.
+///
+/// Each variant carries enough context to point to the offending input:
+/// the index of the polygon in the input list (when it is known)
+/// and the vertex at which the problem was detected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriangulationError<T = Coord> {
+    /// Polygon has fewer than three vertices.
+    TooFewPoints { polygon: Index, count: usize },
+    /// All vertices of a polygon lie on a single line.
+    CollinearPolygon { polygon: Index, vertex: Point<T> },
+    /// Vertex of a polygon has a NaN or infinite coordinate.
+    NonFiniteVertex { polygon: Index, vertex: Point<T> },
+    /// Sweep line reached a vertex that is not an endpoint of any edge.
+    PointNotFound { vertex: Point<T> },
+    /// Sweep line state is inconsistent at the given vertex,
+    /// usually caused by edges that intersect outside of vertices.
+    InvalidSweepState {
+        vertex: Point<T>,
+        reason: &'static str,
+    },
+    /// Edges still cross near the given vertex after the allowed number
+    /// of snap rounding passes.
+    UnresolvedIntersection { vertex: Point<T> },
+    /// Vertices of 3D polygons do not lie on a common plane, `deviation` is
+    /// the largest distance of a vertex of the polygon from the fitted plane.
+    NotPlanar { polygon: Index, deviation: T },
+    /// Edge of a polygon starting at `vertex` is neither horizontal nor vertical,
+    /// so the polygon cannot be triangulated as a rectilinear one.
+    NotRectilinear { polygon: Index, vertex: Point<T> },
+    /// Offset distance is NaN or infinite.
+    NonFiniteDistance { distance: T },
+    /// Error raised while processing one shape of a batch.
+    /// Polygon index of the `source` error refers to rings of this shape.
+    InShape {
+        shape: Index,
+        source: Box<TriangulationError<T>>,
+    },
+}
+
+impl<T: Float> TriangulationError<T> {
+    /// Wrap error with index of the shape in which it occurred.
+    pub fn in_shape(self, shape: Index) -> Self {
+        TriangulationError::InShape {
+            shape,
+            source: Box::new(self),
+        }
+    }
+
+    /// Index of the shape that caused the error, if known.
+    pub fn shape_index(&self) -> Option<Index> {
+        match self {
+            TriangulationError::InShape { shape, .. } => Some(*shape),
+            _ => None,
+        }
+    }
+
+    /// Index of the polygon that caused the error, if known.
+    pub fn polygon_index(&self) -> Option<Index> {
+        match self {
+            TriangulationError::TooFewPoints { polygon, .. }
+            | TriangulationError::CollinearPolygon { polygon, .. }
+            | TriangulationError::NonFiniteVertex { polygon, .. }
+            | TriangulationError::NotPlanar { polygon, .. }
+            | TriangulationError::NotRectilinear { polygon, .. } => Some(*polygon),
+            TriangulationError::InShape { source, .. } => source.polygon_index(),
+            _ => None,
+        }
+    }
+
+    /// Vertex at which the error was detected, if any.
+    pub fn vertex(&self) -> Option<Point<T>> {
+        match self {
+            TriangulationError::TooFewPoints { .. }
+            | TriangulationError::NotPlanar { .. }
+            | TriangulationError::NonFiniteDistance { .. } => None,
+            TriangulationError::CollinearPolygon { vertex, .. }
+            | TriangulationError::NonFiniteVertex { vertex, .. }
+            | TriangulationError::NotRectilinear { vertex, .. }
+            | TriangulationError::PointNotFound { vertex }
+            | TriangulationError::InvalidSweepState { vertex, .. }
+            | TriangulationError::UnresolvedIntersection { vertex } => Some(*vertex),
+            TriangulationError::InShape { source, .. } => source.vertex(),
+        }
+    }
+
+    /// Return copy of error with polygon index mapped by `f`.
+    ///
+    /// Used when a function works on a filtered polygon list,
+    /// to report the index from the original list.
+    pub fn map_polygon_index(self, f: impl FnOnce(Index) -> Index) -> Self {
+        match self {
+            TriangulationError::TooFewPoints { polygon, count } => {
+                TriangulationError::TooFewPoints {
+                    polygon: f(polygon),
+                    count,
+                }
+            }
+            TriangulationError::CollinearPolygon { polygon, vertex } => {
+                TriangulationError::CollinearPolygon {
+                    polygon: f(polygon),
+                    vertex,
+                }
+            }
+            TriangulationError::NonFiniteVertex { polygon, vertex } => {
+                TriangulationError::NonFiniteVertex {
+                    polygon: f(polygon),
+                    vertex,
+                }
+            }
+            TriangulationError::NotPlanar { polygon, deviation } => TriangulationError::NotPlanar {
+                polygon: f(polygon),
+                deviation,
+            },
+            TriangulationError::NotRectilinear { polygon, vertex } => {
+                TriangulationError::NotRectilinear {
+                    polygon: f(polygon),
+                    vertex,
+                }
+            }
+            TriangulationError::InShape { shape, source } => TriangulationError::InShape {
+                shape,
+                source: Box::new(source.map_polygon_index(f)),
+            },
+            other => other,
+        }
+    }
+}
+
+impl<T: Float> fmt::Display for TriangulationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TriangulationError::TooFewPoints { polygon, count } => write!(
+                f,
+                "Polygon at index {} has fewer than 3 points ({})",
+                polygon, count
+            ),
+            TriangulationError::CollinearPolygon { polygon, vertex } => write!(
+                f,
+                "All points in polygon at index {} are collinear (vertex {})",
+                polygon, vertex
+            ),
+            TriangulationError::NonFiniteVertex { polygon, vertex } => write!(
+                f,
+                "Polygon at index {} has a vertex with non-finite coordinates ({})",
+                polygon, vertex
+            ),
+            TriangulationError::PointNotFound { vertex } => {
+                write!(f, "Point {} not found in the edge map", vertex)
+            }
+            TriangulationError::InvalidSweepState { vertex, reason } => {
+                write!(f, "{} at vertex {}", reason, vertex)
+            }
+            TriangulationError::UnresolvedIntersection { vertex } => {
+                write!(
+                    f,
+                    "Edges still cross near vertex {} after snap rounding",
+                    vertex
+                )
+            }
+            TriangulationError::NotPlanar { polygon, deviation } => write!(
+                f,
+                "Polygons are not planar, vertex of polygon at index {} lies {} from the fitted plane",
+                polygon, deviation
+            ),
+            TriangulationError::NotRectilinear { polygon, vertex } => write!(
+                f,
+                "Polygon at index {} has an edge which is not axis-aligned at vertex {}",
+                polygon, vertex
+            ),
+            TriangulationError::NonFiniteDistance { distance } => {
+                write!(f, "Offset distance {} is not finite", distance)
+            }
+            TriangulationError::InShape { shape, source } => {
+                write!(f, "Shape at index {}: {}", shape, source)
+            }
+        }
+    }
+}
+
+impl<T: Float> std::error::Error for TriangulationError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TriangulationError::InShape { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
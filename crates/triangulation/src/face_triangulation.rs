@@ -1,5 +1,12 @@
+use crate::batch::map_in_order;
+use crate::delaunay::{flip_to_constrained_delaunay, refine_delaunay, Refinement};
+use crate::error::TriangulationError;
+use crate::fill_rule::{split_polygons_with_fill_rule, FillRule};
+use crate::intersection::calc_edges;
 use crate::monotone_polygon::{triangulate_monotone_polygon, MonotonePolygon};
-use crate::point::{centroid, orientation, Index, Orientation, Point, Segment, Triangle};
+use crate::point::{
+    centroid, orientation, Coord, Float, Index, Orientation, Point, Segment, Triangle,
+};
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap};
@@ -13,7 +20,8 @@ use std::rc::Rc;
 ///
 /// # Value (Vec<Point>)
 /// Contains a list of points that are connected to the key point by segments.
-type PointToSegmentEndPoints = HashMap<Point, Vec<Point>>;
+type PointToSegmentEndPoints<T> = HashMap<Point<T>, Vec<Point<T>>>;
+type FaceTriangulation<T> = (Vec<Triangle>, Vec<Point<T>>);
 
 /// Builds a mapping between points and their connected segment endpoints.
 ///
@@ -28,7 +36,7 @@ type PointToSegmentEndPoints = HashMap<Point, Vec<Point>>;
 /// A `PointToSegmentEndPoints` containing the mapping where:
 /// - Each key is a point from the input segments
 /// - Each value is a vector of points connected to the key point
-fn get_points_segments(segments: &[Segment]) -> PointToSegmentEndPoints {
+fn get_points_segments<T: Float>(segments: &[Segment<T>]) -> PointToSegmentEndPoints<T> {
     let mut point_to_segments_ends = PointToSegmentEndPoints::new();
 
     // Populate the map with edges
@@ -73,15 +81,15 @@ fn get_points_segments(segments: &[Segment]) -> PointToSegmentEndPoints {
 /// It keeps track of the state between the left and right segments as the sweep line
 /// moves through the polygon, helping to build monotone polygons during the process.
 #[derive(Clone)]
-struct Interval {
-    last_seen: Point,
-    left_segment: Segment,
-    right_segment: Segment,
-    polygons_list: Vec<MonotonePolygon>,
+struct Interval<T> {
+    last_seen: Point<T>,
+    left_segment: Segment<T>,
+    right_segment: Segment<T>,
+    polygons_list: Vec<MonotonePolygon<T>>,
 }
 
-impl Interval {
-    fn new(p: Point, left: Segment, right: Segment) -> Self {
+impl<T: Float> Interval<T> {
+    fn new(p: Point<T>, left: Segment<T>, right: Segment<T>) -> Self {
         Self {
             last_seen: p,
             left_segment: left,
@@ -90,7 +98,12 @@ impl Interval {
         }
     }
 
-    fn with_polygon(p: Point, left: Segment, right: Segment, polygon: MonotonePolygon) -> Self {
+    fn with_polygon(
+        p: Point<T>,
+        left: Segment<T>,
+        right: Segment<T>,
+        polygon: MonotonePolygon<T>,
+    ) -> Self {
         let polygons_list = vec![polygon];
 
         Self {
@@ -101,30 +114,33 @@ impl Interval {
         }
     }
 
-    fn replace_segment(&mut self, old_segment: &Segment, new_segment: Segment) {
+    /// Replace `old_segment` bounding the interval, returns `false` if
+    /// it is not one of the bounding segments.
+    fn replace_segment(&mut self, old_segment: &Segment<T>, new_segment: Segment<T>) -> bool {
         if self.left_segment == *old_segment {
             self.left_segment = new_segment;
-            return;
         } else if self.right_segment == *old_segment {
             self.right_segment = new_segment;
-            return;
+        } else {
+            return false;
         }
-        panic!("Segment not found in interval");
+        true
     }
 
-    fn opposite_segment(&self, segment: &Segment) -> Segment {
+    /// The other bounding segment of the interval, if `segment` is one of them.
+    fn opposite_segment(&self, segment: &Segment<T>) -> Option<Segment<T>> {
         if *segment == self.left_segment {
-            self.right_segment.clone()
+            Some(self.right_segment.clone())
         } else if *segment == self.right_segment {
-            self.left_segment.clone()
+            Some(self.left_segment.clone())
         } else {
-            panic!("Segment not found in interval");
+            None
         }
     }
 }
 
 // Display implementation for Interval
-impl fmt::Display for Interval {
+impl<T: Float> fmt::Display for Interval<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -138,7 +154,7 @@ impl fmt::Display for Interval {
 }
 
 // Debug implementation might be useful
-impl fmt::Debug for Interval {
+impl<T: Float> fmt::Debug for Interval<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Interval")
             .field("last_seen", &self.last_seen)
@@ -152,7 +168,7 @@ impl fmt::Debug for Interval {
 /// Helper function to sort segments that share top points.
 /// needed for process intersection point
 #[inline]
-fn left_right_share_top(s1: &Segment, s2: &Segment) -> Ordering {
+fn left_right_share_top<T: Float>(s1: &Segment<T>, s2: &Segment<T>) -> Ordering {
     match orientation(s1.bottom, s1.top, s2.bottom) {
         Orientation::CounterClockwise => Ordering::Greater,
         Orientation::Clockwise => Ordering::Less,
@@ -163,7 +179,7 @@ fn left_right_share_top(s1: &Segment, s2: &Segment) -> Ordering {
 /// Helper function to sort segments that share bottom points.
 /// needed for process intersection point
 #[inline]
-fn left_right_share_bottom(s1: &Segment, s2: &Segment) -> Ordering {
+fn left_right_share_bottom<T: Float>(s1: &Segment<T>, s2: &Segment<T>) -> Ordering {
     match orientation(s1.top, s1.bottom, s2.top) {
         Orientation::CounterClockwise => Ordering::Less,
         Orientation::Clockwise => Ordering::Greater,
@@ -172,7 +188,10 @@ fn left_right_share_bottom(s1: &Segment, s2: &Segment) -> Ordering {
 }
 
 /// To distinguish left and right segments for split/start point  
-fn get_left_right_edges_top(s1: &Segment, s2: &Segment) -> (Segment, Segment) {
+fn get_left_right_edges_top<T: Float>(
+    s1: &Segment<T>,
+    s2: &Segment<T>,
+) -> (Segment<T>, Segment<T>) {
     if orientation(s1.bottom, s1.top, s2.bottom) == Orientation::CounterClockwise {
         (s2.clone(), s1.clone())
     } else {
@@ -181,7 +200,10 @@ fn get_left_right_edges_top(s1: &Segment, s2: &Segment) -> (Segment, Segment) {
 }
 
 /// To distinguish left and right segments for merge/end point
-fn get_left_right_edges_bottom(s1: &Segment, s2: &Segment) -> (Segment, Segment) {
+fn get_left_right_edges_bottom<T: Float>(
+    s1: &Segment<T>,
+    s2: &Segment<T>,
+) -> (Segment<T>, Segment<T>) {
     if orientation(s1.top, s1.bottom, s2.top) == Orientation::Clockwise {
         (s2.clone(), s1.clone())
     } else {
@@ -213,14 +235,14 @@ fn get_left_right_edges_bottom(s1: &Segment, s2: &Segment) -> (Segment, Segment)
 ///
 /// This implementation follows the standard polygon triangulation algorithm that first
 /// decomposes a polygon into monotone pieces before triangulation.
-struct MonotonePolygonBuilder {
-    segment_to_line: HashMap<Segment, Rc<RefCell<Interval>>>,
-    point_to_edges: PointToSegmentEndPoints,
-    monotone_polygons: Vec<MonotonePolygon>,
+struct MonotonePolygonBuilder<T> {
+    segment_to_line: HashMap<Segment<T>, Rc<RefCell<Interval<T>>>>,
+    point_to_edges: PointToSegmentEndPoints<T>,
+    monotone_polygons: Vec<MonotonePolygon<T>>,
 }
 
-impl MonotonePolygonBuilder {
-    pub fn new(edges: Vec<Segment>) -> Self {
+impl<T: Float> MonotonePolygonBuilder<T> {
+    pub fn new(edges: Vec<Segment<T>>) -> Self {
         let point_to_edges = get_points_segments(&edges);
         Self {
             segment_to_line: HashMap::new(),
@@ -231,10 +253,10 @@ impl MonotonePolygonBuilder {
 
     fn process_end_point(
         &mut self,
-        p: Point,
-        edge_left: Segment,
-        edge_right: Segment,
-        interval: Rc<RefCell<Interval>>,
+        p: Point<T>,
+        edge_left: Segment<T>,
+        edge_right: Segment<T>,
+        interval: Rc<RefCell<Interval<T>>>,
     ) {
         for polygon in &interval.borrow().polygons_list {
             let mut polygon = polygon.clone();
@@ -246,28 +268,35 @@ impl MonotonePolygonBuilder {
         self.segment_to_line.remove(&edge_right);
     }
 
-    fn process_merge_point(&mut self, p: Point, edge_left: Segment, edge_right: Segment) {
+    fn process_merge_point(
+        &mut self,
+        p: Point<T>,
+        edge_left: Segment<T>,
+        edge_right: Segment<T>,
+    ) -> Result<(), TriangulationError<T>> {
         let left_interval_ref = self
             .segment_to_line
             .get_mut(&edge_left)
-            .expect("Left edge interval not found")
+            .ok_or(TriangulationError::InvalidSweepState {
+                vertex: p,
+                reason: "Left edge interval not found",
+            })?
             .clone();
         let right_interval_ref = self
             .segment_to_line
             .get(&edge_right)
-            .expect("Right edge interval not found")
+            .ok_or(TriangulationError::InvalidSweepState {
+                vertex: p,
+                reason: "Right edge interval not found",
+            })?
             .clone();
 
         if !Rc::ptr_eq(&left_interval_ref, &right_interval_ref) {
-            #[cfg(debug_assertions)]
-            {
-                if right_interval_ref.borrow().right_segment == edge_right {
-                    panic!(
-                        "The right edge of merge point should be the left edge of the right interval.\n\
-                        Got interval: {:?} and edge: {:?}",
-                        right_interval_ref, edge_right
-                    );
-                }
+            if right_interval_ref.borrow().right_segment == edge_right {
+                return Err(TriangulationError::InvalidSweepState {
+                    vertex: p,
+                    reason: "Right edge of merge point is not the left edge of the right interval",
+                });
             }
 
             self.segment_to_line.remove(&edge_right);
@@ -276,14 +305,14 @@ impl MonotonePolygonBuilder {
             left_interval_ref.borrow_mut().right_segment =
                 right_interval_ref.borrow().right_segment.clone();
 
-            #[cfg(debug_assertions)]
+            if !self
+                .segment_to_line
+                .contains_key(&right_interval_ref.borrow().right_segment)
             {
-                if !self
-                    .segment_to_line
-                    .contains_key(&right_interval_ref.borrow().right_segment)
-                {
-                    panic!("Segment not found in the map2");
-                }
+                return Err(TriangulationError::InvalidSweepState {
+                    vertex: p,
+                    reason: "Right segment of merged interval not found in the map",
+                });
             }
 
             self.segment_to_line.insert(
@@ -292,12 +321,30 @@ impl MonotonePolygonBuilder {
             );
             left_interval_ref.borrow_mut().last_seen = p;
 
-            // Update polygons
-            if let Some(last_polygon) = left_interval_ref.borrow_mut().polygons_list.last_mut() {
-                last_polygon.right.push(p);
+            // Update polygons. A polygon bounded by the edge ending at `p`,
+            // which is followed by another one in the interval, ends with
+            // a diagonal to the merge point separating them.
+            {
+                let mut left_interval = left_interval_ref.borrow_mut();
+                if left_interval.polygons_list.len() > 1 {
+                    let mut polygon = left_interval.polygons_list.pop().unwrap();
+                    polygon.bottom = Some(p);
+                    self.monotone_polygons.push(polygon);
+                }
+                if let Some(last_polygon) = left_interval.polygons_list.last_mut() {
+                    last_polygon.right.push(p);
+                }
             }
-            if let Some(first_polygon) = right_interval_ref.borrow_mut().polygons_list.first_mut() {
-                first_polygon.left.push(p);
+            {
+                let mut right_interval = right_interval_ref.borrow_mut();
+                if right_interval.polygons_list.len() > 1 {
+                    let mut polygon = right_interval.polygons_list.remove(0);
+                    polygon.bottom = Some(p);
+                    self.monotone_polygons.push(polygon);
+                }
+                if let Some(first_polygon) = right_interval.polygons_list.first_mut() {
+                    first_polygon.left.push(p);
+                }
             }
 
             // Move polygons from right interval to left interval
@@ -309,18 +356,22 @@ impl MonotonePolygonBuilder {
             // This is the end point
             self.process_end_point(p, edge_left, edge_right, left_interval_ref);
         }
+        Ok(())
     }
 
     fn process_normal_point(
         &mut self,
-        p: Point,
-        edge_top: Segment,
-        edge_bottom: Segment,
-    ) -> Result<(), String> {
+        p: Point<T>,
+        edge_top: Segment<T>,
+        edge_bottom: Segment<T>,
+    ) -> Result<(), TriangulationError<T>> {
         let interval_ref = self
             .segment_to_line
             .get_mut(&edge_top)
-            .ok_or_else(|| "Segment not found in the map".to_string())?
+            .ok_or(TriangulationError::InvalidSweepState {
+                vertex: p,
+                reason: "Segment not found in the map",
+            })?
             .clone();
 
         let mut interval = interval_ref.borrow_mut();
@@ -361,26 +412,39 @@ impl MonotonePolygonBuilder {
         self.segment_to_line
             .insert(edge_bottom.clone(), interval_ref.clone());
         interval.last_seen = p;
-        interval.replace_segment(&edge_top, edge_bottom);
-        let val = self.segment_to_line.remove(&edge_top);
-        if val.is_none() {
-            panic!("Segment not found in the map");
+        if !interval.replace_segment(&edge_top, edge_bottom) {
+            return Err(TriangulationError::InvalidSweepState {
+                vertex: p,
+                reason: "Segment not found in interval",
+            });
+        }
+        if self.segment_to_line.remove(&edge_top).is_none() {
+            return Err(TriangulationError::InvalidSweepState {
+                vertex: p,
+                reason: "Segment not found in the map",
+            });
         }
 
         #[cfg(debug_assertions)]
         {
             if !self.segment_to_line.contains_key(&interval.left_segment) {
-                return Err("Left segment not found in the map".to_string());
+                return Err(TriangulationError::InvalidSweepState {
+                    vertex: p,
+                    reason: "Left segment not found in the map",
+                });
             }
             if !self.segment_to_line.contains_key(&interval.right_segment) {
-                return Err("Right segment not found in the map".to_string());
+                return Err(TriangulationError::InvalidSweepState {
+                    vertex: p,
+                    reason: "Right segment not found in the map",
+                });
             }
         }
 
         Ok(())
     }
 
-    fn process_start_point(&mut self, p: Point, edge_left: Segment, edge_right: Segment) {
+    fn process_start_point(&mut self, p: Point<T>, edge_left: Segment<T>, edge_right: Segment<T>) {
         let mut_interval = Interval::with_polygon(
             p,
             edge_left.clone(),
@@ -394,15 +458,15 @@ impl MonotonePolygonBuilder {
         self.segment_to_line.insert(edge_right, line_interval);
     }
 
-    fn find_interval_with_point(&self, p: Point) -> Option<Rc<RefCell<Interval>>> {
+    fn find_interval_with_point(&self, p: Point<T>) -> Option<Rc<RefCell<Interval<T>>>> {
         for (segment, interval) in self.segment_to_line.iter() {
             if *segment == interval.borrow().right_segment {
                 // as each interval is listed twice we
                 // skip this pointed by right segment to avoid duplication
                 continue;
             }
-            if interval.borrow().left_segment.point_on_line_x(p.y) < p.x
-                && interval.borrow().right_segment.point_on_line_x(p.y) > p.x
+            if interval.borrow().left_segment.is_point_right(p)
+                && interval.borrow().right_segment.is_point_left(p)
             {
                 // we are inside the interval
                 return Some(interval.clone());
@@ -411,7 +475,7 @@ impl MonotonePolygonBuilder {
         None
     }
 
-    fn process_split_point(&mut self, p: Point, edge_left: Segment, edge_right: Segment) {
+    fn process_split_point(&mut self, p: Point<T>, edge_left: Segment<T>, edge_right: Segment<T>) {
         if let Some(interval) = self.find_interval_with_point(p) {
             let right_segment = interval.borrow().right_segment.clone();
             self.segment_to_line
@@ -473,7 +537,15 @@ impl MonotonePolygonBuilder {
         }
     }
 
-    fn process_intersection_point(&mut self, p: Point, edges: Vec<Segment>) -> Result<(), String> {
+    fn process_intersection_point(
+        &mut self,
+        p: Point<T>,
+        edges: Vec<Segment<T>>,
+    ) -> Result<(), TriangulationError<T>> {
+        let missing_interval = TriangulationError::InvalidSweepState {
+            vertex: p,
+            reason: "Segment not found in the map",
+        };
         let mut processed_segments = BTreeSet::new();
         let mut segments_to_normal_process = Vec::new();
         let mut top_segments = Vec::new();
@@ -483,16 +555,15 @@ impl MonotonePolygonBuilder {
             if processed_segments.contains(edge) {
                 continue;
             }
-            if self.segment_to_line.contains_key(edge) {
-                let interval = self.segment_to_line.get(edge).unwrap();
-                let opposite_edge = interval.borrow().opposite_segment(edge);
+            if let Some(interval) = self.segment_to_line.get(edge).cloned() {
+                let opposite_edge = interval.borrow().opposite_segment(edge).ok_or(
+                    TriangulationError::InvalidSweepState {
+                        vertex: p,
+                        reason: "Segment not found in interval",
+                    },
+                )?;
                 if edge.bottom == p && opposite_edge.bottom == p {
-                    self.process_end_point(
-                        p,
-                        edge.clone(),
-                        opposite_edge.clone(),
-                        interval.clone(),
-                    );
+                    self.process_end_point(p, edge.clone(), opposite_edge.clone(), interval);
                     processed_segments.insert(opposite_edge);
                     processed_segments.insert(edge.clone());
                     continue;
@@ -516,28 +587,35 @@ impl MonotonePolygonBuilder {
         let mut top_begin = top_segments.iter();
         if !top_segments.is_empty() {
             let first_top = top_segments.first().unwrap();
-            if *first_top == self.segment_to_line[first_top].borrow().right_segment {
+            let first_interval = self
+                .segment_to_line
+                .get(first_top)
+                .ok_or(missing_interval.clone())?;
+            if *first_top == first_interval.borrow().right_segment {
                 top_begin.next();
-                let first_bottom = bottom_begin.next().unwrap();
+                let first_bottom = bottom_begin.next().ok_or(missing_interval.clone())?;
                 self.process_normal_point(p, first_top.clone(), first_bottom.clone())?;
             }
             let top_segments_last = top_segments.last().unwrap();
-            if top_begin.count() > 0
-                && *top_segments_last
-                    == self.segment_to_line[top_segments_last]
-                        .borrow()
-                        .left_segment
-            {
-                let last_bottom = bottom_begin.next_back().unwrap();
-                self.process_normal_point(p, top_segments_last.clone(), last_bottom.clone())?;
+            if top_begin.count() > 0 {
+                let last_interval = self
+                    .segment_to_line
+                    .get(top_segments_last)
+                    .ok_or(missing_interval.clone())?;
+                if *top_segments_last == last_interval.borrow().left_segment {
+                    let last_bottom = bottom_begin.next_back().ok_or(missing_interval.clone())?;
+                    self.process_normal_point(p, top_segments_last.clone(), last_bottom.clone())?;
+                }
             }
         }
-        while bottom_begin.peek().is_some() {
-            self.process_start_point(
-                p,
-                bottom_begin.next().unwrap().clone(),
-                bottom_begin.next().unwrap().clone(),
-            );
+        while let Some(left) = bottom_begin.next() {
+            let right = bottom_begin
+                .next()
+                .ok_or(TriangulationError::InvalidSweepState {
+                    vertex: p,
+                    reason: "Odd number of segments starting at intersection point",
+                })?;
+            self.process_start_point(p, left.clone(), right.clone());
         }
 
         Ok(())
@@ -546,30 +624,33 @@ impl MonotonePolygonBuilder {
 
 /// Enum for encode point type for sweeping line algorithm  
 #[derive(Debug, PartialEq, Eq)]
-pub enum PointType {
-    Intersection(Vec<Segment>),
-    Split(Segment, Segment),
-    Merge(Segment, Segment),
-    Normal(Segment, Segment),
+pub enum PointType<T: Float = Coord> {
+    Intersection(Vec<Segment<T>>),
+    Split(Segment<T>, Segment<T>),
+    Merge(Segment<T>, Segment<T>),
+    Normal(Segment<T>, Segment<T>),
 }
 
 /// Get point type based on adjacent segm
-fn get_point_type(p: Point, point_to_edges: &PointToSegmentEndPoints) -> PointType {
+fn get_point_type<T: Float>(
+    p: Point<T>,
+    point_to_edges: &PointToSegmentEndPoints<T>,
+) -> Result<PointType<T>, TriangulationError<T>> {
     match point_to_edges.get(&p) {
-        None => panic!("Point not found in the map"),
+        None => Err(TriangulationError::PointNotFound { vertex: p }),
         Some(opposite_point) => {
             if opposite_point.is_empty() {
-                panic!("Empty point found in the map");
+                return Err(TriangulationError::PointNotFound { vertex: p });
             }
 
             // Convert edge info to segments
-            let segments: Vec<Segment> = opposite_point
+            let segments: Vec<Segment<T>> = opposite_point
                 .iter()
                 .map(|opposite_point| Segment::new(p, *opposite_point))
                 .collect();
 
             if segments.len() != 2 {
-                return PointType::Intersection(segments);
+                return Ok(PointType::Intersection(segments));
             }
 
             let (seg1, seg2) = (segments[0].clone(), segments[1].clone());
@@ -577,15 +658,15 @@ fn get_point_type(p: Point, point_to_edges: &PointToSegmentEndPoints) -> PointTy
             // Both opposite points are less than p -> Split point
             if opposite_point[0] < p && opposite_point[1] < p {
                 let (left, right) = get_left_right_edges_top(&seg1, &seg2);
-                return PointType::Split(left, right);
+                return Ok(PointType::Split(left, right));
             }
             // Both opposite points are greater than p -> Merge point
             if p < opposite_point[0] && p < opposite_point[1] {
                 let (left, right) = get_left_right_edges_bottom(&seg1, &seg2);
-                return PointType::Merge(left, right);
+                return Ok(PointType::Merge(left, right));
             }
             // Otherwise it's a normal point
-            PointType::Normal(seg1, seg2)
+            Ok(PointType::Normal(seg1, seg2))
         }
     }
 }
@@ -604,18 +685,21 @@ fn get_point_type(p: Point, point_to_edges: &PointToSegmentEndPoints) -> PointTy
 /// # Returns
 ///
 /// A vector of triangles where each triangle's vertices are represented by indices into the `points` array.
-fn triangulate_monotone_polygons(
-    monotone_polygons: &[MonotonePolygon],
-    points: &[Point],
-) -> Vec<Triangle> {
+///
+/// # Errors
+/// Returns [`TriangulationError::InvalidSweepState`] if a polygon is not finished.
+fn triangulate_monotone_polygons<T: Float>(
+    monotone_polygons: &[MonotonePolygon<T>],
+    points: &[Point<T>],
+) -> Result<Vec<Triangle>, TriangulationError<T>> {
     let mut triangles = vec![];
     let point_to_index = points
         .iter()
         .enumerate()
         .map(|(i, p)| (*p, i))
-        .collect::<HashMap<Point, Index>>();
+        .collect::<HashMap<Point<T>, Index>>();
     for monotone_polygon in monotone_polygons {
-        let point_triangles = triangulate_monotone_polygon(monotone_polygon);
+        let point_triangles = triangulate_monotone_polygon(monotone_polygon)?;
         for triangle in point_triangles {
             triangles.push(Triangle::new(
                 point_to_index[&triangle.p1],
@@ -624,7 +708,7 @@ fn triangulate_monotone_polygons(
             ));
         }
     }
-    triangles
+    Ok(triangles)
 }
 
 /// Triangulates a polygon using a sweep line algorithm.
@@ -649,9 +733,9 @@ fn triangulate_monotone_polygons(
 /// * `Vec<Triangle>` - The resulting triangulation as vertex-indexed triangles
 /// * `Vec<Point>` - Sorted list of all vertices (used for index reference)
 ///
-/// # Panics
-/// May panic if the input geometry is invalid or contains self-intersections
-/// that cannot be properly handled.
+/// # Errors
+/// Returns [`TriangulationError`] if the input geometry is invalid or contains
+/// self-intersections that cannot be properly handled.
 ///
 /// # Example
 /// ```
@@ -662,47 +746,52 @@ fn triangulate_monotone_polygons(
 ///     Segment::new(Point::new(1.0, 0.0), Point::new(0.5, 1.0)),
 ///     Segment::new(Point::new(0.5, 1.0), Point::new(0.0, 0.0)),
 /// ];
-/// let (triangles, points) = sweeping_line_triangulation(edges);
+/// let (triangles, points) = sweeping_line_triangulation(edges).unwrap();
 /// ```
-pub fn sweeping_line_triangulation(edges: Vec<Segment>) -> (Vec<Triangle>, Vec<Point>) {
+pub fn sweeping_line_triangulation<T: Float>(
+    edges: Vec<Segment<T>>,
+) -> Result<FaceTriangulation<T>, TriangulationError<T>> {
     let mut builder = MonotonePolygonBuilder::new(edges);
     let mut points = builder
         .point_to_edges
         .keys()
         .cloned()
-        .collect::<Vec<Point>>();
+        .collect::<Vec<Point<T>>>();
     points.sort();
     points.reverse();
     for p in &points {
-        let point_type = get_point_type(*p, &builder.point_to_edges);
+        let point_type = get_point_type(*p, &builder.point_to_edges)?;
         match point_type {
             PointType::Intersection(li) => {
-                builder.process_intersection_point(*p, li).unwrap();
+                builder.process_intersection_point(*p, li)?;
             }
             PointType::Split(left, right) => {
                 builder.process_split_point(*p, left, right);
             }
             PointType::Merge(left, right) => {
-                builder.process_merge_point(*p, left, right);
+                builder.process_merge_point(*p, left, right)?;
             }
             PointType::Normal(top, bottom) => {
-                builder.process_normal_point(*p, top, bottom).unwrap();
+                builder.process_normal_point(*p, top, bottom)?;
             }
         }
         #[cfg(debug_assertions)]
         {
-            for segment in builder.segment_to_line.keys() {
-                let val = builder.segment_to_line.get(segment).unwrap().borrow();
-                if val.left_segment != segment.clone() && val.right_segment != segment.clone() {
-                    panic!("Segment not found in the map");
+            for (segment, interval) in builder.segment_to_line.iter() {
+                let interval = interval.borrow();
+                if interval.left_segment != *segment && interval.right_segment != *segment {
+                    return Err(TriangulationError::InvalidSweepState {
+                        vertex: *p,
+                        reason: "Segment does not bound its interval",
+                    });
                 }
             }
         }
     }
-    (
-        triangulate_monotone_polygons(&builder.monotone_polygons, &points),
+    Ok((
+        triangulate_monotone_polygons(&builder.monotone_polygons, &points)?,
         points,
-    )
+    ))
 }
 
 /// Check if a polygon is convex.
@@ -720,7 +809,7 @@ pub fn sweeping_line_triangulation(edges: Vec<Segment>) -> (Vec<Triangle>, Vec<P
 ///
 /// # Returns
 /// `true` if the polygon is convex, `false` otherwise
-pub fn is_convex(points: &[Point]) -> bool {
+pub fn is_convex<T: Float>(points: &[Point<T>]) -> bool {
     if points.len() < 3 {
         return false;
     }
@@ -790,20 +879,20 @@ pub fn is_convex(points: &[Point]) -> bool {
 ///
 /// # Returns
 /// `true` if the polygon is simple, `false` otherwise
-fn is_simple_polygon<'a, I>(mut iter: I, centroid: Point) -> bool
+fn is_simple_polygon<'a, T: Float, I>(mut iter: I, centroid: Point<T>) -> bool
 where
-    I: Iterator<Item = &'a Point>,
+    I: Iterator<Item = &'a Point<T>>,
 {
     let first = iter.next().unwrap();
-    let start_angle = f32::atan2(first.y - centroid.y, first.x - centroid.x);
+    let start_angle = T::atan2(first.y - centroid.y, first.x - centroid.x);
     // We calculate the angle between starting point, centroid and current point
     // so we start with 0.0
-    let mut prev_angle = 0.0;
+    let mut prev_angle = T::ZERO;
 
     for point in iter {
-        let mut angle = f32::atan2(point.y - centroid.y, point.x - centroid.x) - start_angle;
-        if angle < 0.0 {
-            angle += 2.0 * std::f32::consts::PI;
+        let mut angle = T::atan2(point.y - centroid.y, point.x - centroid.x) - start_angle;
+        if angle < T::ZERO {
+            angle += T::TWO * T::PI;
         }
         if angle < prev_angle {
             return false;
@@ -813,7 +902,7 @@ where
     true
 }
 
-pub fn triangulate_convex_polygon(points: &[Point]) -> Vec<Triangle> {
+pub fn triangulate_convex_polygon<T: Float>(points: &[Point<T>]) -> Vec<Triangle> {
     let mut triangles = Vec::new();
     for i in 1..points.len() - 1 {
         triangles.push(Triangle::new(0, i as Index, (i + 1) as Index));
@@ -821,6 +910,268 @@ pub fn triangulate_convex_polygon(points: &[Point]) -> Vec<Triangle> {
     triangles
 }
 
+/// Face triangulation of multiple independent shapes.
+///
+/// Vertices and triangles of all shapes are concatenated,
+/// triangle indices refer to the concatenated `points`.
+///
+/// # Fields
+/// * `triangles` - Triangles of all shapes.
+/// * `points` - Vertices of all shapes.
+/// * `vertex_offsets` - Index of the first vertex of each shape in `points`,
+///   with one additional entry equal to `points.len()`.
+/// * `triangle_offsets` - Index of the first triangle of each shape in `triangles`,
+///   with one additional entry equal to `triangles.len()`.
+#[derive(Debug, Default)]
+pub struct ShapesTriangulation<T = Coord> {
+    pub triangles: Vec<Triangle>,
+    pub points: Vec<Point<T>>,
+    pub vertex_offsets: Vec<Index>,
+    pub triangle_offsets: Vec<Index>,
+}
+
+impl<T: Float> ShapesTriangulation<T> {
+    pub fn new() -> Self {
+        ShapesTriangulation {
+            triangles: Vec::new(),
+            points: Vec::new(),
+            vertex_offsets: vec![0],
+            triangle_offsets: vec![0],
+        }
+    }
+
+    /// Number of shapes in triangulation.
+    pub fn len(&self) -> usize {
+        self.vertex_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append triangulation of the next shape.
+    /// Triangle indices are shifted to refer to the concatenated points.
+    pub fn push_shape(&mut self, triangles: Vec<Triangle>, points: Vec<Point<T>>) {
+        let shift = self.points.len();
+        self.triangles
+            .extend(triangles.into_iter().map(|t| t.shifted_by(shift)));
+        self.points.extend(points);
+        self.vertex_offsets.push(self.points.len());
+        self.triangle_offsets.push(self.triangles.len());
+    }
+}
+
+/// Triangulates a single shape given as an outer ring with optional holes.
+///
+/// Rings are combined using even-odd rule (see [`calc_dedup_edges`](crate::point::calc_dedup_edges)),
+/// so the order of rings is not important. Triangles and convex polygons are
+/// triangulated directly, other shapes use [`sweeping_line_triangulation`].
+///
+/// # Arguments
+/// * `shape` - Rings of the shape, each as a list of points.
+///
+/// # Returns
+/// A tuple of triangles and points, triangles refer to indices in returned points.
+///
+/// # Errors
+/// Returns [`TriangulationError`] if the shape cannot be triangulated.
+pub fn triangulate_shape_face<T: Float>(
+    shape: &[Vec<Point<T>>],
+) -> Result<FaceTriangulation<T>, TriangulationError<T>> {
+    triangulate_shape_face_with_fill_rule(shape, FillRule::EvenOdd)
+}
+
+/// Triangulates a single shape, combining its rings with the given fill rule.
+///
+/// See [`split_polygons_with_fill_rule`] for the handling of overlapping rings.
+///
+/// # Arguments
+/// * `shape` - Rings of the shape, each as a list of points.
+/// * `fill_rule` - Rule deciding which area of the rings is filled.
+///
+/// # Returns
+/// A tuple of triangles and points, triangles refer to indices in returned points.
+///
+/// # Errors
+/// Returns [`TriangulationError`] if the shape cannot be triangulated.
+///
+/// # Example
+/// ```
+/// use triangulation::face_triangulation::triangulate_shape_face_with_fill_rule;
+/// use triangulation::{FillRule, Point};
+///
+/// let square = |x: f32| {
+///     vec![Point::new(x, 0.0), Point::new(x + 2.0, 0.0), Point::new(x + 2.0, 2.0), Point::new(x, 2.0)]
+/// };
+/// let shape = [square(0.0), square(1.0)];
+/// let (triangles, _) = triangulate_shape_face_with_fill_rule(&shape, FillRule::EvenOdd).unwrap();
+/// assert_eq!(triangles.len(), 4);
+/// let (triangles, _) = triangulate_shape_face_with_fill_rule(&shape, FillRule::NonZero).unwrap();
+/// assert_eq!(triangles.len(), 6);
+/// ```
+pub fn triangulate_shape_face_with_fill_rule<T: Float>(
+    shape: &[Vec<Point<T>>],
+    fill_rule: FillRule,
+) -> Result<FaceTriangulation<T>, TriangulationError<T>> {
+    triangulate_shape_face_styled(shape, &FaceStyle::new(fill_rule))
+}
+
+/// Shape of triangles of face triangulation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TriangulationQuality {
+    /// Triangles of monotone polygons created by the sweep line,
+    /// fastest to compute, but often thin.
+    #[default]
+    Fast,
+    /// Constrained Delaunay triangulation of the same points,
+    /// see [`flip_to_constrained_delaunay`].
+    Delaunay,
+    /// Constrained Delaunay triangulation with Steiner points inserted until
+    /// triangles satisfy the bounds, see [`refine_delaunay`].
+    Refined(Refinement),
+}
+
+/// Parameters of face triangulation of a single shape.
+///
+/// # Fields
+/// * `fill_rule` - Rule deciding which area of the rings is filled.
+/// * `quality` - Shape of the triangles.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FaceStyle {
+    pub fill_rule: FillRule,
+    pub quality: TriangulationQuality,
+}
+
+impl FaceStyle {
+    pub fn new(fill_rule: FillRule) -> Self {
+        FaceStyle {
+            fill_rule,
+            quality: TriangulationQuality::Fast,
+        }
+    }
+
+    pub fn with_quality(self, quality: TriangulationQuality) -> Self {
+        FaceStyle { quality, ..self }
+    }
+}
+
+/// Triangulates a single shape with the given [`FaceStyle`].
+///
+/// # Arguments
+/// * `shape` - Rings of the shape, each as a list of points.
+/// * `style` - Parameters of the triangulation.
+///
+/// # Returns
+/// A tuple of triangles and points, triangles refer to indices in returned points.
+/// With [`TriangulationQuality::Refined`] points are followed by the inserted Steiner points.
+///
+/// # Errors
+/// Returns [`TriangulationError`] if the shape cannot be triangulated.
+///
+/// # Example
+/// ```
+/// use triangulation::face_triangulation::{
+///     triangulate_shape_face_styled, FaceStyle, TriangulationQuality,
+/// };
+/// use triangulation::{FillRule, Point};
+///
+/// let polygon: Vec<Point> = (0..16)
+///     .map(|i| {
+///         let angle = i as f32 / 16.0 * std::f32::consts::TAU;
+///         Point::new(10.0 * angle.cos(), 10.0 * angle.sin())
+///     })
+///     .collect();
+/// let style = FaceStyle::new(FillRule::EvenOdd).with_quality(TriangulationQuality::Delaunay);
+/// let (triangles, points) = triangulate_shape_face_styled(&[polygon], &style).unwrap();
+/// assert_eq!(triangles.len(), 14);
+/// assert_eq!(points.len(), 16);
+/// ```
+pub fn triangulate_shape_face_styled<T: Float>(
+    shape: &[Vec<Point<T>>],
+    style: &FaceStyle,
+) -> Result<FaceTriangulation<T>, TriangulationError<T>> {
+    let (triangles, points, constraints) = match (shape, style.fill_rule) {
+        // orientation of a single polygon matters only for rules with a sign
+        ([polygon], FillRule::EvenOdd | FillRule::NonZero) if polygon.len() == 3 => {
+            (vec![Triangle::new(0, 1, 2)], polygon.clone(), None)
+        }
+        ([polygon], FillRule::EvenOdd | FillRule::NonZero) if is_convex(polygon) => {
+            (triangulate_convex_polygon(polygon), polygon.clone(), None)
+        }
+        _ => {
+            let (_new_polygons, segments) = split_polygons_with_fill_rule(shape, style.fill_rule)?;
+            let constraints =
+                (style.quality != TriangulationQuality::Fast).then(|| segments.clone());
+            let (triangles, points) = sweeping_line_triangulation(segments)?;
+            (triangles, points, constraints)
+        }
+    };
+    match style.quality {
+        TriangulationQuality::Fast => Ok((triangles, points)),
+        TriangulationQuality::Delaunay => {
+            let constraints = constraints.unwrap_or_else(|| calc_edges(shape));
+            let triangles = flip_to_constrained_delaunay(&triangles, &points, &constraints);
+            Ok((triangles, points))
+        }
+        TriangulationQuality::Refined(refinement) => {
+            let constraints = constraints.unwrap_or_else(|| calc_edges(shape));
+            Ok(refine_delaunay(
+                &triangles,
+                &points,
+                &constraints,
+                &refinement,
+            ))
+        }
+    }
+}
+
+/// Triangulates multiple independent shapes, preserving shape identity.
+///
+/// In contrast to passing all rings to [`sweeping_line_triangulation`] at once,
+/// overlapping shapes do not create holes in each other.
+///
+/// # Arguments
+/// * `shapes` - List of shapes, each given as a list of rings (outer ring and holes).
+///
+/// # Returns
+/// [`ShapesTriangulation`] with concatenated points and triangles of all shapes.
+///
+/// With the `parallel` feature enabled shapes are triangulated in parallel,
+/// the output order is the same as the order of `shapes`.
+///
+/// # Errors
+/// Returns [`TriangulationError::InShape`] with the index of the first shape
+/// that cannot be triangulated.
+///
+/// # Example
+/// ```
+/// use triangulation::Point;
+/// use triangulation::face_triangulation::triangulate_shapes_face;
+///
+/// let square = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(1.0, 0.0),
+///     Point::new(1.0, 1.0),
+///     Point::new(0.0, 1.0),
+/// ];
+/// let triangle = vec![Point::new(2.0, 0.0), Point::new(3.0, 0.0), Point::new(2.5, 1.0)];
+/// let result = triangulate_shapes_face(&[vec![square], vec![triangle]]).unwrap();
+/// assert_eq!(result.len(), 2);
+/// assert_eq!(result.vertex_offsets, vec![0, 4, 7]);
+/// assert_eq!(result.triangle_offsets, vec![0, 2, 3]);
+/// ```
+pub fn triangulate_shapes_face<T: Float>(
+    shapes: &[Vec<Vec<Point<T>>>],
+) -> Result<ShapesTriangulation<T>, TriangulationError<T>> {
+    let shapes_results = map_in_order(shapes, |shape| triangulate_shape_face(shape));
+    let mut result = ShapesTriangulation::new();
+    for (i, shape_result) in shapes_results.into_iter().enumerate() {
+        let (triangles, points) = shape_result.map_err(|err| err.in_shape(i))?;
+        result.push_shape(triangles, points);
+    }
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -836,7 +1187,7 @@ mod tests {
 
     #[rstest]
     fn test_get_points_edges_diamond() {
-        let edges = calc_dedup_edges(&vec![DIAMOND.to_vec()]);
+        let edges = calc_dedup_edges(&[DIAMOND.to_vec()]).unwrap();
         let point_to_edges = get_points_segments(&edges);
         assert_eq!(point_to_edges.len(), 4);
         assert_eq!(
@@ -859,31 +1210,31 @@ mod tests {
 
     #[rstest]
     fn test_get_point_type() {
-        let edges = calc_dedup_edges(&vec![DIAMOND.to_vec()]);
+        let edges = calc_dedup_edges(&[DIAMOND.to_vec()]).unwrap();
         let point_to_edges = get_points_segments(&edges);
         assert_eq!(
-            get_point_type(Point::new(1.0, 2.0), &point_to_edges),
+            get_point_type(Point::new(1.0, 2.0), &point_to_edges).unwrap(),
             PointType::Split(
                 Segment::new(Point::new(1.0, 2.0), Point::new(0.0, 1.0)),
                 Segment::new(Point::new(1.0, 2.0), Point::new(2.0, 1.0))
             )
         );
         assert_eq!(
-            get_point_type(Point::new(1.0, 0.0), &point_to_edges),
+            get_point_type(Point::new(1.0, 0.0), &point_to_edges).unwrap(),
             PointType::Merge(
                 Segment::new(Point::new(1.0, 0.0), Point::new(0.0, 1.0)),
                 Segment::new(Point::new(1.0, 0.0), Point::new(2.0, 1.0)),
             )
         );
         assert_eq!(
-            get_point_type(Point::new(2.0, 1.0), &point_to_edges),
+            get_point_type(Point::new(2.0, 1.0), &point_to_edges).unwrap(),
             PointType::Normal(
                 Segment::new(Point::new(2.0, 1.0), Point::new(1.0, 2.0)),
                 Segment::new(Point::new(2.0, 1.0), Point::new(1.0, 0.0))
             )
         );
         assert_eq!(
-            get_point_type(Point::new(0.0, 1.0), &point_to_edges),
+            get_point_type(Point::new(0.0, 1.0), &point_to_edges).unwrap(),
             PointType::Normal(
                 Segment::new(Point::new(0.0, 1.0), Point::new(1.0, 2.0)),
                 Segment::new(Point::new(0.0, 1.0), Point::new(1.0, 0.0))
@@ -891,6 +1242,18 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_get_point_type_missing_point() {
+        let edges = calc_dedup_edges(&[DIAMOND.to_vec()]).unwrap();
+        let point_to_edges = get_points_segments(&edges);
+        assert_eq!(
+            get_point_type(Point::new(5.0, 5.0), &point_to_edges),
+            Err(TriangulationError::PointNotFound {
+                vertex: Point::new(5.0, 5.0)
+            })
+        );
+    }
+
     #[rstest]
     fn tets_sort_segments_comparator_share_bottom() {
         let mut segments = vec![
@@ -0,0 +1,121 @@
+//! Fill rules deciding which parts of overlapping polygons are filled.
+
+use crate::error::TriangulationError;
+use crate::intersection::split_polygons_on_repeated_edges;
+use crate::planar_graph::{join_rings, label_left_sides, ring_edges, split_rings};
+use crate::point::{Float, Point, Segment};
+use std::collections::HashMap;
+
+type Polygons<T> = Vec<Vec<Point<T>>>;
+type SplitPolygons<T> = (Polygons<T>, Vec<Segment<T>>);
+
+/// Rule deciding whether a point is filled from its winding number,
+/// the number of times the polygons wind counterclockwise around it.
+///
+/// Rules follow SVG `fill-rule` and the winding rules of tessellators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Odd winding numbers are filled, so overlapping polygons create holes.
+    #[default]
+    EvenOdd,
+    /// Non-zero winding numbers are filled.
+    NonZero,
+    /// Positive winding numbers are filled, so only counterclockwise polygons contribute area.
+    Positive,
+    /// Negative winding numbers are filled, so only clockwise polygons contribute area.
+    Negative,
+}
+
+impl FillRule {
+    /// Whether area with the given winding number is filled.
+    pub fn is_filled(self, winding: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+            FillRule::Positive => winding > 0,
+            FillRule::Negative => winding < 0,
+        }
+    }
+}
+
+/// Splits polygons at their intersections and keeps the edges bounding the
+/// area filled according to `fill_rule`.
+///
+/// For [`FillRule::EvenOdd`] this is the same as [`split_polygons_on_repeated_edges`].
+/// For other rules winding numbers of faces of the planar graph of split edges
+/// are computed in a sweep line, and the returned polygons are rings of the
+/// boundary of the filled area, with the filled area on their left side.
+///
+/// # Arguments
+/// * `polygon_list` - Polygons, each as a list of points
+/// * `fill_rule` - Rule deciding which area is filled
+///
+/// # Returns
+/// A tuple of polygons and edges bounding the filled area.
+///
+/// # Errors
+/// Returns [`TriangulationError`] if edges of the polygons cannot be split
+/// into non-crossing edges.
+///
+/// # Example
+/// ```
+/// use triangulation::fill_rule::{split_polygons_with_fill_rule, FillRule};
+/// use triangulation::{Point, Segment};
+///
+/// let square = |x: f32| {
+///     vec![Point::new(x, 0.0), Point::new(x + 2.0, 0.0), Point::new(x + 2.0, 2.0), Point::new(x, 2.0)]
+/// };
+/// let polygons = [square(0.0), square(1.0)];
+/// let inner_edge = Segment::new(Point::new(1.0, 0.0), Point::new(1.0, 2.0));
+/// // overlapping area is a hole for even-odd fill
+/// let (_, edges) = split_polygons_with_fill_rule(&polygons, FillRule::EvenOdd).unwrap();
+/// assert!(edges.contains(&inner_edge));
+/// // and is filled for non-zero fill, which leaves only the outline
+/// let (rings, edges) = split_polygons_with_fill_rule(&polygons, FillRule::NonZero).unwrap();
+/// assert_eq!(rings.len(), 1);
+/// assert!(!edges.contains(&inner_edge));
+/// ```
+pub fn split_polygons_with_fill_rule<T: Float>(
+    polygon_list: &[impl AsRef<[Point<T>]>],
+    fill_rule: FillRule,
+) -> Result<SplitPolygons<T>, TriangulationError<T>> {
+    if fill_rule == FillRule::EvenOdd {
+        return split_polygons_on_repeated_edges(polygon_list);
+    }
+    let rings = split_rings(polygon_list.iter().map(AsRef::as_ref))?;
+
+    // change of the winding number when crossing an edge from its right to its left side
+    let mut windings: HashMap<Segment<T>, i32> = HashMap::new();
+    for ring in &rings {
+        for (start, end) in ring_edges(ring) {
+            let segment = Segment::new(start, end);
+            let upward = segment.bottom == start;
+            *windings.entry(segment).or_default() += if upward { 1 } else { -1 };
+        }
+    }
+    let mut edges: Vec<(Segment<T>, i32)> = windings
+        .into_iter()
+        .filter(|(_, winding)| *winding != 0)
+        .collect();
+    // hash map iteration order is random, keep the result deterministic
+    edges.sort_by_key(|(segment, _)| (segment.top, segment.bottom));
+    let (segments, windings): (Vec<_>, Vec<_>) = edges.into_iter().unzip();
+
+    let left_sides = label_left_sides(&segments, 0, |i, left| left - windings[i]);
+
+    let mut boundary = Vec::new();
+    let mut boundary_edges = Vec::new();
+    for ((segment, winding), left) in segments.into_iter().zip(windings).zip(left_sides) {
+        // keep the filled area on the left side of the edge
+        match (
+            fill_rule.is_filled(left),
+            fill_rule.is_filled(left - winding),
+        ) {
+            (true, false) => boundary.push((segment.bottom, segment.top)),
+            (false, true) => boundary.push((segment.top, segment.bottom)),
+            _ => continue,
+        }
+        boundary_edges.push(segment);
+    }
+    Ok((join_rings(boundary), boundary_edges))
+}
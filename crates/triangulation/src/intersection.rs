@@ -1,31 +1,140 @@
+use crate::error::TriangulationError;
 use crate::point;
-use crate::point::{orientation, Orientation};
+use crate::point::{orientation, Coord, Float, Orientation};
+use crate::sweep_status::SweepStatus;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 
-const EPSILON: f32 = 1e-6;
+type Polygons<T> = Vec<Vec<point::Point<T>>>;
+type SplitPolygons<T> = (Polygons<T>, Vec<point::Segment<T>>);
+type SnappedSplitPolygons<T> = (Polygons<T>, Vec<point::Segment<T>>, SnapDiagnostics<T>);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Event {
-    pub p: point::Point,
+/// Default limit of passes of [`snap_round_polygons`].
+pub const DEFAULT_SNAP_ITERATIONS: usize = 8;
+
+/// Parameters of snap rounding of intersection points.
+///
+/// Intersection points computed in floating point arithmetic do not lie
+/// exactly on the edges they split, so the new edges may cross again.
+/// Snap rounding repeats the search for intersections until no edges cross.
+///
+/// # Fields
+/// * `grid` - Spacing of the grid to which vertices and intersection points are rounded.
+///   Rounding is disabled if it is not positive.
+/// * `tolerance` - Intersection point closer than this to an endpoint of one
+///   of the intersecting edges is replaced by that endpoint.
+/// * `max_iterations` - Maximum number of passes inserting intersection points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapRounding<T = Coord> {
+    pub grid: T,
+    pub tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T: Float> SnapRounding<T> {
+    pub fn new(grid: T, tolerance: T) -> Self {
+        SnapRounding {
+            grid,
+            tolerance,
+            max_iterations: DEFAULT_SNAP_ITERATIONS,
+        }
+    }
+
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        SnapRounding {
+            max_iterations,
+            ..self
+        }
+    }
+
+    /// Round point to the grid, if it is enabled.
+    fn round(&self, p: point::Point<T>) -> point::Point<T> {
+        if self.grid > T::ZERO {
+            point::Point::new(
+                (p.x / self.grid).round() * self.grid,
+                (p.y / self.grid).round() * self.grid,
+            )
+        } else {
+            p
+        }
+    }
+
+    /// Snap intersection point `p` of segments `s1` and `s2`.
+    fn snap(
+        &self,
+        p: point::Point<T>,
+        s1: &point::Segment<T>,
+        s2: &point::Segment<T>,
+    ) -> point::Point<T> {
+        if self.tolerance > T::ZERO {
+            let closest = [s1.bottom, s1.top, s2.bottom, s2.top]
+                .into_iter()
+                .map(|endpoint| (point::vector_length(p, endpoint), endpoint))
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+            if let Some((distance, endpoint)) = closest {
+                if distance <= self.tolerance {
+                    return endpoint;
+                }
+            }
+        }
+        self.round(p)
+    }
+}
+
+impl<T: Float> Default for SnapRounding<T> {
+    fn default() -> Self {
+        SnapRounding::new(T::ZERO, T::ZERO)
+    }
+}
+
+/// Summary of changes applied by [`snap_round_polygons`].
+///
+/// # Fields
+/// * `iterations` - Number of passes which inserted intersection points.
+/// * `inserted_points` - Number of intersection points inserted into edges.
+/// * `snapped_points` - Number of vertices and intersection points moved
+///   by rounding to the grid or by merging with an endpoint.
+/// * `max_displacement` - Largest distance by which a point was moved.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SnapDiagnostics<T = Coord> {
+    pub iterations: usize,
+    pub inserted_points: usize,
+    pub snapped_points: usize,
+    pub max_displacement: T,
+}
+
+impl<T: Float> SnapDiagnostics<T> {
+    fn record(&mut self, from: point::Point<T>, to: point::Point<T>) {
+        if from != to {
+            self.snapped_points += 1;
+            self.max_displacement = self.max_displacement.max(point::vector_length(from, to));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Event<T = Coord> {
+    pub p: point::Point<T>,
     pub index: point::Index,
     pub is_top: bool,
 }
 
-impl Event {
-    pub fn new(p: point::Point, index: point::Index, is_top: bool) -> Self {
+impl<T: Float> Event<T> {
+    pub fn new(p: point::Point<T>, index: point::Index, is_top: bool) -> Self {
         Self { p, index, is_top }
     }
 }
 
-impl PartialOrd for Event {
+impl<T: Float> Eq for Event<T> {}
+
+impl<T: Float> PartialOrd for Event<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Event {
+impl<T: Float> Ord for Event<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         if self.p == other.p {
             if self.is_top == other.is_top {
@@ -45,6 +154,8 @@ impl Ord for Event {
 struct EventData {
     tops: Vec<usize>,
     bottoms: Vec<usize>,
+    /// Pairs of neighboring segments (left, right) crossing at the event point.
+    crossings: Vec<(usize, usize)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -105,7 +216,7 @@ impl OrderedPair {
 /// let q_outside = Point::new(5.0, 5.0);
 /// assert!(!on_segment_if_collinear(&s, q_outside)); // `q_outside` does not lie on the segment
 /// ```
-pub fn on_segment_if_collinear(s: &point::Segment, q: point::Point) -> bool {
+pub fn on_segment_if_collinear<T: Float>(s: &point::Segment<T>, q: point::Point<T>) -> bool {
     // TODO We know that point is collinear, so we may use faster code.
     s.point_on_line(q)
 }
@@ -140,7 +251,7 @@ pub fn on_segment_if_collinear(s: &point::Segment, q: point::Point) -> bool {
 ///
 /// assert!(!do_intersect(&seg3, &seg4)); // The segments do not intersect
 /// ```
-pub fn do_intersect(s1: &point::Segment, s2: &point::Segment) -> bool {
+pub fn do_intersect<T: Float>(s1: &point::Segment<T>, s2: &point::Segment<T>) -> bool {
     let p1 = s1.bottom;
     let q1 = s1.top;
     let p2 = s2.bottom;
@@ -201,16 +312,16 @@ pub fn do_intersect(s1: &point::Segment, s2: &point::Segment) -> bool {
 /// let s4 = Segment::new(Point::new(2.0, 2.0), Point::new(3.0, 3.0));
 /// assert!(!do_share_endpoint(&s3, &s4)); // No shared endpoint
 /// ```
-pub fn do_share_endpoint(s1: &point::Segment, s2: &point::Segment) -> bool {
+pub fn do_share_endpoint<T: Float>(s1: &point::Segment<T>, s2: &point::Segment<T>) -> bool {
     s1.bottom == s2.bottom || s1.bottom == s2.top || s1.top == s2.bottom || s1.top == s2.top
 }
 
 #[derive(Debug, PartialEq)]
-pub enum Intersection {
+pub enum Intersection<T = Coord> {
     NoIntersection,
-    PointIntersection(point::Point),
+    PointIntersection(point::Point<T>),
     CollinearNoOverlap,
-    CollinearWithOverlap((point::Point, point::Point)),
+    CollinearWithOverlap((point::Point<T>, point::Point<T>)),
 }
 
 /// Finds the intersection point of two line segments, if it exists.
@@ -254,14 +365,14 @@ pub enum Intersection {
 ///
 ///
 /// ```
-pub fn find_intersection(s1: &point::Segment, s2: &point::Segment) -> Intersection {
-    let a1 = s1.top.y - s1.bottom.y;
-    let b1 = s1.bottom.x - s1.top.x;
-    let a2 = s2.top.y - s2.bottom.y;
-    let b2 = s2.bottom.x - s2.top.x;
-    let det = a1 * b2 - a2 * b1;
+pub fn find_intersection<T: Float>(
+    s1: &point::Segment<T>,
+    s2: &point::Segment<T>,
+) -> Intersection<T> {
+    let o1 = orientation(s1.bottom, s1.top, s2.bottom);
+    let o2 = orientation(s1.bottom, s1.top, s2.top);
 
-    if det == 0.0 {
+    if o1 == Orientation::Collinear && o2 == Orientation::Collinear {
         // collinear case
         let mut res = Vec::new();
         if s1.point_on_line(s2.bottom) {
@@ -289,28 +400,49 @@ pub fn find_intersection(s1: &point::Segment, s2: &point::Segment) -> Intersecti
         return Intersection::CollinearWithOverlap((res[0], res[1]));
     }
 
-    let t = ((s2.top.x - s1.top.x) * (s2.bottom.y - s2.top.y)
-        - (s2.top.y - s1.top.y) * (s2.bottom.x - s2.top.x))
-        / det;
+    let o3 = orientation(s2.bottom, s2.top, s1.bottom);
+    let o4 = orientation(s2.bottom, s2.top, s1.top);
+    if o1 == o2 || o3 == o4 {
+        // both ends of one segment lie strictly on the same side of the other one
+        return Intersection::NoIntersection;
+    }
 
-    // clip to handle problems with floating point precision
-    if t < 0.0 {
-        return if t > -EPSILON {
-            Intersection::PointIntersection(s1.top)
-        } else {
-            Intersection::NoIntersection
-        };
+    // Segments touch at an endpoint lying on the other segment,
+    // return it exactly instead of computing it from the line equations.
+    if o1 == Orientation::Collinear {
+        return Intersection::PointIntersection(s2.bottom);
     }
-    if t > 1.0 {
-        return if t < 1.0 + EPSILON {
-            Intersection::PointIntersection(s1.bottom)
-        } else {
-            Intersection::NoIntersection
-        };
+    if o2 == Orientation::Collinear {
+        return Intersection::PointIntersection(s2.top);
+    }
+    if o3 == Orientation::Collinear {
+        return Intersection::PointIntersection(s1.bottom);
+    }
+    if o4 == Orientation::Collinear {
+        return Intersection::PointIntersection(s1.top);
     }
 
-    let x = s1.top.x + t * b1;
-    let y = s1.top.y + t * (-a1);
+    // Proper crossing, compute the point in `f64` and keep it
+    // inside bounding boxes of both segments after rounding.
+    let (top1, bottom1) = (s1.top.cast::<f64>(), s1.bottom.cast::<f64>());
+    let (top2, bottom2) = (s2.top.cast::<f64>(), s2.bottom.cast::<f64>());
+    let a1 = top1.y - bottom1.y;
+    let b1 = bottom1.x - top1.x;
+    let a2 = top2.y - bottom2.y;
+    let b2 = bottom2.x - top2.x;
+    let det = a1 * b2 - a2 * b1;
+    let t =
+        ((top2.x - top1.x) * (bottom2.y - top2.y) - (top2.y - top1.y) * (bottom2.x - top2.x)) / det;
+    // `det` may round to zero for nearly parallel segments
+    let t = if t.is_nan() { 0.5 } else { t.clamp(0.0, 1.0) };
+
+    let min_x = s1.bottom.x.min(s1.top.x).max(s2.bottom.x.min(s2.top.x));
+    let max_x = s1.bottom.x.max(s1.top.x).min(s2.bottom.x.max(s2.top.x));
+    // `bottom` is never above `top`
+    let min_y = s1.bottom.y.max(s2.bottom.y);
+    let max_y = s1.top.y.min(s2.top.y);
+    let x = T::from_f64(top1.x + t * b1).clamp(min_x, max_x);
+    let y = T::from_f64(top1.y - t * a1).clamp(min_y, max_y);
     Intersection::PointIntersection(point::Point { x, y })
 }
 
@@ -319,6 +451,10 @@ pub fn find_intersection(s1: &point::Segment, s2: &point::Segment) -> Intersecti
 /// This function takes a vector of line segments and returns a set of pairs of
 /// segment indices that intersect. The pairs are ordered to ensure uniqueness
 /// regardless of the order of segments in the input vector.
+/// Segments sharing an endpoint are not reported.
+///
+/// Uses the Bentley-Ottmann sweep, which takes `O((n + k) log n)` time
+/// for `n` segments and `k` intersecting pairs.
 ///
 /// # Arguments
 ///
@@ -344,54 +480,238 @@ pub fn find_intersection(s1: &point::Segment, s2: &point::Segment) -> Intersecti
 /// let expected_intersections: HashSet<OrderedPair> = [(0, 1)].iter().map(|&(a, b)| OrderedPair::new(a, b)).collect();
 /// assert_eq!(intersections, expected_intersections);
 /// ```
-pub fn find_intersections(segments: &[point::Segment]) -> HashSet<OrderedPair> {
-    let mut intersections = HashSet::new();
-    let mut intersection_events: BTreeMap<point::Point, EventData> = BTreeMap::new();
-    for (i, segment) in segments.iter().enumerate() {
-        intersection_events
-            .entry(segment.top)
-            .or_default()
-            .tops
-            .push(i);
-        intersection_events
-            .entry(segment.bottom)
-            .or_default()
-            .bottoms
-            .push(i);
-    }
-
-    let mut active: BTreeMap<point::Point, HashSet<point::Index>> = BTreeMap::new();
-
-    while let Some((&point, event_data)) = intersection_events.iter().next_back() {
-        for &event_index in &event_data.tops {
-            for active_el in active.iter() {
-                for &index in active_el.1 {
-                    if do_intersect(&segments[event_index], &segments[index])
-                        && !do_share_endpoint(&segments[event_index], &segments[index])
-                    {
-                        intersections.insert(OrderedPair::new(event_index, index));
-                    }
+pub fn find_intersections<T: Float>(segments: &[point::Segment<T>]) -> HashSet<OrderedPair> {
+    IntersectionSweep::new(segments).run()
+}
+
+/// Bentley-Ottmann sweep reporting pairs of intersecting segments.
+///
+/// The sweep line moves from the greatest to the smallest point. Active segments
+/// are kept in [`SweepStatus`] ordered by position on the sweep line and each
+/// segment is tested only against its neighbors. At a vertex, all active segments
+/// passing through it are found with exact orientation tests, reported as
+/// intersecting and reordered, which covers touching, overlapping and multiple
+/// segments crossing at a single point. Proper crossings are scheduled as events
+/// at the crossing point, computed in `f64`, where the two segments are swapped.
+struct IntersectionSweep<'a, T: Float> {
+    segments: &'a [point::Segment<T>],
+    status: SweepStatus,
+    events: BTreeMap<point::Point<f64>, EventData>,
+    intersections: HashSet<OrderedPair>,
+}
+
+impl<'a, T: Float> IntersectionSweep<'a, T> {
+    fn new(segments: &'a [point::Segment<T>]) -> Self {
+        let mut events: BTreeMap<point::Point<f64>, EventData> = BTreeMap::new();
+        for (i, segment) in segments.iter().enumerate() {
+            events.entry(segment.top.cast()).or_default().tops.push(i);
+            events
+                .entry(segment.bottom.cast())
+                .or_default()
+                .bottoms
+                .push(i);
+        }
+        Self {
+            segments,
+            status: SweepStatus::new(segments.len()),
+            events,
+            intersections: HashSet::new(),
+        }
+    }
+
+    fn run(mut self) -> HashSet<OrderedPair> {
+        while let Some((p, event)) = self.events.pop_last() {
+            let mut crossings = event.crossings;
+            if !event.tops.is_empty() || !event.bottoms.is_empty() {
+                // vertex events are exact points of type `T`
+                self.process_vertex(p, event.tops, event.bottoms, &mut crossings);
+            }
+            while let Some((left, right)) = crossings.pop() {
+                self.process_crossing(p, left, right, &mut crossings);
+            }
+        }
+        self.intersections
+    }
+
+    fn process_vertex(
+        &mut self,
+        p: point::Point<f64>,
+        tops: Vec<usize>,
+        bottoms: Vec<usize>,
+        crossings: &mut Vec<(usize, usize)>,
+    ) {
+        let vertex = p.cast::<T>();
+        let side = |segment: &point::Segment<T>| orientation(segment.bottom, segment.top, vertex);
+
+        // active segments passing through the vertex form a contiguous run
+        let start = self
+            .status
+            .partition_point(|i| side(&self.segments[i]) == Orientation::Clockwise);
+        let before = match start {
+            Some(start) => self.status.prev(start),
+            None => self.status.last(),
+        };
+        let mut run = Vec::new();
+        let mut after = start;
+        while let Some(i) = after {
+            if side(&self.segments[i]) != Orientation::Collinear {
+                break;
+            }
+            run.push(i);
+            after = self.status.next(i);
+        }
+
+        // all segments containing the vertex intersect each other
+        let through: Vec<usize> = run.iter().chain(tops.iter()).copied().collect();
+        for (k, &i) in through.iter().enumerate() {
+            for &j in &through[k + 1..] {
+                if !do_share_endpoint(&self.segments[i], &self.segments[j]) {
+                    self.intersections.insert(OrderedPair::new(i, j));
+                }
+            }
+        }
+
+        for &i in &run {
+            self.status.remove(i);
+        }
+        // Segments ending here are always in the run, unless the order of
+        // the status was broken by a rounded crossing point.
+        for &i in &bottoms {
+            if self.status.contains(i) {
+                let (prev, next) = (self.status.prev(i), self.status.next(i));
+                self.status.remove(i);
+                if let (Some(prev), Some(next)) = (prev, next) {
+                    self.check_pair(p, prev, next, crossings);
                 }
             }
         }
-        active
-            .entry(point)
-            .or_default()
-            .extend(event_data.tops.iter());
 
-        for &event_index in &event_data.bottoms {
-            if let Some(entry) = active.get_mut(&segments[event_index].top) {
-                entry.remove(&event_index);
-                if entry.is_empty() {
-                    active.remove(&segments[event_index].top);
+        let mut continuing: Vec<usize> = run
+            .into_iter()
+            .filter(|&i| self.segments[i].bottom != vertex)
+            .chain(tops)
+            .collect();
+        continuing.sort_by(|&i, &j| {
+            order_below_common_point(&self.segments[i], &self.segments[j]).then(i.cmp(&j))
+        });
+        for &i in &continuing {
+            self.status.insert_before(i, after);
+        }
+
+        match (continuing.first(), continuing.last()) {
+            (Some(&first), Some(&last)) => {
+                if let Some(before) = before {
+                    self.check_pair(p, before, first, crossings);
+                }
+                if let Some(after) = after {
+                    self.check_pair(p, last, after, crossings);
+                }
+            }
+            _ => {
+                if let (Some(before), Some(after)) = (before, after) {
+                    self.check_pair(p, before, after, crossings);
                 }
             }
         }
+    }
+
+    /// Swap neighboring segments `left` and `right` crossing at `p`.
+    fn process_crossing(
+        &mut self,
+        p: point::Point<f64>,
+        left: usize,
+        right: usize,
+        crossings: &mut Vec<(usize, usize)>,
+    ) {
+        // The pair may be already swapped at a vertex, or separated by a segment
+        // crossing at the same point. In the latter case the pair is scheduled
+        // again when it becomes neighboring.
+        if !self.status.contains(left) || self.status.next(left) != Some(right) {
+            return;
+        }
+        self.status.remove(left);
+        self.status.insert_after(left, right);
+        if let Some(prev) = self.status.prev(right) {
+            self.check_pair(p, prev, right, crossings);
+        }
+        if let Some(next) = self.status.next(left) {
+            self.check_pair(p, left, next, crossings);
+        }
+    }
 
-        intersection_events.remove(&point);
+    /// Report neighboring segments `left` and `right` if they intersect and
+    /// schedule their swap if they cross below the current point `p`.
+    fn check_pair(
+        &mut self,
+        p: point::Point<f64>,
+        left: usize,
+        right: usize,
+        crossings: &mut Vec<(usize, usize)>,
+    ) {
+        let (s1, s2) = (&self.segments[left], &self.segments[right]);
+        if do_share_endpoint(s1, s2) || !do_intersect(s1, s2) {
+            return;
+        }
+        self.intersections.insert(OrderedPair::new(left, right));
+
+        // touching segments are handled at vertices
+        let orientations = [
+            orientation(s1.bottom, s1.top, s2.bottom),
+            orientation(s1.bottom, s1.top, s2.top),
+            orientation(s2.bottom, s2.top, s1.bottom),
+            orientation(s2.bottom, s2.top, s1.top),
+        ];
+        if orientations.contains(&Orientation::Collinear)
+            || order_above_crossing(s1, s2) != Ordering::Less
+        {
+            return;
+        }
+        let cast = |s: &point::Segment<T>| point::Segment::new(s.bottom.cast(), s.top.cast());
+        let crossing = match find_intersection::<f64>(&cast(s1), &cast(s2)) {
+            Intersection::PointIntersection(crossing) => crossing,
+            _ => p,
+        };
+        if crossing < p {
+            self.events
+                .entry(crossing)
+                .or_default()
+                .crossings
+                .push((left, right));
+        } else {
+            // crossing point rounded to an already processed position
+            crossings.push((left, right));
+        }
     }
+}
 
-    intersections
+/// Order on the sweep line of segments `s1` and `s2` properly crossing each other,
+/// above the crossing point.
+fn order_above_crossing<T: Float>(s1: &point::Segment<T>, s2: &point::Segment<T>) -> Ordering {
+    // compare the top of the segment which starts later with the other one
+    if s1.top < s2.top {
+        match orientation(s2.bottom, s2.top, s1.top) {
+            Orientation::CounterClockwise => Ordering::Less,
+            _ => Ordering::Greater,
+        }
+    } else {
+        match orientation(s1.bottom, s1.top, s2.top) {
+            Orientation::CounterClockwise => Ordering::Greater,
+            _ => Ordering::Less,
+        }
+    }
+}
+
+/// Order on the sweep line of segments `s1` and `s2` passing through
+/// a common point, below that point.
+pub(crate) fn order_below_common_point<T: Float>(
+    s1: &point::Segment<T>,
+    s2: &point::Segment<T>,
+) -> Ordering {
+    match orientation(s1.bottom, s1.top, s2.bottom) {
+        Orientation::CounterClockwise => Ordering::Greater,
+        Orientation::Clockwise => Ordering::Less,
+        Orientation::Collinear => Ordering::Equal,
+    }
 }
 
 /// Calculates the edges of polygons from a list of polygons, provided as
@@ -411,15 +731,21 @@ pub fn find_intersections(segments: &[point::Segment]) -> HashSet<OrderedPair> {
 /// # Returns
 ///
 /// A vector of `Segment` instances representing the edges of all polygons in the input list
-pub fn calc_edges(polygon_list: &[Vec<point::Point>]) -> Vec<point::Segment> {
+pub fn calc_edges<T: Float>(
+    polygon_list: &[impl AsRef<[point::Point<T>]>],
+) -> Vec<point::Segment<T>> {
     // Calculate total number of points for capacity pre-allocation
-    let points_count: usize = polygon_list.iter().map(|polygon| polygon.len()).sum();
+    let points_count: usize = polygon_list
+        .iter()
+        .map(|polygon| polygon.as_ref().len())
+        .sum();
 
     // Pre-allocate the vector with the calculated capacity
     let mut edges = Vec::with_capacity(points_count);
 
     // Process each polygon
     for polygon in polygon_list {
+        let polygon = polygon.as_ref();
         // Create edges between consecutive points
         for window in polygon.windows(2) {
             edges.push(point::Segment::new(window[0], window[1]));
@@ -471,28 +797,48 @@ pub fn calc_edges(polygon_list: &[Vec<point::Point>]) -> Vec<point::Segment> {
 /// The function preserves the order of the original polygons but may add additional points
 /// where intersections occur. The resulting polygons maintain their closed nature
 /// (first point equals last point if that was true in the input).
-pub fn find_intersection_points(polygon_list: &[Vec<point::Point>]) -> Vec<Vec<point::Point>> {
+pub fn find_intersection_points<T: Float>(
+    polygon_list: &[Vec<point::Point<T>>],
+) -> Vec<Vec<point::Point<T>>> {
+    insert_intersection_points(
+        polygon_list,
+        &SnapRounding::default(),
+        &mut SnapDiagnostics::default(),
+    )
+    .unwrap_or_else(|| polygon_list.to_vec())
+}
+
+/// Single pass of [`find_intersection_points`] with intersection points snapped
+/// according to `snap`. Returns `None` if no edges intersect.
+fn insert_intersection_points<T: Float>(
+    polygon_list: &[Vec<point::Point<T>>],
+    snap: &SnapRounding<T>,
+    diagnostics: &mut SnapDiagnostics<T>,
+) -> Option<Polygons<T>> {
     // Calculate edges from the polygon list
     let edges = calc_edges(polygon_list);
 
     // Find intersections using the existing function
     let intersections = find_intersections(&edges);
     if intersections.is_empty() {
-        return polygon_list.to_vec();
+        return None;
     }
 
     // Create a HashMap to store intersection points for each edge
-    let mut intersections_points: HashMap<usize, Vec<(point::Coord, point::Point)>> =
-        HashMap::new();
+    let mut intersections_points: HashMap<usize, Vec<(T, point::Point<T>)>> = HashMap::new();
 
     // Process each intersection
     for intersection in intersections {
-        let inter_points =
-            find_intersection(&edges[intersection.first()], &edges[intersection.second()]);
+        let (edge1, edge2) = (&edges[intersection.first()], &edges[intersection.second()]);
+        let inter_points = find_intersection(edge1, edge2);
 
         // Handle the intersection points based on the intersection type
         match inter_points {
             Intersection::PointIntersection(point) => {
+                let snapped = snap.snap(point, edge1, edge2);
+                diagnostics.record(point, snapped);
+                diagnostics.inserted_points += 1;
+                let point = snapped;
                 intersections_points
                     .entry(intersection.first())
                     .or_default()
@@ -512,6 +858,7 @@ pub fn find_intersection_points(polygon_list: &[Vec<point::Point>]) -> Vec<Vec<p
                 // Handle collinear overlap case if needed
                 // to add both points to both edges
                 for point in [p1, p2] {
+                    diagnostics.inserted_points += 1;
                     intersections_points
                         .entry(intersection.first())
                         .or_default()
@@ -535,8 +882,8 @@ pub fn find_intersection_points(polygon_list: &[Vec<point::Point>]) -> Vec<Vec<p
     // Sort and add endpoint markers for each edge's intersections
     for (edge_idx, points) in intersections_points.iter_mut() {
         let edge = &edges[*edge_idx];
-        points.push((-1.0, edge.top));
-        points.push((2.0, edge.bottom));
+        points.push((-T::ONE, edge.top));
+        points.push((T::TWO, edge.bottom));
         points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
     }
 
@@ -546,6 +893,14 @@ pub fn find_intersection_points(polygon_list: &[Vec<point::Point>]) -> Vec<Vec<p
     let mut polygon_shift = 0;
 
     for polygon in polygon_list {
+        // empty rings keep their place, so indices of the other rings do not change
+        if polygon.is_empty() {
+            new_polygons_list.push(Vec::new());
+            continue;
+        }
+        // number of edges of the ring in `calc_edges`, which skips the closing
+        // edge of explicitly closed rings
+        let edges_count = polygon.len() - usize::from(polygon.first() == polygon.last());
         let mut new_polygon = Vec::with_capacity(polygon.len() * 2);
         new_polygon.push(polygon[0]);
 
@@ -553,6 +908,9 @@ pub fn find_intersection_points(polygon_list: &[Vec<point::Point>]) -> Vec<Vec<p
             if new_polygon.last() != Some(point) {
                 new_polygon.push(*point);
             }
+            if i >= edges_count {
+                continue;
+            }
 
             if let Some(new_points) = intersections_points.get(&(i + polygon_shift)) {
                 if new_points[0].1 == *point {
@@ -579,40 +937,143 @@ pub fn find_intersection_points(polygon_list: &[Vec<point::Point>]) -> Vec<Vec<p
         }
 
         new_polygons_list.push(new_polygon);
-        polygon_shift += polygon.len();
+        polygon_shift += edges_count;
     }
 
-    new_polygons_list
+    Some(new_polygons_list)
 }
 
-/// Splits a list of polygons into collinear and non-collinear groups.
+/// Checks that all vertices of the polygons have finite coordinates,
+/// as the intersection sweep cannot order other points.
+fn check_finite<T: Float>(
+    polygon_list: &[impl AsRef<[point::Point<T>]>],
+) -> Result<(), TriangulationError<T>> {
+    for (polygon, points) in polygon_list.iter().enumerate() {
+        if let Some(&vertex) = points
+            .as_ref()
+            .iter()
+            .find(|p| !(p.x.is_finite() && p.y.is_finite()))
+        {
+            return Err(TriangulationError::NonFiniteVertex { polygon, vertex });
+        }
+    }
+    Ok(())
+}
+
+/// Inserts intersection points into polygon edges until no edges cross.
 ///
-/// Returns a tuple where the first element contains polygons whose consecutive triplets of vertices (including wrap-around triplets) are all collinear, and the second element contains all other polygons.
-fn filter_collinear_polygons(
-    polygon_list: &[Vec<point::Point>],
-) -> (Vec<Vec<point::Point>>, Vec<Vec<point::Point>>) {
-    polygon_list.iter().cloned().partition(|polygon| {
-        let n = polygon.len();
-        if n < 3 {
-            return true;
+/// Each pass works like [`find_intersection_points`]. Intersection points are
+/// rounded to the grid or merged with nearby endpoints as configured by `snap`,
+/// and new edges are checked again, as rounded points may create new crossings.
+/// If the grid is enabled, input vertices are rounded to it first.
+///
+/// # Arguments
+/// * `polygon_list` - A slice of vectors where each vector contains points defining a polygon
+/// * `snap` - Snap rounding parameters
+///
+/// # Returns
+/// Polygons with intersection points inserted, whose edges meet only at their
+/// endpoints or overlap, and a summary of the applied snapping.
+///
+/// # Errors
+/// Returns [`TriangulationError::NonFiniteVertex`] if a vertex has a NaN or
+/// infinite coordinate, and [`TriangulationError::UnresolvedIntersection`]
+/// if edges still cross after `snap.max_iterations` passes.
+///
+/// # Examples
+/// ```
+/// use triangulation::point::Point;
+/// use triangulation::intersection::{snap_round_polygons, SnapRounding};
+///
+/// let square = vec![
+///     Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(3.0, 3.0), Point::new(0.0, 3.0),
+/// ];
+/// let bow = vec![Point::new(1.1, -1.0), Point::new(1.9, 4.0), Point::new(2.9, 4.0)];
+/// let (polygons, diagnostics) =
+///     snap_round_polygons(&[square, bow], &SnapRounding::new(0.5, 0.0)).unwrap();
+/// assert_eq!(polygons[0].len(), 8); // four intersection points inserted
+/// assert!(polygons.iter().flatten().all(|p| (p.x * 2.0_f32).fract() == 0.0));
+/// assert!(diagnostics.max_displacement <= 0.5);
+/// ```
+pub fn snap_round_polygons<T: Float>(
+    polygon_list: &[impl AsRef<[point::Point<T>]>],
+    snap: &SnapRounding<T>,
+) -> Result<(Polygons<T>, SnapDiagnostics<T>), TriangulationError<T>> {
+    check_finite(polygon_list)?;
+    let mut diagnostics = SnapDiagnostics::default();
+    let mut polygons: Polygons<T> = polygon_list
+        .iter()
+        .map(|polygon| polygon.as_ref().to_vec())
+        .collect();
+    if snap.grid > T::ZERO {
+        for polygon in polygons.iter_mut() {
+            for p in polygon.iter_mut() {
+                let rounded = snap.round(*p);
+                diagnostics.record(*p, rounded);
+                *p = rounded;
+            }
+            polygon.dedup();
+            while polygon.len() > 1 && polygon.first() == polygon.last() {
+                polygon.pop();
+            }
+        }
+    }
+
+    for _ in 0..snap.max_iterations {
+        match insert_intersection_points(&polygons, snap, &mut diagnostics) {
+            Some(new_polygons) if new_polygons != polygons => {
+                diagnostics.iterations += 1;
+                polygons = new_polygons;
+            }
+            // edges meet only at existing vertices or overlap
+            Some(_) | None => return Ok((polygons, diagnostics)),
         }
+    }
+
+    // the last pass inserted points, which may have created new crossings
+    let edges = calc_edges(&polygons);
+    match find_intersections(&edges).into_iter().min() {
+        None => Ok((polygons, diagnostics)),
+        Some(pair) => Err(TriangulationError::UnresolvedIntersection {
+            vertex: edges[pair.first()].top,
+        }),
+    }
+}
+
+/// Checks if all consecutive triplets of polygon vertices (including wrap-around triplets) are collinear.
+///
+/// Polygons with fewer than 3 vertices are considered collinear.
+fn is_collinear_polygon<T: Float>(polygon: &[point::Point<T>]) -> bool {
+    let n = polygon.len();
+    if n < 3 {
+        return true;
+    }
 
-        // Check if all triplets of vertices, including the ones that wrap around, are collinear.
-        let are_all_vertices_collinear = polygon
-            .windows(3)
-            // Check the main body of the polygon
-            .all(|w| orientation(w[0], w[1], w[2]) == Orientation::Collinear)
-            // And also check the two triplets that wrap around the start/end
-            && orientation(polygon[n - 2], polygon[n - 1], polygon[0]) == Orientation::Collinear
-            && orientation(polygon[n - 1], polygon[0], polygon[1]) == Orientation::Collinear;
+    // Check if all triplets of vertices, including the ones that wrap around, are collinear.
+    polygon
+        .windows(3)
+        // Check the main body of the polygon
+        .all(|w| orientation(w[0], w[1], w[2]) == Orientation::Collinear)
+        // And also check the two triplets that wrap around the start/end
+        && orientation(polygon[n - 2], polygon[n - 1], polygon[0]) == Orientation::Collinear
+        && orientation(polygon[n - 1], polygon[0], polygon[1]) == Orientation::Collinear
+}
 
-        are_all_vertices_collinear
-    })
+/// Splits a list of polygons into collinear and non-collinear groups.
+///
+/// Returns a tuple where the first element contains polygons whose consecutive triplets of vertices (including wrap-around triplets) are all collinear, and the second element contains all other polygons.
+fn filter_collinear_polygons<T: Float>(
+    polygon_list: &[impl AsRef<[point::Point<T>]>],
+) -> (Polygons<T>, Polygons<T>) {
+    polygon_list
+        .iter()
+        .map(|polygon| polygon.as_ref().to_vec())
+        .partition(|polygon| is_collinear_polygon(polygon))
 }
 
 #[derive(Default)]
-struct GraphNode {
-    edges: Vec<point::Point>,
+struct GraphNode<T> {
+    edges: Vec<point::Point<T>>,
     visited: bool,
     sub_index: usize,
 }
@@ -635,6 +1096,13 @@ struct GraphNode {
 /// * A `Vec<Vec<Point>>` representing the split polygons as individual vectors of points
 /// * A `Vec<Segment>` containing the deduplicated list of edges used during splitting
 ///
+/// # Errors
+/// Returns [`TriangulationError`] if a vertex is not finite or if edge
+/// deduplication fails. The polygon index in the error refers to `polygon_list`.
+/// Returns [`TriangulationError::UnresolvedIntersection`] if edges still cross
+/// after [`DEFAULT_SNAP_ITERATIONS`] passes inserting intersection points,
+/// as inserted points rounded to the coordinate type may create new crossings.
+///
 /// # Purpose
 /// This function is designed for edge triangulation (from `path_triangulation.rs`) and
 /// provides the edges that can be further used for face triangulation. It ensures that
@@ -659,21 +1127,70 @@ struct GraphNode {
 ///     Point::new(1.0, 1.0)
 /// ];
 ///
-/// let (polygons, edges) = split_polygons_on_repeated_edges(&vec![polygon1]);
+/// let (polygons, edges) = split_polygons_on_repeated_edges(&vec![polygon1]).unwrap();
 ///
 /// // The polygons are split at intersection points
 /// assert_eq!(polygons.len(), 2); // More polygons after splitting at intersections
 /// ```
 #[inline]
-pub fn split_polygons_on_repeated_edges(
-    polygon_list: &[Vec<point::Point>],
-) -> (Vec<Vec<point::Point>>, Vec<point::Segment>) {
+pub fn split_polygons_on_repeated_edges<T: Float>(
+    polygon_list: &[impl AsRef<[point::Point<T>]>],
+) -> Result<SplitPolygons<T>, TriangulationError<T>> {
+    let (polygons, edges, _) =
+        split_polygons_with_snap_rounding(polygon_list, &SnapRounding::default())?;
+    Ok((polygons, edges))
+}
+
+/// Variant of [`split_polygons_on_repeated_edges`] with configurable snap rounding
+/// of intersection points, see [`snap_round_polygons`].
+///
+/// # Returns
+/// A tuple containing the split polygons, the deduplicated list of edges,
+/// which do not cross each other, and a summary of the applied snapping.
+///
+/// # Errors
+/// Returns [`TriangulationError`] if a vertex is not finite, if edge deduplication
+/// fails or if edges still cross after the allowed number of snap rounding passes.
+///
+/// # Examples
+/// ```
+/// use triangulation::point::Point;
+/// use triangulation::intersection::{split_polygons_with_snap_rounding, SnapRounding};
+///
+/// let polygon = vec![
+///     Point::new(0.0, 0.0), Point::new(2.0, 2.0), Point::new(2.0, 0.0), Point::new(0.0, 2.0),
+/// ];
+/// let (_polygons, edges, diagnostics) =
+///     split_polygons_with_snap_rounding(&[polygon], &SnapRounding::new(0.25, 0.0)).unwrap();
+/// assert_eq!(edges.len(), 6); // both diagonals are split at (1, 1)
+/// assert_eq!(diagnostics.inserted_points, 1);
+/// ```
+pub fn split_polygons_with_snap_rounding<T: Float>(
+    polygon_list: &[impl AsRef<[point::Point<T>]>],
+    snap: &SnapRounding<T>,
+) -> Result<SnappedSplitPolygons<T>, TriangulationError<T>> {
+    // checked before filtering, so the error refers to the input list
+    check_finite(polygon_list)?;
     let (mut collinear_polygons, normal_polygons) = filter_collinear_polygons(polygon_list);
-    let intersected = find_intersection_points(&normal_polygons);
-    let edges_dedup = point::calc_dedup_edges(&intersected);
-    let mut edge_map: HashMap<point::Point, GraphNode> = HashMap::new();
-    let mut sub_polygons: Vec<Vec<point::Point>> = Vec::new();
-    let mut visited_edges: HashSet<point::Segment> = HashSet::new();
+    let (mut intersected, diagnostics) = snap_round_polygons(&normal_polygons, snap)?;
+    if snap.grid > T::ZERO {
+        // rounding to the grid may collapse polygons
+        let (collapsed, rest) = filter_collinear_polygons(&intersected);
+        collinear_polygons.extend(collapsed);
+        intersected = rest;
+    }
+    let edges_dedup = point::calc_dedup_edges(&intersected).map_err(|err| {
+        // Errors refer to the filtered list, map index back to the input list
+        err.map_polygon_index(|index| {
+            (0..polygon_list.len())
+                .filter(|&i| !is_collinear_polygon(polygon_list[i].as_ref()))
+                .nth(index)
+                .unwrap_or(index)
+        })
+    })?;
+    let mut edge_map: HashMap<point::Point<T>, GraphNode<T>> = HashMap::new();
+    let mut sub_polygons: Vec<Vec<point::Point<T>>> = Vec::new();
+    let mut visited_edges: HashSet<point::Segment<T>> = HashSet::new();
 
     // Build undirected graph
     for edge in edges_dedup.iter() {
@@ -713,7 +1230,7 @@ pub fn split_polygons_on_repeated_edges(
         }
     }
     sub_polygons.append(&mut collinear_polygons);
-    (sub_polygons, edges_dedup)
+    Ok((sub_polygons, edges_dedup, diagnostics))
 }
 
 #[cfg(test)]
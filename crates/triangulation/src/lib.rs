@@ -2,16 +2,55 @@
 //!
 //! These algorithms are designed for performance when working with polygons.
 
+mod batch;
+pub mod boolean;
+pub mod contour;
+pub mod delaunay;
+pub mod error;
 pub mod face_triangulation;
+pub mod fill_rule;
 pub mod intersection;
 pub mod monotone_polygon;
+pub mod offset;
 pub mod path_triangulation;
+pub mod path_triangulation_3d;
+mod planar_graph;
+pub mod plane;
 pub mod point;
+pub mod predicates;
+pub mod rasterize;
+pub mod rectilinear;
+pub mod simplify;
+mod sweep_status;
 
+pub use crate::boolean::{polygon_boolean, BooleanOp};
+pub use crate::contour::{label_contours, trace_label_boundaries};
+pub use crate::delaunay::Refinement;
+pub use crate::error::TriangulationError;
 pub use crate::face_triangulation::{
-    is_convex, sweeping_line_triangulation, triangulate_convex_polygon,
+    is_convex, sweeping_line_triangulation, triangulate_convex_polygon, triangulate_shape_face,
+    triangulate_shape_face_styled, triangulate_shape_face_with_fill_rule, triangulate_shapes_face,
+    FaceStyle, ShapesTriangulation, TriangulationQuality,
 };
-pub use crate::intersection::split_polygons_on_repeated_edges;
-pub use crate::path_triangulation::PathTriangulation;
-pub use crate::path_triangulation::{triangulate_path_edge, triangulate_paths_edge};
-pub use crate::point::{Point, Segment, Triangle};
+pub use crate::fill_rule::{split_polygons_with_fill_rule, FillRule};
+pub use crate::intersection::{
+    split_polygons_on_repeated_edges, split_polygons_with_snap_rounding, SnapDiagnostics,
+    SnapRounding,
+};
+pub use crate::offset::{offset_polygons, OffsetStyle};
+pub use crate::path_triangulation::{
+    triangulate_path_edge, triangulate_path_edge_dashed, triangulate_path_edge_styled,
+    triangulate_path_edge_with_widths, triangulate_paths_edge, triangulate_paths_edge_styled,
+};
+pub use crate::path_triangulation::{
+    CapStyle, DashPattern, JoinStyle, PathStyle, PathTriangulation,
+};
+pub use crate::path_triangulation_3d::{
+    triangulate_path_ribbon, triangulate_path_tube, RibbonTriangulation, TubeStyle,
+    TubeTriangulation,
+};
+pub use crate::plane::{project_to_plane, Plane};
+pub use crate::point::{Float, Point, Segment, Triangle};
+pub use crate::rasterize::{rasterize_polygons, rasterize_shapes, RasterMode};
+pub use crate::rectilinear::triangulate_rectilinear_face;
+pub use crate::simplify::{simplify_path, simplify_polygons, SimplifyMethod, SimplifyStyle};
@@ -1,9 +1,10 @@
-use crate::point::{orientation, Orientation, Point, PointTriangle};
+use crate::error::TriangulationError;
+use crate::point::{orientation, Coord, Float, Orientation, Point, PointTriangle};
 use std::cmp::PartialEq;
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone)]
-pub struct MonotonePolygon {
+pub struct MonotonePolygon<T = Coord> {
     /// Represents a y-monotone polygon, which is a polygon whose vertices are split
     /// into two chains (left and right) based on their y-coordinates, and the topmost
     /// and bottommost vertices are connected by these chains.
@@ -54,14 +55,14 @@ pub struct MonotonePolygon {
     /// let polygon = MonotonePolygon::new(top, bottom, left_chain, right_chain);
     /// assert!(polygon.finished());
     /// ```
-    pub top: Point,
-    pub bottom: Option<Point>,
-    pub left: Vec<Point>,
-    pub right: Vec<Point>,
+    pub top: Point<T>,
+    pub bottom: Option<Point<T>>,
+    pub left: Vec<Point<T>>,
+    pub right: Vec<Point<T>>,
 }
 
-impl MonotonePolygon {
-    pub fn new(top: Point, bottom: Point, left: Vec<Point>, right: Vec<Point>) -> Self {
+impl<T: Float> MonotonePolygon<T> {
+    pub fn new(top: Point<T>, bottom: Point<T>, left: Vec<Point<T>>, right: Vec<Point<T>>) -> Self {
         Self {
             top,
             bottom: Some(bottom),
@@ -70,7 +71,7 @@ impl MonotonePolygon {
         }
     }
 
-    pub fn new_top(top: Point) -> Self {
+    pub fn new_top(top: Point<T>) -> Self {
         Self {
             top,
             bottom: None,
@@ -100,10 +101,10 @@ impl MonotonePolygon {
 ///   resulting triangles generated during the triangulation process.
 /// - `current_point`: The current point being processed, which belongs to the opposite
 ///   edge of the polygon relative to the previous point.
-fn build_triangles_opposite_edge(
-    stack: &mut VecDeque<Point>,
-    result: &mut Vec<PointTriangle>,
-    current_point: Point,
+fn build_triangles_opposite_edge<T: Float>(
+    stack: &mut VecDeque<Point<T>>,
+    result: &mut Vec<PointTriangle<T>>,
+    current_point: Point<T>,
 ) {
     #[cfg(debug_assertions)]
     {
@@ -137,10 +138,10 @@ fn build_triangles_opposite_edge(
 ///   previous point.
 /// - `expected_orientation`: The expected orientation of the triangles to be formed, determined based
 ///   on whether the current chain belongs to the left or right edge of the polygon.
-fn build_triangles_current_edge(
-    stack: &mut VecDeque<Point>,
-    result: &mut Vec<PointTriangle>,
-    current_point: Point,
+fn build_triangles_current_edge<T: Float>(
+    stack: &mut VecDeque<Point<T>>,
+    result: &mut Vec<PointTriangle<T>>,
+    current_point: Point<T>,
     expected_orientation: Side,
 ) {
     let mut i = stack.len() - 1;
@@ -188,6 +189,10 @@ enum Side {
 /// A vector of `PointTriangle` representing the resulting triangles formed
 /// during the triangulation process.
 ///
+/// # Errors
+/// Returns [`TriangulationError::InvalidSweepState`] at the top vertex
+/// if the polygon is not finished, i.e. its bottom is not set.
+///
 /// # Example
 ///
 /// ```rust
@@ -200,18 +205,23 @@ enum Side {
 /// let right_chain = vec![Point::new(1.0, 8.0), Point::new(2.0, 6.0)];
 ///
 /// let polygon = MonotonePolygon::new(top, bottom, left_chain, right_chain);
-/// let triangles = triangulate_monotone_polygon(&polygon);
+/// let triangles = triangulate_monotone_polygon(&polygon).unwrap();
 ///
 /// assert_eq!(triangles.len(), 4);
 /// ```
-pub fn triangulate_monotone_polygon(polygon: &MonotonePolygon) -> Vec<PointTriangle> {
-    if !polygon.finished() {
-        panic!("Cannot triangulate an unfinished polygon. Ensure the bottom is set before calling this function.");
-    }
+pub fn triangulate_monotone_polygon<T: Float>(
+    polygon: &MonotonePolygon<T>,
+) -> Result<Vec<PointTriangle<T>>, TriangulationError<T>> {
+    let bottom = polygon
+        .bottom
+        .ok_or(TriangulationError::InvalidSweepState {
+            vertex: polygon.top,
+            reason: "Monotone polygon has no bottom vertex",
+        })?;
     let mut result = Vec::new();
     let mut left_index = 0;
     let mut right_index = 0;
-    let mut stack: VecDeque<Point> = VecDeque::new(); // Using VecDeque for O(1) push_front
+    let mut stack: VecDeque<Point<T>> = VecDeque::new(); // Using VecDeque for O(1) push_front
     let mut points = Vec::with_capacity(polygon.left.len() + polygon.right.len() + 2);
 
     result.reserve(polygon.left.len() + polygon.right.len());
@@ -239,7 +249,7 @@ pub fn triangulate_monotone_polygon(polygon: &MonotonePolygon) -> Vec<PointTrian
         right_index += 1;
     }
 
-    points.push((polygon.bottom.unwrap(), Side::TopOrBottom));
+    points.push((bottom, Side::TopOrBottom));
 
     stack.push_back(points[0].0);
     stack.push_back(points[1].0);
@@ -254,5 +264,5 @@ pub fn triangulate_monotone_polygon(polygon: &MonotonePolygon) -> Vec<PointTrian
         side = s;
     }
 
-    result
+    Ok(result)
 }
@@ -0,0 +1,183 @@
+//! Offsetting (buffering) of polygons by a fixed distance.
+
+use crate::boolean::{polygon_boolean, BooleanOp};
+use crate::error::TriangulationError;
+use crate::fill_rule::{split_polygons_with_fill_rule, FillRule};
+use crate::path_triangulation::{right_normal, JoinStyle};
+use crate::point::{Coord, Float, Point, Vector};
+
+type Polygons<T> = Vec<Vec<Point<T>>>;
+
+/// Parameters of polygon offsetting.
+///
+/// # Fields
+/// * `join` - Shape of corners which move away from the polygon edges.
+/// * `limit` - Miter limit, the largest distance of a mitered corner from
+///   its original vertex in units of the offset distance, above which
+///   the corner is bevelled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffsetStyle {
+    pub join: JoinStyle,
+    pub limit: Coord,
+}
+
+impl OffsetStyle {
+    pub fn new(join: JoinStyle) -> Self {
+        OffsetStyle { join, limit: 3.0 }
+    }
+
+    pub fn with_limit(self, limit: Coord) -> Self {
+        OffsetStyle { limit, ..self }
+    }
+}
+
+impl Default for OffsetStyle {
+    fn default() -> Self {
+        OffsetStyle::new(JoinStyle::Miter)
+    }
+}
+
+/// Appends the vertices of the offset ring at corner `p`, between the edge
+/// with unit direction `prev` and the edge with unit direction `next`.
+fn add_corner<T: Float>(
+    ring: &mut Vec<Point<T>>,
+    p: Point<T>,
+    prev: Vector<T>,
+    next: Vector<T>,
+    distance: T,
+    cos_limit: T,
+    join: JoinStyle,
+) {
+    let prev_normal = right_normal(prev) * distance;
+    let next_normal = right_normal(next) * distance;
+    let cos_angle = prev.x * next.x + prev.y * next.y;
+    let sin_angle = prev.x * next.y - prev.y * next.x;
+
+    if sin_angle * distance < T::ZERO {
+        // offset edges overlap, the loop they form around `p` is removed by the fill rule
+        ring.push(p + prev_normal);
+        ring.push(p);
+        ring.push(p + next_normal);
+        return;
+    }
+    if sin_angle == T::ZERO && cos_angle > T::ZERO {
+        ring.push(p + prev_normal);
+        return;
+    }
+    match join {
+        JoinStyle::Miter if cos_angle >= cos_limit => {
+            let miter = (prev_normal + next_normal) / (T::ONE + cos_angle);
+            ring.push(p + miter);
+        }
+        JoinStyle::Miter | JoinStyle::Bevel => {
+            ring.push(p + prev_normal);
+            ring.push(p + next_normal);
+        }
+        JoinStyle::Round { segments } => {
+            let angle = sin_angle.atan2(cos_angle);
+            let count = (T::from_usize(segments) * angle.abs() / T::PI)
+                .ceil()
+                .to_f64() as usize;
+            let step = angle / T::from_usize(count.max(1));
+            ring.push(p + prev_normal);
+            for k in 1..count {
+                let (sin, cos) = (step * T::from_usize(k)).sin_cos();
+                ring.push(
+                    p + Vector::new(
+                        prev_normal.x * cos - prev_normal.y * sin,
+                        prev_normal.x * sin + prev_normal.y * cos,
+                    ),
+                );
+            }
+            ring.push(p + next_normal);
+        }
+    }
+}
+
+/// Offset of a ring to the right side of its edges, which may overlap itself.
+fn offset_ring<T: Float>(
+    ring: &[Point<T>],
+    distance: T,
+    cos_limit: T,
+    join: JoinStyle,
+) -> Vec<Point<T>> {
+    let directions: Vec<Vector<T>> = (0..ring.len())
+        .map(|i| {
+            let edge = ring[(i + 1) % ring.len()] - ring[i];
+            edge / (edge.x * edge.x + edge.y * edge.y).sqrt()
+        })
+        .collect();
+    let mut result = Vec::with_capacity(2 * ring.len());
+    for (i, &p) in ring.iter().enumerate() {
+        let prev = directions[(i + ring.len() - 1) % ring.len()];
+        add_corner(
+            &mut result,
+            p,
+            prev,
+            directions[i],
+            distance,
+            cos_limit,
+            join,
+        );
+    }
+    result
+}
+
+/// Grows polygons by `distance`, or shrinks them if `distance` is negative.
+///
+/// Polygons are interpreted with the even-odd rule, so holes are given as
+/// additional rings, like for face triangulation. Every ring is offset away from
+/// the filled area, with joins added at corners whose edges move apart,
+/// and self-overlaps of the offset rings are removed by splitting them at
+/// their intersections and keeping the area of positive winding number.
+/// Holes shrink while polygons grow, and parts of polygons narrower than
+/// twice the distance vanish while they shrink.
+///
+/// # Arguments
+/// * `polygons` - Rings of the polygons
+/// * `distance` - Offset distance, positive to grow and negative to shrink the polygons
+/// * `style` - Shape of the offset corners
+///
+/// # Returns
+/// Rings of the offset polygons, with the area on the left side of each ring,
+/// so outer rings are counterclockwise and holes are clockwise.
+///
+/// # Errors
+/// Returns [`TriangulationError::NonFiniteDistance`] if `distance` is NaN or infinite,
+/// and [`TriangulationError::UnresolvedIntersection`] if edges
+/// of the rings cannot be split into non-crossing edges.
+///
+/// # Example
+/// ```
+/// use triangulation::offset::{offset_polygons, OffsetStyle};
+/// use triangulation::Point;
+///
+/// let square = vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0), Point::new(0.0, 4.0)];
+/// let grown = offset_polygons(&[square.clone()], 1.0, &OffsetStyle::default()).unwrap();
+/// assert_eq!(grown.len(), 1);
+/// assert!(grown[0].contains(&Point::new(-1.0, -1.0)) && grown[0].contains(&Point::new(5.0, 5.0)));
+/// // shrinking by half of the width removes the square
+/// assert!(offset_polygons(&[square], -2.0, &OffsetStyle::default()).unwrap().is_empty());
+/// ```
+pub fn offset_polygons<T: Float>(
+    polygons: &[impl AsRef<[Point<T>]>],
+    distance: T,
+    style: &OffsetStyle,
+) -> Result<Polygons<T>, TriangulationError<T>> {
+    if !distance.is_finite() {
+        return Err(TriangulationError::NonFiniteDistance { distance });
+    }
+    // rings with the filled area on their left side, without self-intersections
+    let rings = polygon_boolean(polygons, &[] as &[Vec<Point<T>>], BooleanOp::Union)?;
+    if distance == T::ZERO {
+        return Ok(rings);
+    }
+    let limit = style.limit.max(1.0) as f64;
+    let cos_limit = T::from_f64(2.0 / (limit * limit) - 1.0);
+    let offset: Polygons<T> = rings
+        .iter()
+        .map(|ring| offset_ring(ring, distance, cos_limit, style.join))
+        .collect();
+    let (result, _) = split_polygons_with_fill_rule(&offset, FillRule::Positive)?;
+    Ok(result)
+}
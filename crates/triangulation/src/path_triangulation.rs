@@ -1,18 +1,136 @@
+use crate::batch::map_in_order;
 use crate::point;
+use crate::point::{Coord, Float};
 
+/// Number of triangles used to approximate a half circle
+/// by [`CapStyle::round`] and [`JoinStyle::round`].
+pub const DEFAULT_ROUND_SEGMENTS: usize = 8;
+
+/// Shape of the outer corner where two segments of a path meet,
+/// like SVG `stroke-linejoin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinStyle {
+    /// Sharp corner, replaced by bevel when the miter limit is exceeded.
+    #[default]
+    Miter,
+    /// Corner is cut off with a straight line.
+    Bevel,
+    /// Corner is rounded with an arc of diameter equal to the path width.
+    /// `segments` is the number of triangles used for a half circle,
+    /// smaller turns use proportionally fewer triangles.
+    Round { segments: usize },
+}
+
+impl JoinStyle {
+    /// Round join with [`DEFAULT_ROUND_SEGMENTS`] segments.
+    pub fn round() -> Self {
+        JoinStyle::Round {
+            segments: DEFAULT_ROUND_SEGMENTS,
+        }
+    }
+}
+
+/// Shape of the ends of an open path, like SVG `stroke-linecap`.
+///
+/// Caps are ignored for closed paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CapStyle {
+    /// Path ends exactly at its first and last point.
+    #[default]
+    Butt,
+    /// Path is extended by half of its width beyond its first and last point.
+    Square,
+    /// Path ends with a half circle of diameter equal to the path width,
+    /// approximated with `segments` triangles (at least 2).
+    Round { segments: usize },
+}
+
+impl CapStyle {
+    /// Round cap with [`DEFAULT_ROUND_SEGMENTS`] segments.
+    pub fn round() -> Self {
+        CapStyle::Round {
+            segments: DEFAULT_ROUND_SEGMENTS,
+        }
+    }
+}
+
+/// Parameters of edge triangulation of a single path.
+///
+/// # Fields
+/// * `closed` - If the path is closed (last point is connected with the first one).
+/// * `limit` - Miter limit which determines when to switch from a miter join to a bevel join.
+/// * `join` - Shape of corners of the path.
+/// * `cap` - Shape of both ends of an open path.
+/// * `attributes` - If the result should contain arc lengths and sides of vertices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathStyle {
+    pub closed: bool,
+    pub limit: point::Coord,
+    pub join: JoinStyle,
+    pub cap: CapStyle,
+    pub attributes: bool,
+}
+
+impl PathStyle {
+    pub fn new(closed: bool, limit: point::Coord, join: JoinStyle) -> Self {
+        PathStyle {
+            closed,
+            limit,
+            join,
+            cap: CapStyle::Butt,
+            attributes: false,
+        }
+    }
+
+    pub fn with_cap(self, cap: CapStyle) -> Self {
+        PathStyle { cap, ..self }
+    }
+
+    pub fn with_attributes(self, attributes: bool) -> Self {
+        PathStyle { attributes, ..self }
+    }
+}
+
+impl Default for PathStyle {
+    fn default() -> Self {
+        PathStyle::new(false, 3.0, JoinStyle::Miter)
+    }
+}
+
+/// Edge triangulation of paths.
+///
+/// Vertices of triangles are `centers + width * offsets`.
+/// `arc_lengths`, when present, contains for each vertex the distance
+/// along the path from its start to the vertex center.
+/// `sides`, when present, contains for each vertex `1.0` if it lies on the left
+/// side of the path (looking along the path direction), `-1.0` if on the right side,
+/// and `0.0` if it lies on the path itself, like centers of round caps.
 #[derive(Debug, Default)]
-pub struct PathTriangulation {
+pub struct PathTriangulation<T = Coord> {
     pub triangles: Vec<point::Triangle>,
-    pub centers: Vec<point::Point>,
-    pub offsets: Vec<point::Vector>,
+    pub centers: Vec<point::Point<T>>,
+    pub offsets: Vec<point::Vector<T>>,
+    pub arc_lengths: Option<Vec<T>>,
+    pub sides: Option<Vec<T>>,
 }
 
-impl PathTriangulation {
+impl<T: Float> PathTriangulation<T> {
     pub fn new() -> Self {
         PathTriangulation {
             triangles: Vec::new(),
             centers: Vec::new(),
             offsets: Vec::new(),
+            arc_lengths: None,
+            sides: None,
+        }
+    }
+
+    /// Triangulation without vertices, computing arc lengths and sides of added vertices.
+    fn with_attributes() -> Self {
+        PathTriangulation {
+            arc_lengths: Some(Vec::new()),
+            sides: Some(Vec::new()),
+            ..PathTriangulation::new()
         }
     }
 
@@ -22,95 +140,230 @@ impl PathTriangulation {
         self.offsets.reserve(size);
     }
 
+    /// Append other triangulation, shifting its triangle indices
+    /// to refer to the concatenated vertices.
+    ///
+    /// Arc lengths and sides are kept only if all non-empty parts have them.
+    pub fn extend(&mut self, other: PathTriangulation<T>) {
+        let shift = self.centers.len();
+        let other_empty = other.centers.is_empty();
+        self.arc_lengths = extend_attribute(
+            self.arc_lengths.take(),
+            other.arc_lengths,
+            shift,
+            other_empty,
+        );
+        self.sides = extend_attribute(self.sides.take(), other.sides, shift, other_empty);
+        self.centers.extend(other.centers);
+        self.offsets.extend(other.offsets);
+        self.triangles.extend(
+            other
+                .triangles
+                .into_iter()
+                .map(|triangle| triangle.shifted_by(shift)),
+        );
+    }
+
+    /// Set attributes of vertices added since the last call.
+    ///
+    /// Vertices get `arc_length`, and their sides are computed
+    /// from their offsets relative to the path direction `tangent`.
+    fn fill_attributes(&mut self, arc_length: T, tangent: point::Vector<T>) {
+        if let Some(arc_lengths) = &mut self.arc_lengths {
+            arc_lengths.resize(self.centers.len(), arc_length);
+        }
+        if let Some(sides) = &mut self.sides {
+            let start = sides.len();
+            sides.extend(
+                self.offsets[start..]
+                    .iter()
+                    .map(|&offset| side_of(tangent, offset)),
+            );
+        }
+    }
+
     pub fn fix_triangle_orientation(&mut self) {
         for triangle in &mut self.triangles {
             let p1 = self.centers[triangle.x] + self.offsets[triangle.x];
             let p2 = self.centers[triangle.y] + self.offsets[triangle.y];
             let p3 = self.centers[triangle.z] + self.offsets[triangle.z];
 
-            if (p2.x - p1.x) * (p3.y - p1.y) - (p2.y - p1.y) * (p3.x - p1.x) < 0.0 {
+            if (p2.x - p1.x) * (p3.y - p1.y) - (p2.y - p1.y) * (p3.x - p1.x) < T::ZERO {
                 std::mem::swap(&mut triangle.x, &mut triangle.z);
             }
         }
     }
 }
 
-fn add_triangles_for_join(
-    triangles: &mut PathTriangulation,
-    p1: point::Point,
-    p2: point::Point,
-    p3: point::Point,
-    prev_length: point::Coord,
-    cos_limit: point::Coord,
-    bevel: bool,
-) -> f32 {
+/// Concatenate per-vertex attribute of two triangulations,
+/// where the first one has `len` vertices.
+fn extend_attribute<T: Float>(
+    values: Option<Vec<T>>,
+    other_values: Option<Vec<T>>,
+    len: usize,
+    other_empty: bool,
+) -> Option<Vec<T>> {
+    match (values, other_values) {
+        (Some(mut values), Some(other_values)) => {
+            values.extend(other_values);
+            Some(values)
+        }
+        (None, other_values) if len == 0 => other_values,
+        (values, None) if other_empty => values,
+        _ => None,
+    }
+}
+
+/// Side of the path on which a vertex with `offset` lies, for path direction `tangent`.
+///
+/// Offsets (almost) parallel to the tangent, like the center or the tip
+/// of a round cap, lie on the path and have side `0.0`.
+fn side_of<T: Float>(tangent: point::Vector<T>, offset: point::Vector<T>) -> T {
+    let cross = tangent.x * offset.y - tangent.y * offset.x;
+    let length = (tangent.x * tangent.x + tangent.y * tangent.y).sqrt()
+        * (offset.x * offset.x + offset.y * offset.y).sqrt();
+    let tolerance = length * T::EPSILON * T::from_f64(4.0);
+    if cross > tolerance {
+        T::ONE
+    } else if cross < -tolerance {
+        -T::ONE
+    } else {
+        T::ZERO
+    }
+}
+
+/// Intersection of edges of two consecutive segments of a variable width path,
+/// relative to their common point `p2`.
+///
+/// `half_widths` are half of the path widths at `p1`, `p2` and `p3`,
+/// `side` is `1.0` for the right and `-1.0` for the left side of the path.
+fn variable_width_mitter<T: Float>(
+    p1: point::Point<T>,
+    p2: point::Point<T>,
+    p3: point::Point<T>,
+    half_widths: [T; 3],
+    side: T,
+) -> point::Vector<T> {
+    let [h1, h2, h3] = half_widths;
+    let prev_normal = right_normal(p2 - p1) * side;
+    let next_normal = right_normal(p3 - p2) * side;
+    // Edges pass through the ends of segments moved by the local half width
+    let prev_start = prev_normal * h2;
+    let prev_dir = (p2 - p1) + prev_normal * (h2 - h1);
+    let next_start = next_normal * h2;
+    let next_dir = (p3 - p2) + next_normal * (h3 - h2);
+    let denom = prev_dir.x * next_dir.y - prev_dir.y * next_dir.x;
+    let diff = next_start - prev_start;
+    let t = (diff.x * next_dir.y - diff.y * next_dir.x) / denom;
+    if !t.is_finite() {
+        // parallel edges, both segments end at the same point
+        return prev_start;
+    }
+    prev_start + prev_dir * t
+}
+
+/// Unit vector perpendicular to `vector`, pointing to the right of it.
+pub(crate) fn right_normal<T: Float>(vector: point::Vector<T>) -> point::Vector<T> {
+    let length = (vector.x * vector.x + vector.y * vector.y).sqrt();
+    point::Vector::new(vector.y / length, -vector.x / length)
+}
+
+/// Add vertices and triangles for the join at `p2`.
+///
+/// Without `widths` offsets are computed for a path of unit width, otherwise
+/// `widths` are path widths at `p1`, `p2` and `p3` and offsets are scaled by them.
+#[allow(clippy::too_many_arguments)]
+fn add_triangles_for_join<T: Float>(
+    triangles: &mut PathTriangulation<T>,
+    p1: point::Point<T>,
+    p2: point::Point<T>,
+    p3: point::Point<T>,
+    prev_length: T,
+    cos_limit: T,
+    join: JoinStyle,
+    widths: Option<[T; 3]>,
+) -> T {
     let idx = triangles.offsets.len();
-    let mitter: point::Vector;
+    let mitter: point::Vector<T>;
     let length = point::vector_length(p2, p3);
     let p1_p2_diff_norm = (p2 - p1) / prev_length;
     let p2_p3_diff_norm = (p3 - p2) / length;
+    let half_width = widths.map_or(T::HALF, |widths| widths[1] / T::TWO);
+    let prev_normal = point::Vector::new(p1_p2_diff_norm.y, -p1_p2_diff_norm.x) * half_width;
+    let next_normal = point::Vector::new(p2_p3_diff_norm.y, -p2_p3_diff_norm.x) * half_width;
 
     let cos_angle = p1_p2_diff_norm.x * p2_p3_diff_norm.x + p1_p2_diff_norm.y * p2_p3_diff_norm.y;
     let sin_angle = p1_p2_diff_norm.x * p2_p3_diff_norm.y - p1_p2_diff_norm.y * p2_p3_diff_norm.x;
+    let bevel = match join {
+        JoinStyle::Miter => cos_angle < cos_limit,
+        JoinStyle::Bevel | JoinStyle::Round { .. } => true,
+    };
 
     triangles.centers.push(p2);
     triangles.centers.push(p2);
 
     // Check sin_angle to compute mitter vector
-    if sin_angle == 0.0 {
-        mitter = point::Vector::new(p1_p2_diff_norm.y / 2.0, -p1_p2_diff_norm.x / 2.0);
+    if sin_angle == T::ZERO {
+        mitter = point::Vector::new(p1_p2_diff_norm.y / T::TWO, -p1_p2_diff_norm.x / T::TWO);
     } else {
-        let mut scale_factor = 1.0 / sin_angle;
-        if bevel || cos_angle < cos_limit {
+        let mut scale_factor = T::ONE / sin_angle;
+        if bevel {
             // Compute bevel join and handle limits for inner vector length
             let (sign, mag) = sign_abs(scale_factor);
-            scale_factor = sign * 0.5 * mag.min(prev_length.min(length));
+            scale_factor = sign * T::HALF * mag.min(prev_length.min(length));
         }
-        mitter = (p1_p2_diff_norm - p2_p3_diff_norm) * scale_factor * 0.5;
+        mitter = (p1_p2_diff_norm - p2_p3_diff_norm) * scale_factor * T::HALF;
     }
+    // Offsets of the right and the left edge of the path at the join
+    let (right_mitter, left_mitter) = match widths {
+        None => (mitter, -mitter),
+        Some([w1, w2, w3]) => {
+            let half_widths = [w1 / T::TWO, w2 / T::TWO, w3 / T::TWO];
+            let right = variable_width_mitter(p1, p2, p3, half_widths, T::ONE);
+            let left = variable_width_mitter(p1, p2, p3, half_widths, -T::ONE);
+            if bevel && sin_angle != T::ZERO {
+                // Same limit of inner vector length as for unit width, in units of width
+                let limit = (prev_length.min(length) * sin_angle.abs() / w2).min(T::ONE) * T::HALF;
+                (right * limit, left * limit)
+            } else {
+                (right, left)
+            }
+        }
+    };
 
-    if bevel || cos_angle < cos_limit {
+    if bevel {
         triangles.centers.push(p2);
         triangles
             .triangles
             .push(point::Triangle::new(idx, idx + 1, idx + 2));
 
-        if sin_angle < 0.0 {
-            triangles.offsets.push(mitter);
-            triangles.offsets.push(point::Vector::new(
-                -p1_p2_diff_norm.y * 0.5,
-                p1_p2_diff_norm.x * 0.5,
-            ));
-            triangles.offsets.push(point::Vector::new(
-                -p2_p3_diff_norm.y * 0.5,
-                p2_p3_diff_norm.x * 0.5,
-            ));
-            triangles
-                .triangles
-                .push(point::Triangle::new(idx, idx + 2, idx + 3));
-            triangles
-                .triangles
-                .push(point::Triangle::new(idx + 2, idx + 3, idx + 4));
+        // Vertex on the inner side of the corner, vertex connected with the next join
+        // on the left side of the path, and vertex starting the arc of round join.
+        let (inner, left, outer) = if sin_angle < T::ZERO {
+            triangles.offsets.push(right_mitter);
+            triangles.offsets.push(-prev_normal);
+            triangles.offsets.push(-next_normal);
+            (idx, idx + 2, idx + 1)
         } else {
-            triangles.offsets.push(point::Vector::new(
-                p1_p2_diff_norm.y * 0.5,
-                -p1_p2_diff_norm.x * 0.5,
-            ));
-            triangles.offsets.push(-mitter);
-            triangles.offsets.push(point::Vector::new(
-                p2_p3_diff_norm.y * 0.5,
-                -p2_p3_diff_norm.x * 0.5,
-            ));
-            triangles
-                .triangles
-                .push(point::Triangle::new(idx + 1, idx + 2, idx + 3));
-            triangles
-                .triangles
-                .push(point::Triangle::new(idx + 1, idx + 3, idx + 4));
+            triangles.offsets.push(prev_normal);
+            triangles.offsets.push(left_mitter);
+            triangles.offsets.push(next_normal);
+            (idx + 1, idx + 1, idx)
+        };
+        if let JoinStyle::Round { segments } = join {
+            add_round_join_arc(triangles, outer, idx + 2, sin_angle, cos_angle, segments);
         }
+        // first vertex of the next join
+        let next = triangles.offsets.len();
+        triangles
+            .triangles
+            .push(point::Triangle::new(inner, idx + 2, next));
+        triangles
+            .triangles
+            .push(point::Triangle::new(left, next, next + 1));
     } else {
-        triangles.offsets.push(mitter);
-        triangles.offsets.push(-mitter);
+        triangles.offsets.push(right_mitter);
+        triangles.offsets.push(left_mitter);
         triangles
             .triangles
             .push(point::Triangle::new(idx, idx + 1, idx + 2));
@@ -122,39 +375,237 @@ fn add_triangles_for_join(
     length
 }
 
+/// Fill the circular segment between bevel vertices `start` and `end`
+/// with a fan of triangles, with arc vertices appended to the triangulation.
+fn add_round_join_arc<T: Float>(
+    triangles: &mut PathTriangulation<T>,
+    start: point::Index,
+    end: point::Index,
+    sin_angle: T,
+    cos_angle: T,
+    segments: usize,
+) {
+    let angle = sin_angle.abs().atan2(cos_angle);
+    let count = (T::from_usize(segments) * angle / T::PI).ceil().to_f64() as usize;
+    let center = triangles.centers[start];
+    let side = triangles.offsets[start];
+    let step = angle.copysign(sin_angle) / T::from_usize(count.max(1));
+    let mut prev_idx = None;
+    for k in 1..count {
+        let (sin, cos) = (step * T::from_usize(k)).sin_cos();
+        triangles.centers.push(center);
+        triangles.offsets.push(point::Vector::new(
+            side.x * cos - side.y * sin,
+            side.x * sin + side.y * cos,
+        ));
+        let arc_idx = triangles.offsets.len() - 1;
+        if let Some(prev_idx) = prev_idx {
+            triangles
+                .triangles
+                .push(point::Triangle::new(start, prev_idx, arc_idx));
+        }
+        prev_idx = Some(arc_idx);
+    }
+    if let Some(prev_idx) = prev_idx {
+        triangles
+            .triangles
+            .push(point::Triangle::new(start, prev_idx, end));
+    }
+}
+
+/// Replace butt cap formed by vertices `idx` and `idx + 1` with the requested cap.
+///
+/// `direction` is the unit vector pointing outside the path at its end
+/// and `half_width` is half of the path width at the end (0.5 for unit width).
+fn add_cap<T: Float>(
+    triangles: &mut PathTriangulation<T>,
+    idx: point::Index,
+    direction: point::Vector<T>,
+    half_width: T,
+    cap: CapStyle,
+) {
+    match cap {
+        CapStyle::Butt => {}
+        CapStyle::Square => {
+            triangles.offsets[idx] = triangles.offsets[idx] + direction * half_width;
+            triangles.offsets[idx + 1] = triangles.offsets[idx + 1] + direction * half_width;
+        }
+        CapStyle::Round { segments } => {
+            let segments = segments.max(2);
+            let center_idx = triangles.centers.len();
+            let center = triangles.centers[idx];
+            let side = triangles.offsets[idx];
+            triangles.centers.push(center);
+            triangles.offsets.push(point::Vector::new(T::ZERO, T::ZERO));
+            let mut prev_idx = idx;
+            for k in 1..segments {
+                let angle = T::PI * T::from_usize(k) / T::from_usize(segments);
+                triangles.centers.push(center);
+                triangles
+                    .offsets
+                    .push(side * angle.cos() + direction * (half_width * angle.sin()));
+                let arc_idx = triangles.centers.len() - 1;
+                triangles
+                    .triangles
+                    .push(point::Triangle::new(center_idx, prev_idx, arc_idx));
+                prev_idx = arc_idx;
+            }
+            triangles
+                .triangles
+                .push(point::Triangle::new(center_idx, prev_idx, idx + 1));
+        }
+    }
+}
+
 // Helper function to calculate the sign and absolute value of a number
-fn sign_abs(value: f32) -> (f32, f32) {
-    if value < 0.0 {
-        (-1.0, value.abs())
+fn sign_abs<T: Float>(value: T) -> (T, T) {
+    if value < T::ZERO {
+        (-T::ONE, value.abs())
     } else {
-        (1.0, value.abs())
+        (T::ONE, value.abs())
     }
 }
 
-pub fn triangulate_path_edge(
-    path: &[point::Point],
+pub fn triangulate_path_edge<T: Float>(
+    path: &[point::Point<T>],
     closed: bool,
     limit: f32,
-    bevel: bool,
-) -> PathTriangulation {
+    join: JoinStyle,
+) -> PathTriangulation<T> {
+    triangulate_path_edge_styled(path, &PathStyle::new(closed, limit, join))
+}
+
+/// Generate edge triangulation of a single path using all settings from [`PathStyle`].
+///
+/// # Example
+/// ```
+/// use triangulation::Point;
+/// use triangulation::path_triangulation::{
+///     triangulate_path_edge, triangulate_path_edge_styled, CapStyle, JoinStyle, PathStyle,
+/// };
+///
+/// let path = vec![Point::new(0.0, 0.0), Point::new(0.0, 10.0)];
+/// let butt = triangulate_path_edge(&path, false, 3.0, JoinStyle::Miter);
+/// let style = PathStyle::new(false, 3.0, JoinStyle::Miter).with_cap(CapStyle::Round { segments: 4 });
+/// let round = triangulate_path_edge_styled(&path, &style);
+/// // each round cap adds a center and 3 arc vertices, and 4 triangles
+/// assert_eq!(round.centers.len(), butt.centers.len() + 2 * 4);
+/// assert_eq!(round.triangles.len(), butt.triangles.len() + 2 * 4);
+/// ```
+pub fn triangulate_path_edge_styled<T: Float>(
+    path: &[point::Point<T>],
+    style: &PathStyle,
+) -> PathTriangulation<T> {
+    triangulate_path_edge_impl(path, None, style, style.attributes.then_some(T::ZERO))
+}
+
+/// Generate edge triangulation of a path with a separate width at each vertex.
+///
+/// In contrast to [`triangulate_path_edge_styled`], returned offsets are already
+/// scaled by the path width, so vertices of the triangulation are `centers + offsets`.
+/// Edges of the path segments connect the path outlines at their ends,
+/// and joins are computed at intersections of these edges.
+///
+/// # Panics
+/// If `path` and `widths` have different lengths.
+///
+/// # Example
+/// ```
+/// use triangulation::Point;
+/// use triangulation::path_triangulation::{triangulate_path_edge_with_widths, PathStyle};
+///
+/// let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+/// let result = triangulate_path_edge_with_widths(&path, &[2.0, 0.5], &PathStyle::default());
+/// assert_eq!(result.offsets[0].y, -1.0);
+/// assert_eq!(result.offsets[2].y, -0.25);
+/// ```
+pub fn triangulate_path_edge_with_widths<T: Float>(
+    path: &[point::Point<T>],
+    widths: &[T],
+    style: &PathStyle,
+) -> PathTriangulation<T> {
+    assert_eq!(
+        path.len(),
+        widths.len(),
+        "Number of path points and widths must be equal"
+    );
+    triangulate_path_edge_impl(
+        path,
+        Some(widths),
+        style,
+        style.attributes.then_some(T::ZERO),
+    )
+}
+
+/// Edge triangulation of a path with optional per-vertex widths.
+///
+/// If `arc_length_start` is given, the result contains side and arc length of each vertex,
+/// with arc length starting from this value at the first point of the path.
+fn triangulate_path_edge_impl<T: Float>(
+    path: &[point::Point<T>],
+    widths: Option<&[T]>,
+    style: &PathStyle,
+    arc_length_start: Option<T>,
+) -> PathTriangulation<T> {
+    let PathStyle {
+        closed,
+        limit,
+        join,
+        cap,
+        ..
+    } = *style;
+    if path.is_empty() {
+        return PathTriangulation::new();
+    }
     if path.len() < 2 {
         return PathTriangulation {
             triangles: vec![point::Triangle::new(0, 1, 3), point::Triangle::new(1, 3, 2)],
             centers: vec![path[0], path[0], path[0], path[0]],
             offsets: vec![
-                point::Vector::new(0.0, 0.0),
-                point::Vector::new(0.0, 0.0),
-                point::Vector::new(0.0, 0.0),
-                point::Vector::new(0.0, 0.0),
+                point::Vector::new(T::ZERO, T::ZERO),
+                point::Vector::new(T::ZERO, T::ZERO),
+                point::Vector::new(T::ZERO, T::ZERO),
+                point::Vector::new(T::ZERO, T::ZERO),
             ],
+            arc_lengths: arc_length_start.map(|start| vec![start; 4]),
+            sides: arc_length_start.map(|_| vec![T::ZERO; 4]),
         };
     }
 
+    let half_width = |i: usize| widths.map_or(T::HALF, |widths| widths[i] / T::TWO);
+    let join_widths =
+        |i1: usize, i2: usize, i3: usize| widths.map(|widths| [widths[i1], widths[i2], widths[i3]]);
+    let last = path.len() - 1;
     let mut result = PathTriangulation::new();
     result.reserve(path.len() * 3);
-    let cos_limit = 1.0 / (limit * limit / 2.0) - 1.0;
+    // Arc length at each path point, used only when requested
+    let mut point_arc_lengths = Vec::new();
+    if let Some(start) = arc_length_start {
+        result.arc_lengths = Some(Vec::with_capacity(path.len() * 3));
+        result.sides = Some(Vec::with_capacity(path.len() * 3));
+        point_arc_lengths.reserve(path.len());
+        point_arc_lengths.push(start);
+        for i in 1..path.len() {
+            point_arc_lengths
+                .push(point_arc_lengths[i - 1] + point::vector_length(path[i - 1], path[i]));
+        }
+    }
+    let arc_length_at = |i: usize| point_arc_lengths.get(i).copied().unwrap_or(T::ZERO);
+    // Direction of the path at the join of segments `i1`-`i2` and `i2`-`i3`
+    let join_tangent = |i1: usize, i2: usize, i3: usize| {
+        let prev = (path[i2] - path[i1]) / point::vector_length(path[i1], path[i2]);
+        let next = (path[i3] - path[i2]) / point::vector_length(path[i2], path[i3]);
+        let tangent = prev + next;
+        if tangent.x.abs() + tangent.y.abs() > T::EPSILON {
+            tangent
+        } else {
+            // path turns back, offsets are perpendicular to the previous segment
+            prev
+        }
+    };
+    let cos_limit = T::from_f64((1.0 / (limit * limit / 2.0) - 1.0) as f64);
     let mut prev_length = if closed {
-        point::vector_length(path[0], path[path.len() - 1])
+        point::vector_length(path[0], path[last])
     } else {
         point::vector_length(path[0], path[1])
     };
@@ -162,26 +613,30 @@ pub fn triangulate_path_edge(
     if closed {
         prev_length = add_triangles_for_join(
             &mut result,
-            path[path.len() - 1],
+            path[last],
             path[0],
             path[1],
             prev_length,
             cos_limit,
-            bevel,
+            join,
+            join_widths(last, 0, 1),
         );
+        result.fill_attributes(arc_length_at(0), join_tangent(last, 0, 1));
     } else {
         let norm_diff = (path[1] - path[0]) / prev_length;
         result.centers.push(path[0]);
         result.centers.push(path[0]);
-        result
-            .offsets
-            .push(point::Vector::new(norm_diff.y * 0.5, -norm_diff.x * 0.5));
+        result.offsets.push(point::Vector::new(
+            norm_diff.y * half_width(0),
+            -norm_diff.x * half_width(0),
+        ));
         result.offsets.push(-*result.offsets.last().unwrap());
         result.triangles.push(point::Triangle::new(0, 1, 2));
         result.triangles.push(point::Triangle::new(1, 2, 3));
+        result.fill_attributes(arc_length_at(0), norm_diff);
     }
 
-    for i in 1..path.len() - 1 {
+    for i in 1..last {
         prev_length = add_triangles_for_join(
             &mut result,
             path[i - 1],
@@ -189,61 +644,341 @@ pub fn triangulate_path_edge(
             path[i + 1],
             prev_length,
             cos_limit,
-            bevel,
+            join,
+            join_widths(i - 1, i, i + 1),
         );
+        result.fill_attributes(arc_length_at(i), join_tangent(i - 1, i, i + 1));
     }
 
     if closed {
         add_triangles_for_join(
             &mut result,
-            path[path.len() - 2],
-            path[path.len() - 1],
+            path[last - 1],
+            path[last],
             path[0],
             prev_length,
             cos_limit,
-            bevel,
+            join,
+            join_widths(last - 1, last, 0),
         );
+        result.fill_attributes(arc_length_at(last), join_tangent(last - 1, last, 0));
         result.centers.push(result.centers[0]);
         result.centers.push(result.centers[0]);
         result.offsets.push(result.offsets[0]);
         result.offsets.push(result.offsets[1]);
+        // Closing vertices are at the end of the path
+        result.fill_attributes(
+            arc_length_at(last) + point::vector_length(path[last], path[0]),
+            join_tangent(last, 0, 1),
+        );
     } else {
-        let norm_diff = (path[path.len() - 1] - path[path.len() - 2]) / prev_length;
-        result.centers.push(path[path.len() - 1]);
-        result.centers.push(path[path.len() - 1]);
-        result
-            .offsets
-            .push(point::Vector::new(norm_diff.y * 0.5, -norm_diff.x * 0.5));
+        let norm_diff = (path[last] - path[last - 1]) / prev_length;
+        result.centers.push(path[last]);
+        result.centers.push(path[last]);
+        result.offsets.push(point::Vector::new(
+            norm_diff.y * half_width(last),
+            -norm_diff.x * half_width(last),
+        ));
         result.offsets.push(-*result.offsets.last().unwrap());
+        let end_idx = result.centers.len() - 2;
+        add_cap(&mut result, end_idx, norm_diff, half_width(last), cap);
+        result.fill_attributes(arc_length_at(last), norm_diff);
+        // Added after the whole strip, as strip triangles refer to following vertices
+        let start_diff = (path[1] - path[0]) / point::vector_length(path[0], path[1]);
+        add_cap(&mut result, 0, -start_diff, half_width(0), cap);
+        result.fill_attributes(arc_length_at(0), start_diff);
     }
 
     result.fix_triangle_orientation();
     result
 }
 
+/// Dash pattern of a path, like SVG `stroke-dasharray` and `stroke-dashoffset`.
+///
+/// # Fields
+/// * `lengths` - Alternating lengths of dashes and gaps along the path, starting with a dash.
+///   A pattern with an odd number of lengths is repeated twice, negative lengths are treated as 0.
+///   Dashes of zero length are drawn as dots when the path has square or round caps.
+/// * `phase` - Distance into the pattern at which the path starts.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DashPattern<T = Coord> {
+    pub lengths: Vec<T>,
+    pub phase: T,
+}
+
+impl<T: Float> DashPattern<T> {
+    pub fn new(lengths: Vec<T>, phase: T) -> Self {
+        DashPattern { lengths, phase }
+    }
+
+    /// Intervals of arc length covered by dashes of a path of `total` length.
+    ///
+    /// A pattern without positive lengths covers the whole path.
+    fn dash_intervals(&self, total: T) -> Vec<(T, T)> {
+        let mut lengths: Vec<T> = self
+            .lengths
+            .iter()
+            .map(|length| length.max(T::ZERO))
+            .collect();
+        if lengths.len() % 2 == 1 {
+            lengths.extend_from_within(..);
+        }
+        let period: T = lengths.iter().sum();
+        // Period too short to advance along the path is treated as solid line
+        let min_period = total * T::EPSILON * T::TWO * T::from_usize(lengths.len());
+        if period.is_nan() || period <= min_period || period.is_infinite() {
+            return vec![(T::ZERO, total)];
+        }
+
+        // Find the pattern entry at the start of the path
+        let mut phase = self.phase.rem_euclid(period);
+        let mut i = 0;
+        while phase > T::ZERO && phase >= lengths[i] {
+            phase -= lengths[i];
+            i = (i + 1) % lengths.len();
+        }
+        let mut intervals = Vec::new();
+        let mut start = -phase;
+        while start <= total {
+            let end = start + lengths[i];
+            if i % 2 == 0 {
+                intervals.push((start.max(T::ZERO), end.min(total)));
+            }
+            start = end;
+            i = (i + 1) % lengths.len();
+        }
+        intervals
+    }
+}
+
+/// Path with cumulative arc length of its points, skipping repeated points.
+struct MeasuredPath<T> {
+    points: Vec<point::Point<T>>,
+    arc_lengths: Vec<T>,
+}
+
+impl<T: Float> MeasuredPath<T> {
+    fn new(path: &[point::Point<T>], closed: bool) -> Self {
+        let mut points: Vec<point::Point<T>> = Vec::with_capacity(path.len() + 1);
+        let mut arc_lengths = Vec::with_capacity(path.len() + 1);
+        let closing_point = if closed { path.first() } else { None };
+        for &point in path.iter().chain(closing_point) {
+            match points.last() {
+                None => arc_lengths.push(T::ZERO),
+                Some(&last) if last == point => continue,
+                Some(&last) => arc_lengths
+                    .push(*arc_lengths.last().unwrap() + point::vector_length(last, point)),
+            }
+            points.push(point);
+        }
+        MeasuredPath {
+            points,
+            arc_lengths,
+        }
+    }
+
+    fn total(&self) -> T {
+        self.arc_lengths.last().copied().unwrap_or(T::ZERO)
+    }
+
+    /// Index of the segment containing point at the given arc length.
+    fn segment_at(&self, arc_length: T) -> point::Index {
+        let idx = self
+            .arc_lengths
+            .partition_point(|&length| length <= arc_length);
+        idx.clamp(1, self.points.len() - 1) - 1
+    }
+
+    /// Point at the given arc length and the unit direction of the path there.
+    fn point_at(&self, arc_length: T) -> (point::Point<T>, point::Vector<T>) {
+        let segment = self.segment_at(arc_length);
+        let (start, end) = (self.points[segment], self.points[segment + 1]);
+        let length = self.arc_lengths[segment + 1] - self.arc_lengths[segment];
+        let direction = (end - start) / length;
+        let t = (arc_length - self.arc_lengths[segment]).clamp(T::ZERO, length);
+        (start + direction * t, direction)
+    }
+
+    /// Part of the path between the given arc lengths.
+    fn sub_path(&self, start: T, end: T) -> Vec<point::Point<T>> {
+        let mut result = vec![self.point_at(start).0];
+        for (&point, &arc_length) in self.points.iter().zip(self.arc_lengths.iter()) {
+            if start < arc_length && arc_length < end && result.last() != Some(&point) {
+                result.push(point);
+            }
+        }
+        let end_point = self.point_at(end).0;
+        if result.last() != Some(&end_point) {
+            result.push(end_point);
+        }
+        result
+    }
+}
+
+/// Triangulation of a zero length dash, drawn only by its caps.
+fn triangulate_dot<T: Float>(
+    center: point::Point<T>,
+    direction: point::Vector<T>,
+    cap: CapStyle,
+    arc_length: T,
+) -> PathTriangulation<T> {
+    let mut result = PathTriangulation::with_attributes();
+    if cap == CapStyle::Butt {
+        return result;
+    }
+    let side = point::Vector::new(direction.y * T::HALF, -direction.x * T::HALF);
+    result.centers = vec![center; 4];
+    result.offsets = vec![side, -side, side, -side];
+    result.triangles = vec![point::Triangle::new(0, 1, 2), point::Triangle::new(1, 2, 3)];
+    add_cap(&mut result, 2, direction, T::HALF, cap);
+    add_cap(&mut result, 0, -direction, T::HALF, cap);
+    result.fill_attributes(arc_length, direction);
+    result.fix_triangle_orientation();
+    result
+}
+
+/// Generate edge triangulation of a dashed path.
+///
+/// The path is split along its arc length according to `dash` and each dash
+/// is triangulated as an open path with caps and joins from `style`.
+/// For closed paths, dashes passing through the first point are merged.
+/// The result always contains sides of vertices and their arc lengths measured along the whole path,
+/// so the pattern can also be applied or animated in a shader. Arc lengths of the merged
+/// dash of a closed path continue past the path length.
+///
+/// # Example
+/// ```
+/// use triangulation::Point;
+/// use triangulation::path_triangulation::{triangulate_path_edge_dashed, DashPattern, PathStyle};
+///
+/// let path = vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+/// let dash = DashPattern::new(vec![2.0, 1.0], 0.0);
+/// let result = triangulate_path_edge_dashed(&path, &PathStyle::default(), &dash);
+/// // dashes start at 0, 3, 6 and 9, each is a quad of two triangles
+/// assert_eq!(result.triangles.len(), 4 * 2);
+/// assert_eq!(result.arc_lengths.unwrap()[..4], [0.0, 0.0, 2.0, 2.0]);
+/// ```
+pub fn triangulate_path_edge_dashed<T: Float>(
+    path: &[point::Point<T>],
+    style: &PathStyle,
+    dash: &DashPattern<T>,
+) -> PathTriangulation<T> {
+    let measured = MeasuredPath::new(path, style.closed);
+    if measured.points.len() < 2 {
+        return triangulate_path_edge_impl(path, None, style, Some(T::ZERO));
+    }
+    let total = measured.total();
+    let mut intervals = dash.dash_intervals(total);
+    if intervals.len() == 1 && intervals[0] == (T::ZERO, total) {
+        return triangulate_path_edge_impl(path, None, style, Some(T::ZERO));
+    }
+
+    let open_style = PathStyle {
+        closed: false,
+        ..*style
+    };
+    let mut result = PathTriangulation::with_attributes();
+    let mut merged_start = None;
+    if style.closed && intervals.len() > 1 {
+        let (first, last) = (intervals[0], intervals[intervals.len() - 1]);
+        if first.0 == T::ZERO && last.1 == total && first.1 > T::ZERO && last.0 < total {
+            // Dash passing through the first point of a closed path
+            let mut sub_path = measured.sub_path(last.0, total);
+            sub_path.extend(measured.sub_path(T::ZERO, first.1).into_iter().skip(1));
+            merged_start = Some((sub_path, last.0));
+            intervals.pop();
+            intervals.remove(0);
+        }
+    }
+    let parts: Vec<_> = intervals
+        .into_iter()
+        .map(|(start, end)| (measured.sub_path(start, end), start))
+        .chain(merged_start)
+        .collect();
+    for (sub_path, start) in parts {
+        if sub_path.len() < 2 {
+            let (center, direction) = measured.point_at(start);
+            result.extend(triangulate_dot(center, direction, style.cap, start));
+        } else {
+            result.extend(triangulate_path_edge_impl(
+                &sub_path,
+                None,
+                &open_style,
+                Some(start),
+            ));
+        }
+    }
+    result
+}
+
 /// For list of polygon edges (boundaries) generate its triangulation.
-/// This function is to have consistent numeration of triangles.  
-pub fn triangulate_paths_edge(
-    paths: &[Vec<point::Point>],
+/// This function is to have consistent numeration of triangles.
+/// With the `parallel` feature enabled paths are triangulated in parallel.
+pub fn triangulate_paths_edge<T: Float>(
+    paths: &[impl AsRef<[point::Point<T>]> + Sync],
     closed: bool,
     limit: f32,
-    bevel: bool,
-) -> PathTriangulation {
+    join: JoinStyle,
+) -> PathTriangulation<T> {
     let mut result = PathTriangulation::new();
-    let mut shift = 0;
-    for path in paths.iter() {
-        let sub_res = triangulate_path_edge(path, closed, limit, bevel);
-        let centers_len = sub_res.centers.len();
-        result.centers.extend(sub_res.centers);
-        result.offsets.extend(sub_res.offsets);
-        result.triangles.extend(
-            sub_res
-                .triangles
-                .into_iter()
-                .map(|triangle| triangle.shifted_by(shift)),
-        );
-        shift += centers_len;
+    for sub_res in map_in_order(paths, |path| {
+        triangulate_path_edge(path.as_ref(), closed, limit, join)
+    }) {
+        result.extend(sub_res);
     }
 
     result
 }
+
+/// For list of paths, each with its own [`PathStyle`], generate concatenated triangulation.
+///
+/// # Arguments
+/// * `paths` - List of paths, each as a list of points.
+/// * `styles` - Style of each path, must have the same length as `paths`.
+///
+/// # Returns
+/// A tuple containing:
+/// * `PathTriangulation` - Concatenated triangulation of all paths.
+/// * `Vec<Index>` - Index of the first vertex of each path in the triangulation,
+///   with one additional entry equal to the total number of vertices.
+///
+/// # Panics
+/// If `paths` and `styles` have different lengths.
+///
+/// # Example
+/// ```
+/// use triangulation::Point;
+/// use triangulation::path_triangulation::{triangulate_paths_edge_styled, JoinStyle, PathStyle};
+///
+/// let path = vec![Point::new(0.0, 0.0), Point::new(0.0, 10.0), Point::new(10.0, 10.0)];
+/// let styles = [
+///     PathStyle::new(false, 3.0, JoinStyle::Miter),
+///     PathStyle::new(true, 3.0, JoinStyle::Bevel),
+/// ];
+/// let (result, vertex_offsets) =
+///     triangulate_paths_edge_styled(&[path.clone(), path], &styles);
+/// assert_eq!(vertex_offsets.len(), 3);
+/// assert_eq!(*vertex_offsets.last().unwrap(), result.centers.len());
+/// ```
+pub fn triangulate_paths_edge_styled<T: Float>(
+    paths: &[impl AsRef<[point::Point<T>]> + Sync],
+    styles: &[PathStyle],
+) -> (PathTriangulation<T>, Vec<point::Index>) {
+    assert_eq!(
+        paths.len(),
+        styles.len(),
+        "Number of paths and styles must be equal"
+    );
+    let paths_with_styles: Vec<_> = paths.iter().zip(styles.iter()).collect();
+    let mut result = PathTriangulation::new();
+    let mut vertex_offsets = Vec::with_capacity(paths.len() + 1);
+    vertex_offsets.push(0);
+    for sub_res in map_in_order(&paths_with_styles, |(path, style)| {
+        triangulate_path_edge_styled(path.as_ref(), style)
+    }) {
+        result.extend(sub_res);
+        vertex_offsets.push(result.centers.len());
+    }
+
+    (result, vertex_offsets)
+}
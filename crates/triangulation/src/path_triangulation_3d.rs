@@ -0,0 +1,401 @@
+//! Triangulation of paths in 3D, as camera-facing ribbons or as tubes.
+
+use std::f64::consts::TAU;
+
+use crate::plane::{cross, dot, normalized, to_f64, Vector};
+use crate::point::{Coord, Float, Triangle};
+
+/// Number of vertices around a tube used by [`TubeStyle::default`].
+pub const DEFAULT_RADIAL_SEGMENTS: usize = 8;
+
+/// Parameters of tube triangulation of a single 3D path.
+///
+/// # Fields
+/// * `closed` - If the path is closed (last point is connected with the first one).
+/// * `segments` - Number of vertices around the tube (at least 3).
+/// * `limit` - Miter limit which determines when to switch from a mitered joint,
+///   where segments meet in a single ring of vertices, to a bevelled one.
+/// * `caps` - If ends of an open tube are closed with flat disks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TubeStyle {
+    pub closed: bool,
+    pub segments: usize,
+    pub limit: Coord,
+    pub caps: bool,
+}
+
+impl TubeStyle {
+    pub fn new(closed: bool, segments: usize) -> Self {
+        TubeStyle {
+            closed,
+            segments,
+            limit: 3.0,
+            caps: false,
+        }
+    }
+
+    pub fn with_limit(self, limit: Coord) -> Self {
+        TubeStyle { limit, ..self }
+    }
+
+    pub fn with_caps(self, caps: bool) -> Self {
+        TubeStyle { caps, ..self }
+    }
+}
+
+impl Default for TubeStyle {
+    fn default() -> Self {
+        TubeStyle::new(false, DEFAULT_RADIAL_SEGMENTS)
+    }
+}
+
+/// Ribbon triangulation of a 3D path, with offsets of vertices left to the renderer.
+///
+/// Each path point has two vertices, `2 * i` with side `-1.0` and `2 * i + 1` with
+/// side `1.0`. A camera-facing ribbon of a given width has vertices
+/// `centers + sides * width / 2 * normalize(cross(tangents, view_direction))`,
+/// which is usually computed in a vertex shader. Ribbons with a fixed orientation
+/// can use `centers + sides * width / 2 * normals` instead.
+///
+/// `tangents` are unit directions of the path, at corners the average of directions
+/// of both segments. `normals` are unit vectors perpendicular to `tangents`, which
+/// follow the path without twisting.
+#[derive(Debug, Default)]
+pub struct RibbonTriangulation<T = Coord> {
+    pub triangles: Vec<Triangle>,
+    pub centers: Vec<[T; 3]>,
+    pub tangents: Vec<[T; 3]>,
+    pub normals: Vec<[T; 3]>,
+    pub sides: Vec<T>,
+}
+
+/// Tube triangulation of a 3D path.
+///
+/// Vertices of triangles are `centers + radius * offsets`, triangles are
+/// oriented counter-clockwise when seen from outside the tube. Offsets have unit
+/// length, except at mitered joints, where they are longer to keep the radius
+/// of both segments, and in centers of caps, where they are zero.
+#[derive(Debug, Default)]
+pub struct TubeTriangulation<T = Coord> {
+    pub triangles: Vec<Triangle>,
+    pub centers: Vec<[T; 3]>,
+    pub offsets: Vec<[T; 3]>,
+}
+
+/// Rotates `vector` around the unit `axis` by the angle with the given cosine and sine.
+fn rotate(vector: Vector, axis: Vector, cos: f64, sin: f64) -> Vector {
+    let across = cross(axis, vector);
+    let along = dot(axis, vector) * (1.0 - cos);
+    [0, 1, 2].map(|k| vector[k] * cos + across[k] * sin + axis[k] * along)
+}
+
+/// Rotates `vector` by the smallest rotation taking unit vector `from` to `to`,
+/// keeping it perpendicular to `to` if it was perpendicular to `from`.
+fn transport(vector: Vector, from: Vector, to: Vector) -> Vector {
+    let axis = cross(from, to);
+    let sin = dot(axis, axis).sqrt();
+    if sin <= f64::EPSILON {
+        // parallel or opposite directions, `vector` is perpendicular to both
+        return vector;
+    }
+    rotate(vector, axis.map(|c| c / sin), dot(from, to), sin)
+}
+
+/// Unit vector perpendicular to unit `direction`, closest to the coordinate axis
+/// along which `direction` has the smallest component.
+fn perpendicular(direction: Vector) -> Vector {
+    let axis = (0..3)
+        .min_by(|&i, &j| direction[i].abs().total_cmp(&direction[j].abs()))
+        .unwrap();
+    let mut vector = [0.0; 3];
+    vector[axis] = 1.0;
+    normalized([0, 1, 2].map(|k| vector[k] - direction[axis] * direction[k]))
+}
+
+/// Path points without consecutive duplicates, with the index of the distinct point
+/// of each path point.
+///
+/// For closed paths the last point is merged with the first one if they are equal.
+fn distinct_points<T: Float>(path: &[[T; 3]], closed: bool) -> (Vec<Vector>, Vec<usize>) {
+    let mut points: Vec<Vector> = Vec::with_capacity(path.len());
+    let mut indices = Vec::with_capacity(path.len());
+    for p in path.iter().map(to_f64) {
+        if points.last() != Some(&p) {
+            points.push(p);
+        }
+        indices.push(points.len() - 1);
+    }
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+        let merged = points.len();
+        for index in &mut indices {
+            if *index == merged {
+                *index = 0;
+            }
+        }
+    }
+    (points, indices)
+}
+
+/// Rotation minimizing frames of segments of a path with at least two distinct points.
+///
+/// `directions` are unit directions of segments and `normals` are perpendicular to them,
+/// transported along the path with the smallest rotation at each corner.
+/// For closed paths the twist accumulated over the loop is distributed evenly
+/// over all corners, so the frame of the last segment continues into the first one.
+struct Frames {
+    directions: Vec<Vector>,
+    normals: Vec<Vector>,
+}
+
+impl Frames {
+    fn new(points: &[Vector], closed: bool) -> Self {
+        let count = if closed {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+        let directions: Vec<Vector> = (0..count)
+            .map(|i| {
+                let (p, q) = (points[i], points[(i + 1) % points.len()]);
+                normalized([0, 1, 2].map(|k| q[k] - p[k]))
+            })
+            .collect();
+        let mut normals = Vec::with_capacity(count);
+        normals.push(perpendicular(directions[0]));
+        for i in 1..count {
+            let normal = transport(normals[i - 1], directions[i - 1], directions[i]);
+            // remove rounding errors accumulated along the path
+            let along = dot(normal, directions[i]);
+            normals.push(normalized(
+                [0, 1, 2].map(|k| normal[k] - along * directions[i][k]),
+            ));
+        }
+        if closed {
+            let first = directions[0];
+            let last = transport(normals[count - 1], directions[count - 1], first);
+            let twist = dot(cross(last, normals[0]), first).atan2(dot(last, normals[0]));
+            for (i, normal) in normals.iter_mut().enumerate() {
+                let angle = twist * i as f64 / count as f64;
+                *normal = rotate(*normal, directions[i], angle.cos(), angle.sin());
+            }
+        }
+        Frames {
+            directions,
+            normals,
+        }
+    }
+
+    /// Indices of segments before and after the point `i` of a path with `len` points.
+    fn segments_at(&self, i: usize, len: usize) -> (Option<usize>, Option<usize>) {
+        let count = self.directions.len();
+        let before = if i > 0 {
+            Some(i - 1)
+        } else if count == len {
+            Some(count - 1)
+        } else {
+            None
+        };
+        (before, (i < count).then_some(i))
+    }
+}
+
+fn to_coords<T: Float>(vector: Vector) -> [T; 3] {
+    vector.map(T::from_f64)
+}
+
+/// Generate ribbon triangulation of a 3D path.
+///
+/// Vertices are computed by the renderer from the returned data, see [`RibbonTriangulation`].
+/// Consecutive repeated points keep their vertices, but do not produce triangles.
+/// Paths with less than two distinct points produce an empty triangulation.
+///
+/// # Example
+/// ```
+/// use triangulation::path_triangulation_3d::triangulate_path_ribbon;
+///
+/// let path = vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 1.0]];
+/// let result = triangulate_path_ribbon(&path, false);
+/// assert_eq!(result.centers.len(), 6);
+/// assert_eq!(result.triangles.len(), 4);
+/// assert_eq!(result.tangents[0], [1.0, 0.0, 0.0]);
+/// assert_eq!(result.sides[..2], [-1.0, 1.0]);
+/// ```
+pub fn triangulate_path_ribbon<T: Float>(path: &[[T; 3]], closed: bool) -> RibbonTriangulation<T> {
+    let (points, indices) = distinct_points(path, closed);
+    if points.len() < 2 {
+        return RibbonTriangulation::default();
+    }
+    let frames = Frames::new(&points, closed);
+    let (tangents, normals): (Vec<Vector>, Vec<Vector>) = (0..points.len())
+        .map(|i| {
+            let (before, after) = frames.segments_at(i, points.len());
+            let direction = |segment: Option<usize>| segment.map(|s| frames.directions[s]);
+            let tangent = match (direction(before), direction(after)) {
+                (Some(d1), Some(d2)) => {
+                    let sum = [0, 1, 2].map(|k| d1[k] + d2[k]);
+                    if dot(sum, sum) > f64::EPSILON {
+                        normalized(sum)
+                    } else {
+                        d1
+                    }
+                }
+                (Some(d), None) | (None, Some(d)) => d,
+                (None, None) => unreachable!("path has at least one segment"),
+            };
+            let normal = frames.normals[after.or(before).unwrap()];
+            let along = dot(normal, tangent);
+            let normal = normalized([0, 1, 2].map(|k| normal[k] - along * tangent[k]));
+            (tangent, normal)
+        })
+        .unzip();
+
+    let mut result = RibbonTriangulation::default();
+    for (p, &index) in path.iter().zip(&indices) {
+        for side in [-T::ONE, T::ONE] {
+            result.centers.push(*p);
+            result.tangents.push(to_coords(tangents[index]));
+            result.normals.push(to_coords(normals[index]));
+            result.sides.push(side);
+        }
+    }
+    let last = path.len() - 1;
+    let segments = (0..last)
+        .map(|i| (i, i + 1))
+        .chain(closed.then_some((last, 0)));
+    for (i, j) in segments {
+        if indices[i] != indices[j] {
+            let (a, b) = (2 * i, 2 * j);
+            result.triangles.push(Triangle::new(a, a + 1, b + 1));
+            result.triangles.push(Triangle::new(a, b + 1, b));
+        }
+    }
+    result
+}
+
+/// Offsets of a ring of `segments` vertices around a tube segment with unit `direction`,
+/// starting at `normal`.
+///
+/// With `miter` the ring lies on the plane perpendicular to it, the unit direction
+/// of a joint, otherwise on the plane perpendicular to `direction`.
+fn ring(direction: Vector, normal: Vector, miter: Option<Vector>, segments: usize) -> Vec<Vector> {
+    let binormal = cross(direction, normal);
+    (0..segments)
+        .map(|k| {
+            let (sin, cos) = (TAU * k as f64 / segments as f64).sin_cos();
+            let offset = [0, 1, 2].map(|i| cos * normal[i] + sin * binormal[i]);
+            match miter {
+                // move the offset along the segment onto the plane of the joint
+                Some(miter) => {
+                    let shift = dot(offset, miter) / dot(direction, miter);
+                    [0, 1, 2].map(|i| offset[i] - shift * direction[i])
+                }
+                None => offset,
+            }
+        })
+        .collect()
+}
+
+/// Generate tube triangulation of a 3D path.
+///
+/// Segments meet in a single ring of vertices on the plane bisecting their joint,
+/// unless the miter limit is exceeded. Then each segment ends with its own ring,
+/// and the rings are connected with a band of triangles.
+/// Paths with less than two distinct points produce an empty triangulation.
+///
+/// # Example
+/// ```
+/// use triangulation::path_triangulation_3d::{triangulate_path_tube, TubeStyle};
+///
+/// let path = vec![[0.0, 0.0, 0.0], [0.0, 0.0, 5.0], [0.0, 5.0, 5.0]];
+/// let result = triangulate_path_tube(&path, &TubeStyle::new(false, 6));
+/// // a ring of 6 vertices at each point and 6 quads along each segment
+/// assert_eq!(result.centers.len(), 3 * 6);
+/// assert_eq!(result.triangles.len(), 2 * 2 * 6);
+///
+/// let capped = triangulate_path_tube(&path, &TubeStyle::new(false, 6).with_caps(true));
+/// assert_eq!(capped.triangles.len(), result.triangles.len() + 2 * 6);
+/// ```
+pub fn triangulate_path_tube<T: Float>(path: &[[T; 3]], style: &TubeStyle) -> TubeTriangulation<T> {
+    let TubeStyle {
+        closed,
+        segments,
+        limit,
+        caps,
+    } = *style;
+    let segments = segments.max(3);
+    let (points, _) = distinct_points(path, closed);
+    if points.len() < 2 {
+        return TubeTriangulation::default();
+    }
+    let frames = Frames::new(&points, closed);
+    let mut rings: Vec<(Vector, Vec<Vector>)> = Vec::with_capacity(points.len() + 2);
+    let mut first_bevelled = false;
+    for (i, &point) in points.iter().enumerate() {
+        let (before, after) = frames.segments_at(i, points.len());
+        let frame = |s: usize| (frames.directions[s], frames.normals[s]);
+        match (before.map(frame), after.map(frame)) {
+            (Some((d1, n1)), Some((d2, n2))) => {
+                let sum = [0, 1, 2].map(|k| d1[k] + d2[k]);
+                let length = dot(sum, sum).sqrt();
+                // cosine of half of the turn, the miter is 1 / cos long
+                if length / 2.0 * limit.to_f64() >= 1.0 {
+                    let miter = sum.map(|c| c / length);
+                    rings.push((point, ring(d1, n1, Some(miter), segments)));
+                } else {
+                    rings.push((point, ring(d1, n1, None, segments)));
+                    rings.push((point, ring(d2, n2, None, segments)));
+                    first_bevelled |= i == 0;
+                }
+            }
+            (Some((d, n)), None) | (None, Some((d, n))) => {
+                rings.push((point, ring(d, n, None, segments)));
+            }
+            (None, None) => unreachable!("path has at least one segment"),
+        }
+    }
+    if first_bevelled {
+        // the ring ending the last segment belongs at the end
+        rings.rotate_left(1);
+    }
+
+    let mut result = TubeTriangulation::default();
+    for (center, offsets) in &rings {
+        for offset in offsets {
+            result.centers.push(to_coords(*center));
+            result.offsets.push(to_coords(*offset));
+        }
+    }
+    let bands = (0..rings.len() - 1)
+        .map(|r| (r, r + 1))
+        .chain(closed.then_some((rings.len() - 1, 0)));
+    for (r1, r2) in bands {
+        for k in 0..segments {
+            let next = (k + 1) % segments;
+            let (a, b) = (r1 * segments, r2 * segments);
+            result
+                .triangles
+                .push(Triangle::new(a + k, a + next, b + next));
+            result.triangles.push(Triangle::new(a + k, b + next, b + k));
+        }
+    }
+    if caps && !closed {
+        let last = (rings.len() - 1) * segments;
+        for (start, ring, reversed) in
+            [(0, &rings[0], true), (last, &rings[rings.len() - 1], false)]
+        {
+            let center = result.centers.len();
+            result.centers.push(to_coords(ring.0));
+            result.offsets.push([T::ZERO; 3]);
+            for k in 0..segments {
+                let (p, q) = (start + k, start + (k + 1) % segments);
+                result.triangles.push(if reversed {
+                    Triangle::new(center, q, p)
+                } else {
+                    Triangle::new(center, p, q)
+                });
+            }
+        }
+    }
+    result
+}
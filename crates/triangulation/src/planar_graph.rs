@@ -0,0 +1,171 @@
+//! Planar graph built from edges of polygons split at their intersections.
+//!
+//! Faces of the graph are labeled in a sweep line, so that operations
+//! like fill rules or boolean operations only need to decide which faces
+//! are filled, and the boundary of filled faces is joined back into rings.
+
+use crate::error::TriangulationError;
+use crate::intersection::{order_below_common_point, snap_round_polygons};
+use crate::point::{orientation, Float, Orientation, Point, Segment};
+use crate::sweep_status::SweepStatus;
+use std::collections::BTreeMap;
+
+type Polygons<T> = Vec<Vec<Point<T>>>;
+
+/// Splits edges of the rings at their intersections, keeping the number,
+/// order and direction of the rings.
+///
+/// Consecutive duplicated points and closing points equal to the first one are removed.
+/// Rings with fewer than 3 distinct points are replaced by empty ones, so indices
+/// of the other rings do not change.
+pub(crate) fn split_rings<'a, T: Float + 'a>(
+    rings: impl IntoIterator<Item = &'a [Point<T>]>,
+) -> Result<Polygons<T>, TriangulationError<T>> {
+    let rings: Polygons<T> = rings
+        .into_iter()
+        .map(|ring| {
+            let mut ring = ring.to_vec();
+            ring.dedup();
+            while ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            if ring.len() < 3 {
+                ring.clear();
+            }
+            ring
+        })
+        .collect();
+    let (rings, _) = snap_round_polygons(&rings, &Default::default())?;
+    Ok(rings)
+}
+
+/// Directed edges of a ring, including the closing edge.
+pub(crate) fn ring_edges<T: Float>(
+    ring: &[Point<T>],
+) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+    let closing = (ring.len() > 1).then(|| (ring[ring.len() - 1], ring[0]));
+    ring.windows(2)
+        .map(|window| (window[0], window[1]))
+        .chain(closing)
+}
+
+/// Labels of the faces on the left side of each segment, when looking
+/// from its bottom to its top.
+///
+/// The face to the left of all segments is labeled `outside`, and
+/// `cross(i, label)` gives the label on the right side of segment `i`
+/// from the label on its left side.
+/// Segments must not cross or touch each other outside of their endpoints.
+pub(crate) fn label_left_sides<T: Float, L: Copy>(
+    segments: &[Segment<T>],
+    outside: L,
+    cross: impl Fn(usize, L) -> L,
+) -> Vec<L> {
+    let mut events: BTreeMap<Point<T>, (Vec<usize>, Vec<usize>)> = BTreeMap::new();
+    for (i, segment) in segments.iter().enumerate() {
+        events.entry(segment.top).or_default().0.push(i);
+        events.entry(segment.bottom).or_default().1.push(i);
+    }
+
+    let mut status = SweepStatus::new(segments.len());
+    let mut left_sides = vec![outside; segments.len()];
+    while let Some((p, (mut tops, bottoms))) = events.pop_last() {
+        for i in bottoms {
+            status.remove(i);
+        }
+        // no active segment passes through `p`, so it lies between two of them
+        let after = status.partition_point(|i| {
+            let segment = &segments[i];
+            orientation(segment.bottom, segment.top, p) == Orientation::Clockwise
+        });
+        let mut left = match after {
+            Some(after) => status.prev(after),
+            None => status.last(),
+        };
+        tops.sort_by(|&i, &j| order_below_common_point(&segments[i], &segments[j]).then(i.cmp(&j)));
+        for i in tops {
+            // face between neighboring segments is the same for both of them
+            left_sides[i] = match left {
+                Some(left) => cross(left, left_sides[left]),
+                None => outside,
+            };
+            status.insert_before(i, after);
+            left = Some(i);
+        }
+    }
+    left_sides
+}
+
+/// Joins directed edges into closed rings.
+///
+/// At vertices with several outgoing edges the ring turns as much to the left as
+/// possible, so rings touching at a vertex are not merged into a single ring.
+pub(crate) fn join_rings<T: Float>(
+    edges: impl IntoIterator<Item = (Point<T>, Point<T>)>,
+) -> Polygons<T> {
+    let mut outgoing: BTreeMap<Point<T>, Vec<Point<T>>> = BTreeMap::new();
+    for (start, end) in edges {
+        outgoing.entry(start).or_default().push(end);
+    }
+    let mut rings = Vec::new();
+    while let Some((&start, targets)) = outgoing.iter_mut().next_back() {
+        let mut ring = vec![start];
+        let mut previous = start;
+        let mut current = targets.pop().unwrap();
+        if targets.is_empty() {
+            outgoing.remove(&start);
+        }
+        while current != start {
+            ring.push(current);
+            let Some(targets) = outgoing.get_mut(&current) else {
+                // unbalanced graph, which may only result from invalid input
+                break;
+            };
+            let best = (1..targets.len()).fold(0, |best, k| {
+                if is_more_left(previous, current, targets[best], targets[k]) {
+                    k
+                } else {
+                    best
+                }
+            });
+            let next = targets.swap_remove(best);
+            if targets.is_empty() {
+                outgoing.remove(&current);
+            }
+            previous = current;
+            current = next;
+        }
+        if ring.len() >= 3 {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+/// Whether continuing from `current` to `second` turns more to the left
+/// than continuing to `first`, when coming from `previous`.
+fn is_more_left<T: Float>(
+    previous: Point<T>,
+    current: Point<T>,
+    first: Point<T>,
+    second: Point<T>,
+) -> bool {
+    let turn = |next: Point<T>| match orientation(previous, current, next) {
+        Orientation::CounterClockwise => 3,
+        Orientation::Clockwise => 1,
+        Orientation::Collinear => {
+            let (incoming, outgoing) = (current - previous, next - current);
+            if incoming.x * outgoing.x + incoming.y * outgoing.y > T::ZERO {
+                2
+            } else {
+                0
+            }
+        }
+    };
+    match turn(second).cmp(&turn(first)) {
+        std::cmp::Ordering::Equal => {
+            orientation(current, first, second) == Orientation::CounterClockwise
+        }
+        ordering => ordering == std::cmp::Ordering::Greater,
+    }
+}
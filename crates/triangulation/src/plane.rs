@@ -0,0 +1,264 @@
+//! Planes of 3D polygons, used to triangulate them in 2D coordinates of their plane.
+
+use crate::error::TriangulationError;
+use crate::point::{Float, Point};
+
+pub(crate) type Vector = [f64; 3];
+type ProjectedPolygons<T> = (Vec<Vec<Point<T>>>, Plane);
+
+pub(crate) fn dot(a: Vector, b: Vector) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: Vector, b: Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn normalized(a: Vector) -> Vector {
+    let length = dot(a, a).sqrt();
+    a.map(|c| c / length)
+}
+
+pub(crate) fn to_f64<T: Float>(p: &[T; 3]) -> Vector {
+    p.map(T::to_f64)
+}
+
+/// Plane with an orthonormal frame of 2D coordinates in it.
+///
+/// The frame follows the coordinate axes: when the normal points along axis `k`,
+/// `u` and `v` are the projections of the two remaining axes in increasing order,
+/// so for polygons on a coordinate plane the 2D coordinates are their original
+/// coordinates without axis `k`.
+///
+/// # Fields
+/// * `normal` - Unit normal, equal to `u × v`.
+/// * `offset` - Signed distance of the plane from the origin along `normal`.
+/// * `u` - First axis of the 2D frame.
+/// * `v` - Second axis of the 2D frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: [f64; 3],
+    pub offset: f64,
+    pub u: [f64; 3],
+    pub v: [f64; 3],
+}
+
+impl Plane {
+    /// Creates a plane through `point` perpendicular to `normal`.
+    ///
+    /// The normal is normalized and may be flipped to keep the frame aligned with
+    /// the coordinate axes, a zero normal gives a plane perpendicular to the z axis.
+    pub fn new(normal: [f64; 3], point: [f64; 3]) -> Self {
+        let normal = if dot(normal, normal) > 0.0 {
+            normalized(normal)
+        } else {
+            [0.0, 0.0, 1.0]
+        };
+        let axis = (0..3)
+            .max_by(|&i, &j| normal[i].abs().total_cmp(&normal[j].abs()))
+            .unwrap();
+        let (i, j) = match axis {
+            0 => (1, 2),
+            1 => (0, 2),
+            _ => (0, 1),
+        };
+        let mut e_i = [0.0; 3];
+        e_i[i] = 1.0;
+        let mut e_j = [0.0; 3];
+        e_j[j] = 1.0;
+        let normal = if dot(normal, cross(e_i, e_j)) < 0.0 {
+            normal.map(|c| -c)
+        } else {
+            normal
+        };
+        let u = normalized([0, 1, 2].map(|k| e_i[k] - normal[i] * normal[k]));
+        Plane {
+            normal,
+            offset: dot(normal, point),
+            u,
+            v: cross(normal, u),
+        }
+    }
+
+    /// Fits a plane to rings of 3D points with Newell's method.
+    ///
+    /// The normal is the sum of cross products of consecutive vertices of the rings,
+    /// which is the area weighted normal of non-planar rings and stays accurate for
+    /// nearly collinear vertices. The plane passes through the centroid of the vertices.
+    /// When all points share one coordinate, the plane is exactly that coordinate plane.
+    /// Rings without area, like collinear points, are fitted by a coordinate plane
+    /// containing them where possible.
+    ///
+    /// # Example
+    /// ```
+    /// use triangulation::plane::Plane;
+    ///
+    /// let ring = vec![[0.0f32, 0.0, 0.0], [1.0, 0.0, 1.0], [1.0, 1.0, 1.0], [0.0, 1.0, 0.0]];
+    /// let plane = Plane::fit(&[ring]);
+    /// let expected = -std::f64::consts::FRAC_1_SQRT_2;
+    /// assert!((plane.normal[0] - expected).abs() < 1e-12);
+    /// assert!(plane.normal[1].abs() < 1e-12);
+    /// ```
+    pub fn fit<T: Float>(rings: &[Vec<[T; 3]>]) -> Self {
+        let points = rings.iter().flatten().map(to_f64);
+        let count = points.clone().count();
+        if count == 0 {
+            return Plane::new([0.0, 0.0, 1.0], [0.0; 3]);
+        }
+        let mut centroid = [0.0; 3];
+        for p in points.clone() {
+            (0..3).for_each(|k| centroid[k] += p[k] / count as f64);
+        }
+        let first = points.clone().next().unwrap();
+        if let Some(axis) = (0..3)
+            .rev()
+            .find(|&k| points.clone().all(|p| p[k] == first[k]))
+        {
+            let mut normal = [0.0; 3];
+            normal[axis] = 1.0;
+            return Plane::new(normal, first);
+        }
+
+        let mut normal = [0.0; 3];
+        for ring in rings {
+            for (k, p) in ring.iter().enumerate() {
+                // relative to the centroid to reduce cancellation for coordinates far from the origin
+                let q = to_f64(&ring[(k + 1) % ring.len()]);
+                let p = to_f64(p);
+                let (p, q) = (
+                    [0, 1, 2].map(|i| p[i] - centroid[i]),
+                    [0, 1, 2].map(|i| q[i] - centroid[i]),
+                );
+                let c = cross(p, q);
+                (0..3).for_each(|i| normal[i] += c[i]);
+            }
+        }
+        if dot(normal, normal) == 0.0 {
+            // no area, any plane containing the points would do
+            let spread = |k: usize| {
+                points
+                    .clone()
+                    .map(|p| (p[k] - centroid[k]).abs())
+                    .fold(0.0, f64::max)
+            };
+            let axis = (0..3)
+                .min_by(|&i, &j| spread(i).total_cmp(&spread(j)))
+                .unwrap();
+            normal[axis] = 1.0;
+        }
+        Plane::new(normal, centroid)
+    }
+
+    /// Signed distance of `point` from the plane.
+    pub fn distance(&self, point: [f64; 3]) -> f64 {
+        dot(self.normal, point) - self.offset
+    }
+
+    /// Coordinates of `point` projected onto the plane, in the frame of the plane.
+    pub fn project(&self, point: [f64; 3]) -> [f64; 2] {
+        [dot(self.u, point), dot(self.v, point)]
+    }
+
+    /// Point of the plane with the given coordinates in its frame.
+    pub fn unproject(&self, point: [f64; 2]) -> [f64; 3] {
+        [0, 1, 2]
+            .map(|k| point[0] * self.u[k] + point[1] * self.v[k] + self.offset * self.normal[k])
+    }
+}
+
+/// Fits a plane to 3D rings and projects them into 2D coordinates of the plane.
+///
+/// See [`Plane::fit`] for the fitting. Triangulation of the projected rings can be
+/// mapped back to 3D with [`Plane::unproject`], vertices of the rings are best
+/// mapped back to their original coordinates.
+///
+/// # Arguments
+/// * `rings` - Rings of 3D points
+/// * `tolerance` - Largest allowed distance of a vertex from the fitted plane. `None` uses
+///   `1e-6` of the bounding box diagonal, plus the rounding error of coordinates in `T`.
+///
+/// # Returns
+/// A tuple of projected rings and the fitted plane.
+///
+/// # Errors
+/// Returns [`TriangulationError::NotPlanar`] with the largest distance of a vertex
+/// from the plane if it exceeds `tolerance`.
+///
+/// # Example
+/// ```
+/// use triangulation::plane::project_to_plane;
+/// use triangulation::TriangulationError;
+///
+/// // square on the plane x = z
+/// let ring = vec![[0.0f32, 0.0, 0.0], [1.0, 0.0, 1.0], [1.0, 1.0, 1.0], [0.0, 1.0, 0.0]];
+/// let (rings, plane) = project_to_plane(&[ring.clone()], None).unwrap();
+/// assert_eq!(rings[0].len(), 4);
+/// let back = plane.unproject([rings[0][2].x as f64, rings[0][2].y as f64]);
+/// assert!((back[0] - 1.0).abs() < 1e-6 && (back[1] - 1.0).abs() < 1e-6);
+///
+/// let mut bent = ring;
+/// bent[2][2] = 1.5;
+/// let err = project_to_plane(&[bent], None).unwrap_err();
+/// assert!(matches!(err, TriangulationError::NotPlanar { polygon: 0, .. }));
+/// ```
+pub fn project_to_plane<T: Float>(
+    rings: &[Vec<[T; 3]>],
+    tolerance: Option<T>,
+) -> Result<ProjectedPolygons<T>, TriangulationError<T>> {
+    let plane = Plane::fit(rings);
+    let tolerance = tolerance.map_or_else(
+        || {
+            let (mut low, mut high) = ([f64::INFINITY; 3], [f64::NEG_INFINITY; 3]);
+            for p in rings.iter().flatten().map(to_f64) {
+                for k in 0..3 {
+                    low[k] = low[k].min(p[k]);
+                    high[k] = high[k].max(p[k]);
+                }
+            }
+            let diagonal = [0, 1, 2].map(|k| (high[k] - low[k]).max(0.0));
+            let magnitude = [0, 1, 2]
+                .map(|k| low[k].abs().max(high[k].abs()))
+                .into_iter()
+                .fold(0.0, f64::max);
+            1e-6 * dot(diagonal, diagonal).sqrt() + 4.0 * T::EPSILON.to_f64() * magnitude
+        },
+        T::to_f64,
+    );
+
+    let deviations = rings.iter().enumerate().flat_map(|(polygon, ring)| {
+        ring.iter()
+            .map(move |p| (polygon, plane.distance(to_f64(p)).abs()))
+    });
+    if let Some((polygon, deviation)) = deviations.fold(None, |max, (polygon, deviation)| match max
+    {
+        Some((_, max_deviation)) if max_deviation >= deviation => max,
+        _ => Some((polygon, deviation)),
+    }) {
+        if deviation > tolerance {
+            return Err(TriangulationError::NotPlanar {
+                polygon,
+                deviation: T::from_f64(deviation),
+            });
+        }
+    }
+
+    let projected = rings
+        .iter()
+        .map(|ring| {
+            let mut points: Vec<Point<T>> = ring
+                .iter()
+                .map(|p| {
+                    let [x, y] = plane.project(to_f64(p));
+                    Point::new(T::from_f64(x), T::from_f64(y))
+                })
+                .collect();
+            points.dedup();
+            points
+        })
+        .collect();
+    Ok((projected, plane))
+}
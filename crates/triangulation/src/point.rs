@@ -1,51 +1,268 @@
+use crate::error::TriangulationError;
+use crate::predicates::orient2d;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::{iter, ops};
 
+/// Default coordinate type.
 pub(crate) type Coord = f32;
 pub(crate) type Index = usize;
 
+/// Floating point type of coordinates, implemented for `f32` and `f64`.
+///
+/// Geometry types and algorithms are generic over this trait and use `f32`
+/// by default. `f64` keeps precision of large coordinates, like geospatial
+/// or microscope stage coordinates.
+pub trait Float:
+    Copy
+    + Default
+    + PartialOrd
+    + fmt::Debug
+    + fmt::Display
+    + Send
+    + Sync
+    + 'static
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::Neg<Output = Self>
+    + ops::AddAssign
+    + ops::SubAssign
+    + ops::MulAssign
+    + ops::DivAssign
+    + iter::Sum
+    + for<'a> iter::Sum<&'a Self>
+{
+    const ZERO: Self;
+    const HALF: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const EPSILON: Self;
+    const PI: Self;
+
+    /// Convert from `f64`, rounding to the nearest representable value.
+    fn from_f64(value: f64) -> Self;
+    fn from_usize(value: usize) -> Self;
+    fn to_f64(self) -> f64;
+    /// Bit representation used for hashing, equal values have equal bits
+    /// (except for `0.0` and `-0.0`).
+    fn to_bits_u64(self) -> u64;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn atan2(self, other: Self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn signum(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($float:ident) => {
+        impl Float for $float {
+            const ZERO: Self = 0.0;
+            const HALF: Self = 0.5;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const EPSILON: Self = $float::EPSILON;
+            const PI: Self = std::$float::consts::PI;
+
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+            fn from_usize(value: usize) -> Self {
+                value as $float
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn to_bits_u64(self) -> u64 {
+                self.to_bits() as u64
+            }
+
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+            fn sqrt(self) -> Self {
+                $float::sqrt(self)
+            }
+            fn powi(self, n: i32) -> Self {
+                $float::powi(self, n)
+            }
+            fn sin(self) -> Self {
+                $float::sin(self)
+            }
+            fn cos(self) -> Self {
+                $float::cos(self)
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                $float::sin_cos(self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                $float::atan2(self, other)
+            }
+            fn ceil(self) -> Self {
+                $float::ceil(self)
+            }
+            fn round(self) -> Self {
+                $float::round(self)
+            }
+            fn min(self, other: Self) -> Self {
+                $float::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $float::max(self, other)
+            }
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $float::clamp(self, min, max)
+            }
+            fn signum(self) -> Self {
+                $float::signum(self)
+            }
+            fn copysign(self, sign: Self) -> Self {
+                $float::copysign(self, sign)
+            }
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $float::rem_euclid(self, rhs)
+            }
+            fn is_nan(self) -> bool {
+                $float::is_nan(self)
+            }
+            fn is_finite(self) -> bool {
+                $float::is_finite(self)
+            }
+            fn is_infinite(self) -> bool {
+                $float::is_infinite(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Point in 2D.
+///
+/// The layout is `#[repr(C)]`, equal to `[T; 2]`, so slices of points can be viewed
+/// as interleaved coordinates `[x0, y0, x1, y1, ...]` and back without copying,
+/// see [`Point::slice_from_coords`].
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Point {
-    pub x: Coord,
-    pub y: Coord,
+#[repr(C)]
+pub struct Point<T = Coord> {
+    pub x: T,
+    pub y: T,
 }
 
 impl Point {
-    pub const fn new(x: Coord, y: Coord) -> Self {
-        Self { x, y }
-    }
     pub fn new_i(x: i32, y: i32) -> Self {
-        Self::new(x as f32, y as f32)
+        Self::new(x as Coord, y as Coord)
     }
+}
 
-    pub fn add(&self, other: &Point) -> Vector {
+impl<T: Float> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+    pub fn add(&self, other: &Point<T>) -> Vector<T> {
         Vector {
             x: self.x + other.x,
             y: self.y + other.y,
         }
     }
 
-    pub fn sub(&self, other: &Point) -> Vector {
+    pub fn sub(&self, other: &Point<T>) -> Vector<T> {
         Vector {
             x: self.x - other.x,
             y: self.y - other.y,
         }
     }
 
-    pub fn add_vector(&self, vector: &Vector) -> Point {
+    pub fn add_vector(&self, vector: &Vector<T>) -> Point<T> {
         Point {
             x: self.x + vector.x,
             y: self.y + vector.y,
         }
     }
+
+    /// Convert coordinates to another float type.
+    pub fn cast<U: Float>(self) -> Point<U> {
+        Point::new(U::from_f64(self.x.to_f64()), U::from_f64(self.y.to_f64()))
+    }
+
+    /// View interleaved coordinates `[x0, y0, x1, y1, ...]` as points, without copying.
+    ///
+    /// Returns `None` if the number of coordinates is odd.
+    ///
+    /// # Example
+    /// ```
+    /// use triangulation::Point;
+    ///
+    /// let coords = [0.0f32, 1.0, 2.0, 3.0];
+    /// let points = Point::slice_from_coords(&coords).unwrap();
+    /// assert_eq!(points, [Point::new(0.0, 1.0), Point::new(2.0, 3.0)]);
+    /// assert_eq!(Point::slice_as_coords(points), coords);
+    /// ```
+    #[allow(unsafe_code)]
+    pub fn slice_from_coords(coords: &[T]) -> Option<&[Point<T>]> {
+        if !coords.len().is_multiple_of(2) {
+            return None;
+        }
+        // SAFETY: `Point<T>` is `repr(C)` with two fields of type `T`, so it has the layout
+        // of `[T; 2]`, and every pair of coordinates is a valid point.
+        Some(unsafe { std::slice::from_raw_parts(coords.as_ptr().cast(), coords.len() / 2) })
+    }
+
+    /// View points as interleaved coordinates `[x0, y0, x1, y1, ...]`, without copying.
+    #[allow(unsafe_code)]
+    pub fn slice_as_coords(points: &[Point<T>]) -> &[T] {
+        // SAFETY: `Point<T>` has the layout of `[T; 2]`.
+        unsafe { std::slice::from_raw_parts(points.as_ptr().cast(), points.len() * 2) }
+    }
+
+    /// Convert points into interleaved coordinates, reusing their allocation.
+    #[allow(unsafe_code)]
+    pub fn vec_into_coords(points: Vec<Point<T>>) -> Vec<T> {
+        // SAFETY: `Point<T>` has the layout of `[T; 2]`.
+        unsafe { pairs_into_coords(points) }
+    }
+}
+
+/// Convert a vector of pairs into a vector of their interleaved coordinates,
+/// reusing its allocation.
+///
+/// # Safety
+/// `P` must have the layout of `[T; 2]`.
+#[allow(unsafe_code)]
+unsafe fn pairs_into_coords<P, T>(pairs: Vec<P>) -> Vec<T> {
+    let mut pairs = std::mem::ManuallyDrop::new(pairs);
+    // SAFETY: allocation of `n` pairs has the size and alignment of `2 * n` coordinates.
+    unsafe {
+        Vec::from_raw_parts(
+            pairs.as_mut_ptr().cast(),
+            pairs.len() * 2,
+            pairs.capacity() * 2,
+        )
+    }
 }
 
-impl Eq for Point {}
+impl<T: Float> Eq for Point<T> {}
 
 #[allow(clippy::non_canonical_partial_ord_impl)]
-impl PartialOrd for Point {
+impl<T: Float> PartialOrd for Point<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self.y == other.y {
             self.x.partial_cmp(&other.x)
@@ -55,57 +272,66 @@ impl PartialOrd for Point {
     }
 }
 
-impl Ord for Point {
+impl<T: Float> Ord for Point<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.partial_cmp(other).unwrap()
     }
 }
 
-impl Hash for Point {
+impl<T: Float> Hash for Point<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let x_hash = (self.x.to_bits() as u64).rotate_left(16);
-        let y_hash = self.y.to_bits() as u64;
+        let x_hash = self.x.to_bits_u64().rotate_left(16);
+        let y_hash = self.y.to_bits_u64();
         state.write_u64(x_hash ^ y_hash);
     }
 }
 
-impl fmt::Display for Point {
+impl<T: Float> fmt::Display for Point<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "(x={}, y={})", self.x, self.y)
     }
 }
 
+/// Vector in 2D, with the layout of `[T; 2]` like [`Point`].
 #[derive(Debug, Clone, Copy)]
-pub struct Vector {
-    pub x: Coord,
-    pub y: Coord,
+#[repr(C)]
+pub struct Vector<T = Coord> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Vector {
-    pub fn new(x: Coord, y: Coord) -> Self {
+impl<T: Float> Vector<T> {
+    pub fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
-    pub fn scale(&self, factor: Coord) -> Vector {
+    pub fn scale(&self, factor: T) -> Vector<T> {
         Vector {
             x: self.x * factor,
             y: self.y * factor,
         }
     }
+
+    /// Convert vectors into interleaved coordinates, reusing their allocation.
+    #[allow(unsafe_code)]
+    pub fn vec_into_coords(vectors: Vec<Vector<T>>) -> Vec<T> {
+        // SAFETY: `Vector<T>` is `repr(C)` with two fields of type `T`.
+        unsafe { pairs_into_coords(vectors) }
+    }
 }
 
-impl std::ops::Add<Vector> for Point {
-    type Output = Point;
-    fn add(self, other: Vector) -> Point {
+impl<T: Float> std::ops::Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+    fn add(self, other: Vector<T>) -> Point<T> {
         Point {
             x: self.x + other.x,
             y: self.y + other.y,
         }
     }
 }
-impl std::ops::Sub for Point {
-    type Output = Vector;
-    fn sub(self, other: Point) -> Vector {
+impl<T: Float> std::ops::Sub for Point<T> {
+    type Output = Vector<T>;
+    fn sub(self, other: Point<T>) -> Vector<T> {
         Vector {
             x: self.x - other.x,
             y: self.y - other.y,
@@ -113,10 +339,10 @@ impl std::ops::Sub for Point {
     }
 }
 
-impl std::ops::Add for Vector {
-    type Output = Vector;
+impl<T: Float> std::ops::Add for Vector<T> {
+    type Output = Vector<T>;
 
-    fn add(self, other: Vector) -> Vector {
+    fn add(self, other: Vector<T>) -> Vector<T> {
         Vector {
             x: self.x + other.x,
             y: self.y + other.y,
@@ -124,20 +350,20 @@ impl std::ops::Add for Vector {
     }
 }
 
-impl std::ops::Sub for Vector {
-    type Output = Vector;
+impl<T: Float> std::ops::Sub for Vector<T> {
+    type Output = Vector<T>;
 
-    fn sub(self, other: Vector) -> Vector {
+    fn sub(self, other: Vector<T>) -> Vector<T> {
         Vector {
             x: self.x - other.x,
             y: self.y - other.y,
         }
     }
 }
-impl std::ops::Div<Coord> for Vector {
-    type Output = Vector;
+impl<T: Float> std::ops::Div<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn div(self, rhs: Coord) -> Self::Output {
+    fn div(self, rhs: T) -> Self::Output {
         Vector {
             x: self.x / rhs,
             y: self.y / rhs,
@@ -145,10 +371,10 @@ impl std::ops::Div<Coord> for Vector {
     }
 }
 
-impl std::ops::Mul<Coord> for Vector {
-    type Output = Vector;
+impl<T: Float> std::ops::Mul<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, rhs: Coord) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Vector {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -156,8 +382,8 @@ impl std::ops::Mul<Coord> for Vector {
     }
 }
 
-impl std::ops::Neg for Vector {
-    type Output = Vector;
+impl<T: Float> std::ops::Neg for Vector<T> {
+    type Output = Vector<T>;
 
     fn neg(self) -> Self::Output {
         Vector {
@@ -168,13 +394,19 @@ impl std::ops::Neg for Vector {
 }
 
 #[derive(Debug, Clone)]
-pub struct Segment {
-    pub top: Point,
-    pub bottom: Point,
+pub struct Segment<T = Coord> {
+    pub top: Point<T>,
+    pub bottom: Point<T>,
 }
 
 impl Segment {
-    pub fn new(p1: Point, p2: Point) -> Self {
+    pub fn new_i(p1: (i32, i32), p2: (i32, i32)) -> Self {
+        Self::new(Point::new_i(p1.0, p1.1), Point::new_i(p2.0, p2.1))
+    }
+}
+
+impl<T: Float> Segment<T> {
+    pub fn new(p1: Point<T>, p2: Point<T>) -> Self {
         if p1 == p2 {
             panic!("Segment cannot have two identical points: {}", p1);
         }
@@ -192,11 +424,7 @@ impl Segment {
         }
     }
 
-    pub fn new_i(p1: (i32, i32), p2: (i32, i32)) -> Self {
-        Self::new(Point::new_i(p1.0, p1.1), Point::new_i(p2.0, p2.1))
-    }
-
-    pub fn new_f(p1: (f32, f32), p2: (f32, f32)) -> Self {
+    pub fn new_f(p1: (T, T), p2: (T, T)) -> Self {
         Self::new(Point::new(p1.0, p1.1), Point::new(p2.0, p2.1))
     }
 
@@ -208,7 +436,7 @@ impl Segment {
         self.bottom.x == self.top.x
     }
 
-    pub fn point_on_line_x(&self, y: Coord) -> Coord {
+    pub fn point_on_line_x(&self, y: T) -> T {
         if self.bottom.y == self.top.y {
             self.bottom.x
         } else {
@@ -218,7 +446,7 @@ impl Segment {
         }
     }
 
-    pub fn point_projection_factor(&self, p: Point) -> Coord {
+    pub fn point_projection_factor(&self, p: Point<T>) -> T {
         let numerator = (p.x - self.top.x) * (self.bottom.x - self.top.x)
             + (p.y - self.top.y) * (self.bottom.y - self.top.y);
         let denominator =
@@ -227,43 +455,69 @@ impl Segment {
         numerator / denominator
     }
 
-    pub fn point_on_line(&self, p: Point) -> bool {
+    /// Whether point `p`, collinear with the segment, lies on it.
+    ///
+    /// For collinear points it is enough to compare coordinates with the segment
+    /// bounding box, so the check is exact.
+    pub fn point_on_line(&self, p: Point<T>) -> bool {
+        let (min_x, max_x) = if self.bottom.x <= self.top.x {
+            (self.bottom.x, self.top.x)
+        } else {
+            (self.top.x, self.bottom.x)
+        };
+        self.bottom.y <= p.y && p.y <= self.top.y && min_x <= p.x && p.x <= max_x
+    }
+
+    /// Whether point `p` lies strictly to the left of the segment line,
+    /// looking from `bottom` to `top`.
+    ///
+    /// For horizontal segments `p` is compared with `bottom`, like
+    /// with [`Segment::point_on_line_x`].
+    pub fn is_point_left(&self, p: Point<T>) -> bool {
         if self.is_horizontal() {
-            return self.bottom.x <= p.x && p.x <= self.top.x;
+            p.x < self.bottom.x
+        } else {
+            orientation(self.bottom, self.top, p) == Orientation::CounterClockwise
         }
+    }
 
-        if self.is_vertical() {
-            return self.bottom.y <= p.y && p.y <= self.top.y;
+    /// Whether point `p` lies strictly to the right of the segment line,
+    /// looking from `bottom` to `top`.
+    ///
+    /// For horizontal segments `p` is compared with `bottom`, like
+    /// with [`Segment::point_on_line_x`].
+    pub fn is_point_right(&self, p: Point<T>) -> bool {
+        if self.is_horizontal() {
+            p.x > self.bottom.x
+        } else {
+            orientation(self.bottom, self.top, p) == Orientation::Clockwise
         }
-
-        let x_coord = self.point_on_line_x(p.y);
-        self.bottom.x <= x_coord && x_coord <= self.top.x
     }
 }
 
-impl fmt::Display for Segment {
+impl<T: Float> fmt::Display for Segment<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[bottom={}, top={}]", self.bottom, self.top)
     }
 }
 
-impl Hash for Segment {
+impl<T: Float> Hash for Segment<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.bottom.hash(state);
         self.top.hash(state);
     }
 }
 
-impl PartialEq for Segment {
+impl<T: Float> PartialEq for Segment<T> {
     fn eq(&self, other: &Self) -> bool {
         self.bottom == other.bottom && self.top == other.top
     }
 }
 
-impl Eq for Segment {}
+impl<T: Float> Eq for Segment<T> {}
 
 #[allow(clippy::non_canonical_partial_ord_impl)]
-impl PartialOrd for Segment {
+impl<T: Float> PartialOrd for Segment<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self.bottom == other.bottom {
             self.top.partial_cmp(&other.top)
@@ -273,7 +527,7 @@ impl PartialOrd for Segment {
     }
 }
 
-impl Ord for Segment {
+impl<T: Float> Ord for Segment<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.partial_cmp(other).unwrap()
     }
@@ -318,17 +572,19 @@ impl Triangle {
 }
 
 /// Represents a triangle using three points.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PointTriangle {
-    pub p1: Point,
-    pub p2: Point,
-    pub p3: Point,
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointTriangle<T = Coord> {
+    pub p1: Point<T>,
+    pub p2: Point<T>,
+    pub p3: Point<T>,
 }
 
-impl PointTriangle {
-    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+impl<T: Float> Eq for PointTriangle<T> {}
+
+impl<T: Float> PointTriangle<T> {
+    pub fn new(p1: Point<T>, p2: Point<T>, p3: Point<T>) -> Self {
         // Check if points are ordered counter-clockwise.
-        if (p2.x - p1.x) * (p3.y - p1.y) - (p2.y - p1.y) * (p3.x - p1.x) < 0.0 {
+        if (p2.x - p1.x) * (p3.y - p1.y) - (p2.y - p1.y) * (p3.x - p1.x) < T::ZERO {
             // Reorder points to be counter-clockwise.
             Self { p1: p3, p2, p3: p1 }
         } else {
@@ -344,7 +600,7 @@ impl PointTriangle {
 /// * `p2` - The second point.
 ///
 /// # Returns
-/// The distance between `p1` and `p2` as a `Coord`.
+/// The distance between `p1` and `p2`.
 ///
 /// # Examples
 /// ```
@@ -356,7 +612,7 @@ impl PointTriangle {
 /// let distance = vector_length(point1, point2);
 /// assert_eq!(distance, 5.0);
 /// ```
-pub fn vector_length(p1: Point, p2: Point) -> Coord {
+pub fn vector_length<T: Float>(p1: Point<T>, p2: Point<T>) -> T {
     let dx = p1.x - p2.x;
     let dy = p1.y - p2.y;
     (dx * dx + dy * dy).sqrt()
@@ -385,8 +641,8 @@ pub enum Orientation {
 ///
 /// # Note
 ///
-/// Due to floating-point arithmetic, the results may be sensitive to numerical precision
-/// when points are nearly collinear.
+/// The result is exact, also for nearly collinear points,
+/// as it is computed with the adaptive precision [`orient2d`] predicate.
 ///
 /// # Example
 ///
@@ -406,12 +662,11 @@ pub enum Orientation {
 /// assert_eq!(orientation(p, q, r_counterclockwise), Orientation::CounterClockwise); // Counterclockwise orientation
 ///
 /// ```
-pub const fn orientation(p: Point, q: Point, r: Point) -> Orientation {
-    let val1 = (q.y - p.y) * (r.x - q.x);
-    let val2 = (r.y - q.y) * (q.x - p.x);
-    if val1 == val2 {
+pub fn orientation<T: Float>(p: Point<T>, q: Point<T>, r: Point<T>) -> Orientation {
+    let det = orient2d(p, q, r);
+    if det == 0.0 {
         Orientation::Collinear
-    } else if val1 > val2 {
+    } else if det < 0.0 {
         Orientation::Clockwise
     } else {
         Orientation::CounterClockwise
@@ -434,6 +689,10 @@ pub const fn orientation(p: Point, q: Point, r: Point) -> Orientation {
 /// # Returns
 /// A `Vec<Segment>` containing all edges deduplicated modulo 2.
 ///
+/// # Errors
+/// Returns [`TriangulationError::TooFewPoints`] if any polygon has fewer than 3 points
+/// and [`TriangulationError::CollinearPolygon`] if all points of any polygon are collinear.
+///
 /// # Examples
 /// ```
 /// use triangulation::point::{Point, Segment, calc_dedup_edges};
@@ -452,30 +711,36 @@ pub const fn orientation(p: Point, q: Point, r: Point) -> Orientation {
 ///     Point::new(1.0, 0.0),
 /// ];
 ///
-/// let edges = calc_dedup_edges(&[polygon1, polygon2]);
+/// let edges = calc_dedup_edges(&[polygon1, polygon2]).unwrap();
 /// assert_eq!(edges.len(), 6); // Deduplicated edges
 /// ```
 #[inline]
-pub fn calc_dedup_edges(polygon_list: &[Vec<Point>]) -> Vec<Segment> {
+pub fn calc_dedup_edges<T: Float>(
+    polygon_list: &[Vec<Point<T>>],
+) -> Result<Vec<Segment<T>>, TriangulationError<T>> {
     for (i, polygon) in polygon_list.iter().enumerate() {
         if polygon.len() < 3 {
-            panic!("Polygon at index {} has fewer than 3 points", i);
+            return Err(TriangulationError::TooFewPoints {
+                polygon: i,
+                count: polygon.len(),
+            });
         }
         // Check for collinearity of all points
-        if polygon.len() >= 3 {
-            let first_orientation = orientation(polygon[polygon.len() - 1], polygon[0], polygon[1])
-                == Orientation::Collinear;
-            let last_orientation = orientation(
-                polygon[polygon.len() - 2],
-                polygon[polygon.len() - 1],
-                polygon[0],
-            ) == Orientation::Collinear;
-            let all_collinear = polygon.windows(3).all(|window| {
-                orientation(window[0], window[1], window[2]) == Orientation::Collinear
+        let first_orientation = orientation(polygon[polygon.len() - 1], polygon[0], polygon[1])
+            == Orientation::Collinear;
+        let last_orientation = orientation(
+            polygon[polygon.len() - 2],
+            polygon[polygon.len() - 1],
+            polygon[0],
+        ) == Orientation::Collinear;
+        let all_collinear = polygon
+            .windows(3)
+            .all(|window| orientation(window[0], window[1], window[2]) == Orientation::Collinear);
+        if first_orientation && last_orientation && all_collinear {
+            return Err(TriangulationError::CollinearPolygon {
+                polygon: i,
+                vertex: polygon[0],
             });
-            if first_orientation && last_orientation && all_collinear {
-                panic!("All points in polygon at index {} are collinear", i);
-            }
         }
     }
 
@@ -500,16 +765,16 @@ pub fn calc_dedup_edges(polygon_list: &[Vec<Point>]) -> Vec<Segment> {
             }
         }
     }
-    edges_set.into_iter().collect()
+    Ok(edges_set.into_iter().collect())
 }
 
-pub fn centroid(points: &[Point]) -> Point {
+pub fn centroid<T: Float>(points: &[Point<T>]) -> Point<T> {
     if points.is_empty() {
         panic!("Cannot calculate centroid of an empty points list");
     }
-    let sum = points.iter().fold(Point::new(0.0, 0.0), |acc, p| {
+    let sum = points.iter().fold(Point::new(T::ZERO, T::ZERO), |acc, p| {
         Point::new(acc.x + p.x, acc.y + p.y)
     });
-    let n = points.len() as f32;
+    let n = T::from_usize(points.len());
     Point::new(sum.x / n, sum.y / n)
 }
@@ -0,0 +1,205 @@
+//! Robust geometric predicates.
+//!
+//! Adaptive precision orientation test from J. R. Shewchuk, "Adaptive Precision
+//! Floating-Point Arithmetic and Fast Robust Geometric Predicates" (1997).
+//! The determinant is first evaluated with plain floating point arithmetic and
+//! recomputed with exact expansion arithmetic only when its sign is uncertain,
+//! so the sign is exact for all finite `f64` (and therefore `f32`) coordinates.
+
+use crate::point::{Float, Point};
+
+/// Half of the `f64` machine epsilon, the relative rounding error of a single operation.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const RESULT_ERR_BOUND: f64 = (3.0 + 8.0 * EPSILON) * EPSILON;
+const CCW_ERR_BOUND_A: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const CCW_ERR_BOUND_B: f64 = (2.0 + 12.0 * EPSILON) * EPSILON;
+const CCW_ERR_BOUND_C: f64 = (9.0 + 64.0 * EPSILON) * EPSILON * EPSILON;
+
+/// Returns a value with the exact sign of the determinant
+/// `(b - a) x (c - a)`: positive if `a`, `b`, `c` are in counterclockwise order,
+/// negative if they are in clockwise order and zero if they are collinear.
+///
+/// The magnitude is an approximation of twice the signed area of the triangle.
+///
+/// # Example
+/// ```
+/// use triangulation::predicates::orient2d;
+/// use triangulation::Point;
+///
+/// let a = Point::new(0.5_f64, 0.5);
+/// let b = Point::new(12.0, 12.0);
+/// // closest representable neighbour of the point (24, 24) on the line
+/// let c = Point::new(24.0, 24.000000000000004);
+/// assert!(orient2d(a, b, c) > 0.0);
+/// assert_eq!(orient2d(a, b, Point::new(24.0, 24.0)), 0.0);
+/// ```
+pub fn orient2d<T: Float>(a: Point<T>, b: Point<T>, c: Point<T>) -> f64 {
+    let (ax, ay) = (a.x.to_f64(), a.y.to_f64());
+    let (bx, by) = (b.x.to_f64(), b.y.to_f64());
+    let (cx, cy) = (c.x.to_f64(), c.y.to_f64());
+
+    let det_left = (ax - cx) * (by - cy);
+    let det_right = (ay - cy) * (bx - cx);
+    let det = det_left - det_right;
+
+    let det_sum = if det_left > 0.0 {
+        if det_right <= 0.0 {
+            return det;
+        }
+        det_left + det_right
+    } else if det_left < 0.0 {
+        if det_right >= 0.0 {
+            return det;
+        }
+        -det_left - det_right
+    } else {
+        return det;
+    };
+
+    let err_bound = CCW_ERR_BOUND_A * det_sum;
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+    orient2d_adapt([ax, ay], [bx, by], [cx, cy], det_sum)
+}
+
+/// Slow path of [`orient2d`], computing the determinant with increasing precision.
+fn orient2d_adapt(a: [f64; 2], b: [f64; 2], c: [f64; 2], det_sum: f64) -> f64 {
+    let acx = a[0] - c[0];
+    let bcx = b[0] - c[0];
+    let acy = a[1] - c[1];
+    let bcy = b[1] - c[1];
+
+    let b_exp = two_two_diff(two_product(acx, bcy), two_product(acy, bcx));
+    let mut det: f64 = b_exp.iter().sum();
+    let err_bound = CCW_ERR_BOUND_B * det_sum;
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+
+    let acx_tail = two_diff_tail(a[0], c[0], acx);
+    let bcx_tail = two_diff_tail(b[0], c[0], bcx);
+    let acy_tail = two_diff_tail(a[1], c[1], acy);
+    let bcy_tail = two_diff_tail(b[1], c[1], bcy);
+    if acx_tail == 0.0 && acy_tail == 0.0 && bcx_tail == 0.0 && bcy_tail == 0.0 {
+        return det;
+    }
+
+    let err_bound = CCW_ERR_BOUND_C * det_sum + RESULT_ERR_BOUND * det.abs();
+    det += (acx * bcy_tail + bcy * acx_tail) - (acy * bcx_tail + bcx * acy_tail);
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+
+    let u = two_two_diff(two_product(acx_tail, bcy), two_product(acy_tail, bcx));
+    let c1 = fast_expansion_sum_zero_elim(&b_exp, &u);
+    let u = two_two_diff(two_product(acx, bcy_tail), two_product(acy, bcx_tail));
+    let c2 = fast_expansion_sum_zero_elim(&c1, &u);
+    let u = two_two_diff(
+        two_product(acx_tail, bcy_tail),
+        two_product(acy_tail, bcx_tail),
+    );
+    let d = fast_expansion_sum_zero_elim(&c2, &u);
+    // the largest component of a nonoverlapping expansion has the sign of its sum
+    *d.last().unwrap()
+}
+
+/// Sum of `a` and `b` as a pair `(sum, error)`, requires `|a| >= |b|`.
+#[inline]
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    (x, b - (x - a))
+}
+
+/// Sum of `a` and `b` as a pair `(sum, error)`.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Rounding error of `x = a - b`.
+#[inline]
+fn two_diff_tail(a: f64, b: f64, x: f64) -> f64 {
+    let b_virtual = a - x;
+    let a_virtual = x + b_virtual;
+    (a - a_virtual) + (b_virtual - b)
+}
+
+/// Difference of `a` and `b` as a pair `(difference, error)`.
+#[inline]
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+    let x = a - b;
+    (x, two_diff_tail(a, b, x))
+}
+
+/// Product of `a` and `b` as a pair `(product, error)`.
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+/// Exact difference of two-component expansions `a - b`,
+/// as an expansion with components in increasing order of magnitude.
+#[inline]
+fn two_two_diff(a: (f64, f64), b: (f64, f64)) -> [f64; 4] {
+    let (a1, a0) = a;
+    let (b1, b0) = b;
+    let (i, x0) = two_diff(a0, b0);
+    let (j, r0) = two_sum(a1, i);
+    let (k, x1) = two_diff(r0, b1);
+    let (x3, x2) = two_sum(j, k);
+    [x0, x1, x2, x3]
+}
+
+/// Sum of two expansions, skipping zero components of the result.
+fn fast_expansion_sum_zero_elim(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + f.len());
+    let (mut e_index, mut f_index) = (0, 0);
+    let take_e = |e_index: usize, f_index: usize| match (e.get(e_index), f.get(f_index)) {
+        (Some(&e_now), Some(&f_now)) => (f_now > e_now) == (f_now > -e_now),
+        (Some(_), None) => true,
+        _ => false,
+    };
+
+    let mut q = if take_e(e_index, f_index) {
+        e_index += 1;
+        e[e_index - 1]
+    } else {
+        f_index += 1;
+        f[f_index - 1]
+    };
+    if e_index < e.len() && f_index < f.len() {
+        let (q_new, hh) = if take_e(e_index, f_index) {
+            e_index += 1;
+            fast_two_sum(e[e_index - 1], q)
+        } else {
+            f_index += 1;
+            fast_two_sum(f[f_index - 1], q)
+        };
+        q = q_new;
+        if hh != 0.0 {
+            h.push(hh);
+        }
+    }
+    while e_index < e.len() || f_index < f.len() {
+        let (q_new, hh) = if take_e(e_index, f_index) {
+            e_index += 1;
+            two_sum(q, e[e_index - 1])
+        } else {
+            f_index += 1;
+            two_sum(q, f[f_index - 1])
+        };
+        q = q_new;
+        if hh != 0.0 {
+            h.push(hh);
+        }
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
@@ -0,0 +1,225 @@
+//! Scanline rasterization of polygons into label images.
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::point::{Float, Point, Segment};
+
+/// Rule deciding which pixels are filled by rasterization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RasterMode {
+    /// Pixels whose center lies inside the polygons. Centers lying exactly on an
+    /// edge are filled only if the polygon is on their side of increasing
+    /// coordinates, so polygons sharing an edge do not fill the same pixels.
+    #[default]
+    PixelCenter,
+    /// Pixels which overlap the polygons by any nonzero area. Pixels which only
+    /// touch the boundary of the polygons at their own boundary are not filled.
+    AnyTouch,
+}
+
+/// Edges of the rings in `f64` coordinates, with pairs of identical edges removed
+/// as in [`calc_dedup_edges`](crate::point::calc_dedup_edges), each starting
+/// at the endpoint with the smaller `x`. Vertices with non-finite coordinates
+/// are skipped, joining their neighbors.
+fn dedup_edges<T: Float>(polygons: &[Vec<Point<T>>]) -> Vec<(Point<f64>, Point<f64>)> {
+    let mut edges = HashSet::new();
+    for ring in polygons {
+        let ring: Vec<Point<f64>> = ring
+            .iter()
+            .map(|p| p.cast::<f64>())
+            .filter(|p| p.x.is_finite() && p.y.is_finite())
+            .collect();
+        for (i, &p) in ring.iter().enumerate() {
+            let q = ring[(i + 1) % ring.len()];
+            if p == q {
+                continue;
+            }
+            let edge = Segment::new(p, q);
+            if !edges.remove(&edge) {
+                edges.insert(edge);
+            }
+        }
+    }
+    edges
+        .into_iter()
+        .map(|edge| {
+            if edge.bottom.x <= edge.top.x {
+                (edge.bottom, edge.top)
+            } else {
+                (edge.top, edge.bottom)
+            }
+        })
+        .collect()
+}
+
+/// Indices `start..end` of pixels, clipped to `0..len`.
+fn pixel_range(start: f64, end: f64, len: usize) -> Range<usize> {
+    let clip = |value: f64| value.clamp(0.0, len as f64) as usize;
+    let start = clip(start);
+    start..clip(end).max(start)
+}
+
+/// Coordinate `y` of the point of edge `p`, `q` at coordinate `x`.
+fn y_at(p: Point<f64>, q: Point<f64>, x: f64) -> f64 {
+    p.y + (x - p.x) * (q.y - p.y) / (q.x - p.x)
+}
+
+/// Pairs crossings of the polygon boundary with a line into filled intervals.
+fn crossing_intervals(mut crossings: Vec<f64>) -> impl Iterator<Item = (f64, f64)> {
+    crossings.sort_by(f64::total_cmp);
+    (0..crossings.len() / 2).map(move |i| (crossings[2 * i], crossings[2 * i + 1]))
+}
+
+/// Intervals of `y` filled at rows of pixel centers, as `[start, end)` ranges.
+fn pixel_center_intervals(edges: &[(Point<f64>, Point<f64>)], rows: usize) -> Vec<Vec<(f64, f64)>> {
+    let mut crossings = vec![Vec::new(); rows];
+    for &(p, q) in edges {
+        if p.x == q.x {
+            continue;
+        }
+        // rows at `p.x <= row < q.x`, so rows through a vertex count only one of its edges
+        for row in pixel_range(p.x.ceil(), q.x.ceil(), rows) {
+            crossings[row].push(y_at(p, q, row as f64));
+        }
+    }
+    crossings
+        .into_iter()
+        .map(|row| {
+            crossing_intervals(row)
+                .map(|(start, end)| (start.ceil(), end.ceil()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Intervals of `y` covered by the polygons in strips of pixel rows, as open ranges.
+///
+/// Every point of the polygons in a strip can be moved along `x` to one of
+/// the strip boundaries or to an edge, so the polygons cover the union of their
+/// cross sections at both boundaries and of the parts of edges inside the strip.
+fn any_touch_intervals(edges: &[(Point<f64>, Point<f64>)], rows: usize) -> Vec<Vec<(f64, f64)>> {
+    let mut lower = vec![Vec::new(); rows];
+    let mut upper = vec![Vec::new(); rows];
+    let mut intervals = vec![Vec::new(); rows];
+    for &(p, q) in edges {
+        // strips `(row - 0.5, row + 0.5)` overlapping the edge
+        for row in pixel_range((p.x - 0.5).floor() + 1.0, (q.x + 0.5).ceil(), rows) {
+            let (start, end) = (row as f64 - 0.5, row as f64 + 0.5);
+            let (a, b) = if p.x == q.x {
+                (p.y, q.y)
+            } else {
+                (y_at(p, q, p.x.max(start)), y_at(p, q, q.x.min(end)))
+            };
+            intervals[row].push((a.min(b), a.max(b)));
+            // cross sections just inside the strip at both of its boundaries
+            if p.x <= start && start < q.x {
+                lower[row].push(y_at(p, q, start));
+            }
+            if p.x < end && end <= q.x {
+                upper[row].push(y_at(p, q, end));
+            }
+        }
+    }
+    for (row, (lower, upper)) in lower.into_iter().zip(upper).enumerate() {
+        intervals[row].extend(crossing_intervals(lower));
+        intervals[row].extend(crossing_intervals(upper));
+    }
+    intervals
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|(start, end)| ((start - 0.5).floor() + 1.0, (end + 0.5).ceil()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Fills the pixels of `image` covered by a set of polygons with `label`.
+///
+/// Pixels follow the convention of [`label_contours`](crate::contour::label_contours):
+/// pixel `(row, column)` is `image[row * columns + column]`, a unit square centered
+/// at `Point::new(row, column)`. Rings are combined using the even-odd rule, with
+/// pairs of identical edges removed as in
+/// [`calc_dedup_edges`](crate::point::calc_dedup_edges), and may have any orientation.
+/// Vertices with NaN or infinite coordinates are ignored.
+/// Parts of the polygons outside of the image are ignored, and pixels not covered
+/// by the polygons keep their values.
+///
+/// # Arguments
+/// * `polygons` - Rings of the polygons, each as a list of points.
+/// * `label` - Value written to covered pixels.
+/// * `image` - Pixels of the image, in row-major order.
+/// * `shape` - Number of rows and columns of the image.
+/// * `mode` - Rule deciding which pixels are covered.
+///
+/// # Panics
+/// Panics if the length of `image` is not equal to the number of pixels.
+///
+/// # Example
+/// ```
+/// use triangulation::rasterize::{rasterize_polygons, RasterMode};
+/// use triangulation::Point;
+///
+/// let triangle = vec![Point::new(0.0, 0.0), Point::new(0.0, 3.0), Point::new(3.0, 0.0)];
+/// let mut image = [0u8; 9];
+/// rasterize_polygons(&[triangle], 1, &mut image, [3, 3], RasterMode::PixelCenter);
+/// assert_eq!(image, [1, 1, 1, 1, 1, 0, 1, 0, 0]);
+/// ```
+pub fn rasterize_polygons<L: Copy, T: Float>(
+    polygons: &[Vec<Point<T>>],
+    label: L,
+    image: &mut [L],
+    shape: [usize; 2],
+    mode: RasterMode,
+) {
+    let [rows, columns] = shape;
+    assert_eq!(
+        image.len(),
+        rows * columns,
+        "image does not match the image shape"
+    );
+    let edges = dedup_edges(polygons);
+    let intervals = match mode {
+        RasterMode::PixelCenter => pixel_center_intervals(&edges, rows),
+        RasterMode::AnyTouch => any_touch_intervals(&edges, rows),
+    };
+    for (row, intervals) in intervals.into_iter().enumerate() {
+        let pixels = &mut image[row * columns..(row + 1) * columns];
+        for (start, end) in intervals {
+            pixels[pixel_range(start, end, columns)].fill(label);
+        }
+    }
+}
+
+/// Fills the pixels of `image` covered by each shape with its label.
+///
+/// Each shape is rasterized as in [`rasterize_polygons`], in order,
+/// so later shapes overwrite pixels of earlier ones where they overlap.
+///
+/// # Arguments
+/// * `shapes` - List of shapes, each as a list of rings.
+/// * `labels` - Label of each shape.
+/// * `image` - Pixels of the image, in row-major order.
+/// * `shape` - Number of rows and columns of the image.
+/// * `mode` - Rule deciding which pixels are covered.
+///
+/// # Panics
+/// Panics if the number of labels is not equal to the number of shapes,
+/// or if the length of `image` is not equal to the number of pixels.
+pub fn rasterize_shapes<L: Copy, T: Float>(
+    shapes: &[Vec<Vec<Point<T>>>],
+    labels: &[L],
+    image: &mut [L],
+    shape: [usize; 2],
+    mode: RasterMode,
+) {
+    assert_eq!(
+        shapes.len(),
+        labels.len(),
+        "number of labels does not match the number of shapes"
+    );
+    for (polygons, &label) in shapes.iter().zip(labels) {
+        rasterize_polygons(polygons, label, image, shape, mode);
+    }
+}
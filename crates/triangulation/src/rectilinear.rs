@@ -0,0 +1,141 @@
+//! Triangulation of rectilinear polygons, with all edges parallel to the axes.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::error::TriangulationError;
+use crate::point::{Float, Index, Point, Triangle};
+
+type FaceTriangulation<T> = (Vec<Triangle>, Vec<Point<T>>);
+
+fn compare<T: Float>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap()
+}
+
+/// Toggles `y` in the sorted list of edges crossing the current slab,
+/// so edges repeated an even number of times cancel out.
+fn toggle<T: Float>(active: &mut Vec<T>, y: T) {
+    match active.binary_search_by(|probe| compare(probe, &y)) {
+        Ok(i) => {
+            active.remove(i);
+        }
+        Err(i) => active.insert(i, y),
+    }
+}
+
+/// Triangles and deduplicated vertices of a set of rectangles.
+#[derive(Default)]
+struct Rectangles<T: Float> {
+    triangles: Vec<Triangle>,
+    points: Vec<Point<T>>,
+    indices: HashMap<Point<T>, Index>,
+}
+
+impl<T: Float> Rectangles<T> {
+    fn index(&mut self, point: Point<T>) -> Index {
+        *self.indices.entry(point).or_insert_with(|| {
+            self.points.push(point);
+            self.points.len() - 1
+        })
+    }
+
+    /// Adds two counterclockwise triangles covering `[x0, x1] x [y0, y1]`.
+    fn push(&mut self, x0: T, x1: T, y0: T, y1: T) {
+        let a = self.index(Point::new(x0, y0));
+        let b = self.index(Point::new(x1, y0));
+        let c = self.index(Point::new(x1, y1));
+        let d = self.index(Point::new(x0, y1));
+        self.triangles.push(Triangle::new(a, b, c));
+        self.triangles.push(Triangle::new(a, c, d));
+    }
+}
+
+/// Triangulates a shape whose rings have only horizontal and vertical edges.
+///
+/// Rings are combined using the even-odd rule, like in
+/// [`triangulate_shape_face`](crate::face_triangulation::triangulate_shape_face),
+/// but instead of the general sweep line the shape is split into vertical slabs
+/// between consecutive `x` coordinates of its vertices, and equal intervals of
+/// neighboring slabs are merged into rectangles. Each rectangle is covered by two
+/// counterclockwise triangles, and vertices shared by rectangles are deduplicated.
+/// Rings may touch, overlap and have collinear or repeated vertices, which makes
+/// this suitable for boundaries from
+/// [`trace_label_boundaries`](crate::contour::trace_label_boundaries).
+///
+/// # Arguments
+/// * `shape` - Rings of the shape, each as a list of points.
+///
+/// # Returns
+/// A tuple of triangles and points, triangles refer to indices in returned points.
+///
+/// # Errors
+/// Returns [`TriangulationError::NotRectilinear`] for the first edge which is
+/// neither horizontal nor vertical.
+///
+/// # Example
+/// ```
+/// use triangulation::rectilinear::triangulate_rectilinear_face;
+/// use triangulation::Point;
+///
+/// let l_shape = vec![
+///     Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 1.0),
+///     Point::new(1.0, 1.0), Point::new(1.0, 2.0), Point::new(0.0, 2.0),
+/// ];
+/// let (triangles, points) = triangulate_rectilinear_face(&[l_shape]).unwrap();
+/// assert_eq!(triangles.len(), 4);
+/// assert_eq!(points.len(), 7);
+/// ```
+pub fn triangulate_rectilinear_face<T: Float>(
+    shape: &[Vec<Point<T>>],
+) -> Result<FaceTriangulation<T>, TriangulationError<T>> {
+    // horizontal edges, as (x, y) events at both of their ends
+    let mut events = Vec::new();
+    for (polygon, ring) in shape.iter().enumerate() {
+        for (i, &p) in ring.iter().enumerate() {
+            let q = ring[(i + 1) % ring.len()];
+            if p.x == q.x {
+                continue;
+            }
+            if p.y != q.y {
+                return Err(TriangulationError::NotRectilinear { polygon, vertex: p });
+            }
+            events.push((p.x, p.y));
+            events.push((q.x, q.y));
+        }
+    }
+    events.sort_by(|a, b| compare(&a.0, &b.0));
+
+    let mut rectangles = Rectangles::default();
+    let mut active = Vec::new();
+    // intervals of the previous slab, with the x coordinate at which they started
+    let mut open: Vec<(T, T, T)> = Vec::new();
+    let mut events = events.into_iter().peekable();
+    while let Some(&(x, _)) = events.peek() {
+        while let Some((_, y)) = events.next_if(|event| event.0 == x) {
+            toggle(&mut active, y);
+        }
+        let mut next_open = Vec::with_capacity(active.len() / 2);
+        let mut previous = open.iter().peekable();
+        for interval in active.chunks_exact(2) {
+            let (y0, y1) = (interval[0], interval[1]);
+            let mut start = x;
+            while let Some(&&(p0, p1, p_start)) = previous.peek() {
+                if p0 > y0 || (p0 == y0 && p1 > y1) {
+                    break;
+                }
+                previous.next();
+                if p0 == y0 && p1 == y1 {
+                    start = p_start;
+                } else {
+                    rectangles.push(p_start, x, p0, p1);
+                }
+            }
+            next_open.push((y0, y1, start));
+        }
+        for &(p0, p1, p_start) in previous {
+            rectangles.push(p_start, x, p0, p1);
+        }
+        open = next_open;
+    }
+    Ok((rectangles.triangles, rectangles.points))
+}
@@ -0,0 +1,408 @@
+//! Simplification of paths and polygon rings, removing vertices which
+//! do not change their shape by more than a tolerance.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::intersection::find_intersections;
+use crate::point::{Float, Point, Segment};
+
+/// Algorithm deciding which vertices are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimplifyMethod {
+    /// Douglas–Peucker, keeps vertices farther than the tolerance
+    /// from the simplified path.
+    #[default]
+    DouglasPeucker,
+    /// Visvalingam–Whyatt, repeatedly removes the vertex forming the smallest
+    /// triangle with its neighbors, while the triangle area does not exceed the tolerance.
+    VisvalingamWhyatt,
+}
+
+/// Parameters of path simplification.
+///
+/// # Fields
+/// * `method` - Algorithm deciding which vertices are removed.
+/// * `preserve_topology` - If removed vertices are restored where simplified
+///   edges would cross each other, so simplified paths intersect only where
+///   the input paths do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SimplifyStyle {
+    pub method: SimplifyMethod,
+    pub preserve_topology: bool,
+}
+
+impl SimplifyStyle {
+    pub fn new(method: SimplifyMethod) -> Self {
+        SimplifyStyle {
+            method,
+            preserve_topology: false,
+        }
+    }
+
+    pub fn with_preserve_topology(self, preserve_topology: bool) -> Self {
+        SimplifyStyle {
+            preserve_topology,
+            ..self
+        }
+    }
+}
+
+/// Squared distance of `p` from the segment between `a` and `b`.
+fn distance_squared<T: Float>(p: Point<T>, a: Point<T>, b: Point<T>) -> T {
+    let (edge, diff) = (b - a, p - a);
+    let length = edge.x * edge.x + edge.y * edge.y;
+    let t = if length > T::ZERO {
+        ((diff.x * edge.x + diff.y * edge.y) / length).clamp(T::ZERO, T::ONE)
+    } else {
+        T::ZERO
+    };
+    let offset = diff - edge * t;
+    offset.x * offset.x + offset.y * offset.y
+}
+
+fn triangle_area<T: Float>(a: Point<T>, b: Point<T>, c: Point<T>) -> T {
+    let (ab, ac) = (b - a, c - a);
+    (ab.x * ac.y - ab.y * ac.x).abs() / T::TWO
+}
+
+/// Path with flags of vertices kept by simplification.
+///
+/// Vertex indices of closed paths wrap around, so edges and ranges
+/// of removed vertices may pass over the first vertex.
+struct Simplified<'a, T: Float> {
+    points: &'a [Point<T>],
+    closed: bool,
+    keep: Vec<bool>,
+}
+
+impl<'a, T: Float> Simplified<'a, T> {
+    fn point(&self, index: usize) -> Point<T> {
+        self.points[index % self.points.len()]
+    }
+
+    /// Vertex between `start` and `end` farthest from the segment joining them,
+    /// with its squared distance.
+    fn farthest(&self, start: usize, end: usize) -> Option<(usize, T)> {
+        let (a, b) = (self.point(start), self.point(end));
+        (start + 1..end)
+            .map(|k| (k, distance_squared(self.point(k), a, b)))
+            .fold(None, |best, (k, distance)| match best {
+                Some((_, best_distance)) if best_distance >= distance => best,
+                _ => Some((k, distance)),
+            })
+    }
+
+    /// Keep vertices between `start` and `end` farther than `tolerance` from the path.
+    fn douglas_peucker(&mut self, start: usize, end: usize, tolerance: T) {
+        let n = self.points.len();
+        let mut ranges = vec![(start, end)];
+        while let Some((start, end)) = ranges.pop() {
+            match self.farthest(start, end) {
+                Some((k, distance)) if distance > tolerance * tolerance => {
+                    self.keep[k % n] = true;
+                    ranges.push((start, k));
+                    ranges.push((k, end));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visvalingam_whyatt(&mut self, tolerance: T) {
+        let n = self.points.len();
+        let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+        let mut areas = vec![T::ZERO; n];
+        let mut heap = BinaryHeap::new();
+        let candidates = if self.closed { 0..n } else { 1..n - 1 };
+        for i in candidates {
+            areas[i] = triangle_area(self.point(prev[i]), self.point(i), self.point(next[i]));
+            heap.push(Candidate::new(areas[i], i));
+        }
+        let mut count = n;
+        let minimum = if self.closed { 3 } else { 2 };
+        while let Some(Candidate { area, index }) = heap.pop() {
+            if !self.keep[index] || area != areas[index] {
+                // removed vertex, or area changed after removing a neighbor
+                continue;
+            }
+            if count <= minimum || area > tolerance {
+                break;
+            }
+            self.keep[index] = false;
+            count -= 1;
+            let (before, after) = (prev[index], next[index]);
+            next[before] = after;
+            prev[after] = before;
+            for i in [before, after] {
+                if !self.closed && (i == 0 || i == n - 1) {
+                    continue;
+                }
+                // area never decreases, so vertices are removed in order of significance
+                let new_area =
+                    triangle_area(self.point(prev[i]), self.point(i), self.point(next[i]));
+                areas[i] = new_area.max(area);
+                heap.push(Candidate::new(areas[i], i));
+            }
+        }
+    }
+
+    /// Edges of the simplified path, as pairs of vertex indices.
+    fn edges(&self) -> Vec<(usize, usize)> {
+        let n = self.points.len();
+        let kept: Vec<usize> = (0..n).filter(|&i| self.keep[i]).collect();
+        let mut edges: Vec<(usize, usize)> = kept.windows(2).map(|w| (w[0], w[1])).collect();
+        if self.closed && kept.len() > 1 {
+            edges.push((kept[kept.len() - 1], kept[0] + n));
+        }
+        edges
+    }
+
+    fn into_points(self) -> Vec<Point<T>> {
+        self.points
+            .iter()
+            .zip(self.keep)
+            .filter_map(|(p, keep)| keep.then_some(*p))
+            .collect()
+    }
+}
+
+/// Candidate vertex for removal, ordered so that the smallest area is popped first.
+struct Candidate<T: Float> {
+    area: T,
+    index: usize,
+}
+
+impl<T: Float> Candidate<T> {
+    fn new(area: T, index: usize) -> Self {
+        Candidate { area, index }
+    }
+}
+
+impl<T: Float> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for Candidate<T> {}
+
+impl<T: Float> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .to_f64()
+            .total_cmp(&self.area.to_f64())
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+fn simplify<'a, T: Float>(
+    points: &'a [Point<T>],
+    closed: bool,
+    tolerance: T,
+    method: SimplifyMethod,
+) -> Simplified<'a, T> {
+    let n = points.len();
+    let minimum = if closed { 3 } else { 2 };
+    if n <= minimum {
+        return Simplified {
+            points,
+            closed,
+            keep: vec![true; n],
+        };
+    }
+    match method {
+        SimplifyMethod::DouglasPeucker => {
+            let mut result = Simplified {
+                points,
+                closed,
+                keep: vec![false; n],
+            };
+            result.keep[0] = true;
+            if closed {
+                // split the ring at the vertex farthest from the first one
+                let distance = |k: usize| distance_squared(points[k], points[0], points[0]);
+                let far = (1..n)
+                    .max_by(|&i, &j| {
+                        distance(i)
+                            .partial_cmp(&distance(j))
+                            .unwrap_or(Ordering::Equal)
+                    })
+                    .unwrap();
+                result.keep[far] = true;
+                result.douglas_peucker(0, far, tolerance);
+                result.douglas_peucker(far, n, tolerance);
+                if result.keep.iter().filter(|&&keep| keep).count() < 3 {
+                    // keep a triangle instead of a degenerate ring
+                    let third = [result.farthest(0, far), result.farthest(far, n)]
+                        .into_iter()
+                        .flatten()
+                        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+                    if let Some((k, _)) = third {
+                        result.keep[k % n] = true;
+                    }
+                }
+            } else {
+                result.keep[n - 1] = true;
+                result.douglas_peucker(0, n - 1, tolerance);
+            }
+            result
+        }
+        SimplifyMethod::VisvalingamWhyatt => {
+            let mut result = Simplified {
+                points,
+                closed,
+                keep: vec![true; n],
+            };
+            result.visvalingam_whyatt(tolerance);
+            result
+        }
+    }
+}
+
+/// Restores removed vertices until simplified edges of all paths
+/// intersect only where original edges do.
+fn restore_topology<T: Float>(paths: &mut [Simplified<'_, T>]) {
+    loop {
+        let edges: Vec<(usize, usize, usize)> = paths
+            .iter()
+            .enumerate()
+            .flat_map(|(path, simplified)| {
+                simplified
+                    .edges()
+                    .into_iter()
+                    .map(move |(start, end)| (path, start, end))
+            })
+            .collect();
+        let segments: Vec<Segment<T>> = edges
+            .iter()
+            .map(|&(path, start, end)| {
+                Segment::new(paths[path].point(start), paths[path].point(end))
+            })
+            .collect();
+        let mut restored = false;
+        for pair in find_intersections(&segments) {
+            for edge in [pair.first(), pair.second()] {
+                let (path, start, end) = edges[edge];
+                if let Some((k, _)) = paths[path].farthest(start, end) {
+                    let n = paths[path].points.len();
+                    restored |= !paths[path].keep[k % n];
+                    paths[path].keep[k % n] = true;
+                }
+            }
+        }
+        if !restored {
+            return;
+        }
+    }
+}
+
+/// Removes consecutive repeated points and, for closed paths,
+/// the closing point equal to the first one.
+fn dedup_path<T: Float>(path: &[Point<T>], closed: bool) -> Vec<Point<T>> {
+    let mut path = path.to_vec();
+    path.dedup();
+    while closed && path.len() > 1 && path.first() == path.last() {
+        path.pop();
+    }
+    path
+}
+
+/// Simplifies a path, removing vertices which do not change its shape by more
+/// than `tolerance`.
+///
+/// For [`SimplifyMethod::DouglasPeucker`] `tolerance` is the largest distance of a removed
+/// vertex from the simplified path, for [`SimplifyMethod::VisvalingamWhyatt`] it is the
+/// largest area of a triangle, formed by a vertex and its neighbors, for which the vertex
+/// is removed.
+/// Ends of open paths are always kept, closed paths keep at least three vertices.
+///
+/// # Arguments
+/// * `path` - Vertices of the path
+/// * `closed` - If the path is closed (last point is connected with the first one)
+/// * `tolerance` - Largest allowed change of the path
+/// * `style` - Simplification method and whether to preserve topology
+///
+/// # Returns
+/// Kept vertices of the path, in their original order. Repeated points are removed.
+///
+/// # Example
+/// ```
+/// use triangulation::simplify::{simplify_path, SimplifyStyle};
+/// use triangulation::Point;
+///
+/// let path = [Point::new(0.0, 0.0), Point::new(1.0, 0.1), Point::new(2.0, -0.1), Point::new(3.0, 0.0)];
+/// let simplified = simplify_path(&path, false, 0.5, &SimplifyStyle::default());
+/// assert_eq!(simplified, vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0)]);
+/// ```
+pub fn simplify_path<T: Float>(
+    path: &[Point<T>],
+    closed: bool,
+    tolerance: T,
+    style: &SimplifyStyle,
+) -> Vec<Point<T>> {
+    let path = dedup_path(path, closed);
+    let mut simplified = [simplify(&path, closed, tolerance, style.method)];
+    if style.preserve_topology {
+        restore_topology(&mut simplified);
+    }
+    let [simplified] = simplified;
+    simplified.into_points()
+}
+
+/// Simplifies closed rings of polygons, like [`simplify_path`].
+///
+/// With `style.preserve_topology` simplified rings cross neither themselves nor each other,
+/// unless the input rings do, so outer rings and holes stay valid input for triangulation.
+///
+/// # Arguments
+/// * `polygons` - Rings of the polygons
+/// * `tolerance` - Largest allowed change of the rings
+/// * `style` - Simplification method and whether to preserve topology
+///
+/// # Returns
+/// Simplified rings, one for each input ring.
+///
+/// # Example
+/// ```
+/// use triangulation::simplify::{simplify_polygons, SimplifyMethod, SimplifyStyle};
+/// use triangulation::Point;
+///
+/// // square with a vertex slightly off each edge
+/// let ring = vec![
+///     Point::new(0.0, 0.0), Point::new(2.0, 0.1), Point::new(4.0, 0.0), Point::new(3.9, 2.0),
+///     Point::new(4.0, 4.0), Point::new(2.0, 4.1), Point::new(0.0, 4.0), Point::new(0.1, 2.0),
+/// ];
+/// let style = SimplifyStyle::new(SimplifyMethod::VisvalingamWhyatt);
+/// let simplified = simplify_polygons(&[ring], 1.0, &style);
+/// assert_eq!(simplified[0].len(), 4);
+/// ```
+pub fn simplify_polygons<T: Float>(
+    polygons: &[impl AsRef<[Point<T>]>],
+    tolerance: T,
+    style: &SimplifyStyle,
+) -> Vec<Vec<Point<T>>> {
+    let rings: Vec<Vec<Point<T>>> = polygons
+        .iter()
+        .map(|ring| dedup_path(ring.as_ref(), true))
+        .collect();
+    let mut simplified: Vec<Simplified<'_, T>> = rings
+        .iter()
+        .map(|ring| simplify(ring, true, tolerance, style.method))
+        .collect();
+    if style.preserve_topology {
+        restore_topology(&mut simplified);
+    }
+    simplified
+        .into_iter()
+        .map(Simplified::into_points)
+        .collect()
+}
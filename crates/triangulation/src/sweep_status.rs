@@ -0,0 +1,281 @@
+//! Ordered sequence of active segments of a sweep line algorithm.
+//!
+//! The order of segments on the sweep line is not described by a key,
+//! so the sequence is stored in a treap ordered only by position.
+//! Items are positioned relative to other items or searched with a predicate
+//! that is monotone along the sequence.
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone)]
+struct Node {
+    priority: u64,
+    left: usize,
+    right: usize,
+    parent: usize,
+    active: bool,
+}
+
+/// Sequence of distinct items `0..capacity` with `O(log n)` insertion,
+/// removal, neighbor lookup and search.
+pub(crate) struct SweepStatus {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+impl SweepStatus {
+    pub fn new(capacity: usize) -> Self {
+        // fixed seed keeps the result deterministic
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let nodes = (0..capacity)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                Node {
+                    priority: state,
+                    left: NIL,
+                    right: NIL,
+                    parent: NIL,
+                    active: false,
+                }
+            })
+            .collect();
+        Self { nodes, root: NIL }
+    }
+
+    pub fn contains(&self, item: usize) -> bool {
+        self.nodes[item].active
+    }
+
+    pub fn last(&self) -> Option<usize> {
+        (self.root != NIL).then(|| self.rightmost(self.root))
+    }
+
+    pub fn next(&self, item: usize) -> Option<usize> {
+        let node = &self.nodes[item];
+        if node.right != NIL {
+            return Some(self.leftmost(node.right));
+        }
+        let mut current = item;
+        let mut parent = node.parent;
+        while parent != NIL && self.nodes[parent].right == current {
+            current = parent;
+            parent = self.nodes[parent].parent;
+        }
+        (parent != NIL).then_some(parent)
+    }
+
+    pub fn prev(&self, item: usize) -> Option<usize> {
+        let node = &self.nodes[item];
+        if node.left != NIL {
+            return Some(self.rightmost(node.left));
+        }
+        let mut current = item;
+        let mut parent = node.parent;
+        while parent != NIL && self.nodes[parent].left == current {
+            current = parent;
+            parent = self.nodes[parent].parent;
+        }
+        (parent != NIL).then_some(parent)
+    }
+
+    /// First item for which `is_before` returns `false`,
+    /// `is_before` has to be `true` for a prefix of the sequence only.
+    pub fn partition_point(&self, mut is_before: impl FnMut(usize) -> bool) -> Option<usize> {
+        let mut result = None;
+        let mut current = self.root;
+        while current != NIL {
+            if is_before(current) {
+                current = self.nodes[current].right;
+            } else {
+                result = Some(current);
+                current = self.nodes[current].left;
+            }
+        }
+        result
+    }
+
+    /// Insert `item` before `position`, or at the end if `position` is `None`.
+    pub fn insert_before(&mut self, item: usize, position: Option<usize>) {
+        debug_assert!(
+            !self.nodes[item].active,
+            "item {item} is already in the sequence"
+        );
+        let node = &mut self.nodes[item];
+        node.active = true;
+        node.left = NIL;
+        node.right = NIL;
+        node.parent = NIL;
+
+        if self.root == NIL {
+            self.root = item;
+            return;
+        }
+        match position {
+            Some(position) if self.nodes[position].left == NIL => {
+                self.nodes[position].left = item;
+                self.nodes[item].parent = position;
+            }
+            Some(position) => {
+                let parent = self.rightmost(self.nodes[position].left);
+                self.nodes[parent].right = item;
+                self.nodes[item].parent = parent;
+            }
+            None => {
+                let parent = self.rightmost(self.root);
+                self.nodes[parent].right = item;
+                self.nodes[item].parent = parent;
+            }
+        }
+        while self.nodes[item].parent != NIL
+            && self.nodes[self.nodes[item].parent].priority < self.nodes[item].priority
+        {
+            self.rotate_up(item);
+        }
+    }
+
+    pub fn insert_after(&mut self, item: usize, position: usize) {
+        let next = self.next(position);
+        self.insert_before(item, next);
+    }
+
+    pub fn remove(&mut self, item: usize) {
+        debug_assert!(
+            self.nodes[item].active,
+            "item {item} is not in the sequence"
+        );
+        loop {
+            let Node { left, right, .. } = self.nodes[item];
+            let child = match (left, right) {
+                (NIL, NIL) => break,
+                (child, NIL) | (NIL, child) => child,
+                (left, right) if self.nodes[left].priority > self.nodes[right].priority => left,
+                (_, right) => right,
+            };
+            self.rotate_up(child);
+        }
+        let parent = self.nodes[item].parent;
+        if parent == NIL {
+            self.root = NIL;
+        } else if self.nodes[parent].left == item {
+            self.nodes[parent].left = NIL;
+        } else {
+            self.nodes[parent].right = NIL;
+        }
+        let node = &mut self.nodes[item];
+        node.active = false;
+        node.parent = NIL;
+    }
+
+    fn leftmost(&self, mut item: usize) -> usize {
+        while self.nodes[item].left != NIL {
+            item = self.nodes[item].left;
+        }
+        item
+    }
+
+    fn rightmost(&self, mut item: usize) -> usize {
+        while self.nodes[item].right != NIL {
+            item = self.nodes[item].right;
+        }
+        item
+    }
+
+    /// Rotate `item` above its parent, keeping the order of the sequence.
+    fn rotate_up(&mut self, item: usize) {
+        let parent = self.nodes[item].parent;
+        let grandparent = self.nodes[parent].parent;
+        if self.nodes[parent].left == item {
+            let moved = self.nodes[item].right;
+            self.nodes[parent].left = moved;
+            if moved != NIL {
+                self.nodes[moved].parent = parent;
+            }
+            self.nodes[item].right = parent;
+        } else {
+            let moved = self.nodes[item].left;
+            self.nodes[parent].right = moved;
+            if moved != NIL {
+                self.nodes[moved].parent = parent;
+            }
+            self.nodes[item].left = parent;
+        }
+        self.nodes[parent].parent = item;
+        self.nodes[item].parent = grandparent;
+        if grandparent == NIL {
+            self.root = item;
+        } else if self.nodes[grandparent].left == parent {
+            self.nodes[grandparent].left = item;
+        } else {
+            self.nodes[grandparent].right = item;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn to_vec(status: &SweepStatus) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut current = status.partition_point(|_| false);
+        while let Some(item) = current {
+            result.push(item);
+            current = status.next(item);
+        }
+        let mut reversed = Vec::new();
+        let mut current = status.last();
+        while let Some(item) = current {
+            reversed.push(item);
+            current = status.prev(item);
+        }
+        reversed.reverse();
+        assert_eq!(result, reversed);
+        result
+    }
+
+    #[rstest]
+    fn test_sweep_status_matches_vec() {
+        let mut status = SweepStatus::new(64);
+        let mut expected: Vec<usize> = Vec::new();
+        let mut state: u64 = 12345;
+        for step in 0..2000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let item = (state >> 33) as usize % 64;
+            if status.contains(item) {
+                status.remove(item);
+                expected.retain(|&x| x != item);
+            } else if expected.is_empty() || step % 3 == 0 {
+                status.insert_before(item, None);
+                expected.push(item);
+            } else {
+                let index = (state >> 20) as usize % expected.len();
+                status.insert_before(item, Some(expected[index]));
+                expected.insert(index, item);
+            }
+            assert_eq!(to_vec(&status), expected);
+        }
+    }
+
+    #[rstest]
+    fn test_sweep_status_partition_point() {
+        let mut status = SweepStatus::new(10);
+        for item in [3, 1, 4, 0, 9] {
+            status.insert_before(item, None);
+        }
+        let order = to_vec(&status);
+        for split in 0..=order.len() {
+            let position = |item| order.iter().position(|&x| x == item).unwrap();
+            assert_eq!(
+                status.partition_point(|item| position(item) < split),
+                order.get(split).copied()
+            );
+        }
+        status.insert_after(7, 1);
+        assert_eq!(to_vec(&status), vec![3, 1, 7, 4, 0, 9]);
+    }
+}
@@ -1,15 +1,43 @@
 from bermuda._bermuda import (
+    TriangulationError,
+    label_contours,
+    offset_polygons,
+    polygon_boolean,
+    rasterize_polygons,
+    rasterize_shapes,
+    simplify_polygons,
     split_polygons_on_repeated_edges,
+    trace_label_boundaries,
     triangulate_path_edge,
+    triangulate_path_edge_dashed,
+    triangulate_path_ribbon_3d,
+    triangulate_path_tube_3d,
+    triangulate_paths_edge,
     triangulate_polygons_face,
     triangulate_polygons_face_3d,
     triangulate_polygons_with_edge,
+    triangulate_labels_face,
+    triangulate_shapes_face,
 )
 
 __all__ = (
+    'TriangulationError',
+    'label_contours',
+    'offset_polygons',
+    'polygon_boolean',
+    'rasterize_polygons',
+    'rasterize_shapes',
+    'simplify_polygons',
     'split_polygons_on_repeated_edges',
+    'trace_label_boundaries',
     'triangulate_path_edge',
+    'triangulate_path_edge_dashed',
+    'triangulate_path_ribbon_3d',
+    'triangulate_path_tube_3d',
+    'triangulate_paths_edge',
     'triangulate_polygons_face',
     'triangulate_polygons_face_3d',
     'triangulate_polygons_with_edge',
+    'triangulate_labels_face',
+    'triangulate_shapes_face',
 )
@@ -1,43 +1,237 @@
-from typing import Literal
+from collections.abc import Sequence
+from typing import Literal, TypeVar, overload
 
 import numpy as np
 import numpy.typing as npt
 
+CapStyle = Literal['butt', 'square', 'round']
+JoinStyle = Literal['miter', 'bevel', 'round']
+FillRule = Literal['evenodd', 'nonzero', 'positive', 'negative']
+TriangulationQuality = Literal['fast', 'delaunay', 'refined']
+BooleanOperation = Literal['union', 'intersection', 'difference', 'xor']
+SimplifyMethod = Literal['douglas-peucker', 'visvalingam-whyatt']
+RasterMode = Literal['pixel-center', 'any-touch']
+# Results keep the dtype of coordinates, mixed inputs are promoted to float64
+FloatT = TypeVar('FloatT', np.float32, np.float64)
+LabelT = TypeVar('LabelT', bound=np.bool_ | np.integer)
+
+class TriangulationError(ValueError):
+    shape_index: int | None
+    polygon_index: int | None
+    vertex: list[float] | None
+
+@overload
 def triangulate_path_edge(
-    path: npt.NDArray[tuple[int, Literal[2]], np.float32],
+    path: npt.NDArray[tuple[int, Literal[2]], FloatT],
     closed: bool = False,
     limit: float = 3.0,
     bevel: bool = False,
+    cap: CapStyle = 'butt',
+    join: JoinStyle | None = None,
+    width: npt.NDArray[tuple[int], np.float32 | np.float64] | None = None,
+    attributes: Literal[False] = False,
+    out: tuple[
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
+        npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    ]
+    | None = None,
 ) -> tuple[
-    npt.NDArray[tuple[int, Literal[2]], np.float32],
-    npt.NDArray[tuple[int, Literal[2]], np.float32],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+]: ...
+@overload
+def triangulate_path_edge(
+    path: npt.NDArray[tuple[int, Literal[2]], FloatT],
+    closed: bool = False,
+    limit: float = 3.0,
+    bevel: bool = False,
+    cap: CapStyle = 'butt',
+    join: JoinStyle | None = None,
+    width: npt.NDArray[tuple[int], np.float32 | np.float64] | None = None,
+    *,
+    attributes: Literal[True],
+    out: tuple[
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
+        npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    ]
+    | None = None,
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int], FloatT],
+    npt.NDArray[tuple[int], FloatT],
+]: ...
+def triangulate_path_edge_dashed(
+    path: npt.NDArray[tuple[int, Literal[2]], FloatT],
+    dash: Sequence[float],
+    dash_phase: float = 0.0,
+    closed: bool = False,
+    limit: float = 3.0,
+    cap: CapStyle = 'butt',
+    join: JoinStyle = 'miter',
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int], FloatT],
+]: ...
+def triangulate_path_ribbon_3d(
+    path: npt.NDArray[tuple[int, Literal[3]], FloatT],
+    closed: bool = False,
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[3]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int], FloatT],
+]: ...
+def triangulate_path_tube_3d(
+    path: npt.NDArray[tuple[int, Literal[3]], FloatT],
+    closed: bool = False,
+    segments: int = 8,
+    limit: float = 3.0,
+    caps: bool = False,
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[3]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], FloatT],
     npt.NDArray[tuple[int, Literal[3]], np.uint32],
 ]: ...
 def triangulate_polygons_with_edge(
-    polygons: list[npt.NDArray[tuple[int, Literal[2]], np.float32]],
+    polygons: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    fill_rule: FillRule = 'evenodd',
+    quality: TriangulationQuality = 'fast',
+    min_angle: float | None = None,
+    max_area: float | None = None,
 ) -> tuple[
     tuple[
-        npt.NDArray[tuple[int, Literal[2]], np.float32],
-        npt.NDArray[tuple[int, Literal[2]], np.float32],
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
         npt.NDArray[tuple[int, Literal[3]], np.uint32],
     ],
     tuple[
         npt.NDArray[tuple[int, Literal[3]], np.uint32],
-        npt.NDArray[tuple[int, Literal[2]], np.float32],
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
     ],
 ]: ...
 def triangulate_polygons_face(
-    polygons: list[npt.NDArray[tuple[int, Literal[2]], np.float32]],
+    polygons: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    fill_rule: FillRule = 'evenodd',
+    quality: TriangulationQuality = 'fast',
+    min_angle: float | None = None,
+    max_area: float | None = None,
+    out: tuple[
+        npt.NDArray[tuple[int, Literal[3]], np.uint32],
+        npt.NDArray[tuple[int, Literal[2]], FloatT],
+    ]
+    | None = None,
 ) -> tuple[
     npt.NDArray[tuple[int, Literal[3]], np.uint32],
-    npt.NDArray[tuple[int, Literal[2]], np.float32],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
 ]: ...
 def triangulate_polygons_face_3d(
-    polygons: list[npt.NDArray[tuple[int, Literal[3]], np.float32]],
+    polygons: list[npt.NDArray[tuple[int, Literal[3]], FloatT]],
+    tolerance: float | None = None,
 ) -> tuple[
     npt.NDArray[tuple[int, Literal[3]], np.uint32],
-    npt.NDArray[tuple[int, Literal[3]], np.float32],
+    npt.NDArray[tuple[int, Literal[3]], FloatT],
 ]: ...
 def split_polygons_on_repeated_edges(
-    polygons: list[npt.NDArray[tuple[int, Literal[2]], np.float32]],
-) -> list[npt.NDArray[tuple[int, Literal[2]], np.float32]]: ...
+    polygons: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+) -> list[npt.NDArray[tuple[int, Literal[2]], FloatT]]: ...
+def polygon_boolean(
+    subject: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    clip: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    operation: BooleanOperation,
+) -> list[npt.NDArray[tuple[int, Literal[2]], FloatT]]: ...
+def offset_polygons(
+    polygons: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    distance: float,
+    join: JoinStyle | None = None,
+    limit: float = 3.0,
+) -> list[npt.NDArray[tuple[int, Literal[2]], FloatT]]: ...
+def simplify_polygons(
+    polygons: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    tolerance: float,
+    method: SimplifyMethod | None = None,
+    preserve_topology: bool = False,
+) -> list[npt.NDArray[tuple[int, Literal[2]], FloatT]]: ...
+def triangulate_shapes_face(
+    shapes: list[
+        npt.NDArray[tuple[int, Literal[2]], FloatT]
+        | list[npt.NDArray[tuple[int, Literal[2]], FloatT]]
+    ],
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[2]], np.uint32],
+]: ...
+def label_contours(
+    labels: npt.NDArray[tuple[int, int], LabelT],
+    background: int | bool | None = None,
+) -> dict[int | bool, list[npt.NDArray[tuple[int, Literal[2]], np.float32]]]: ...
+def rasterize_polygons(
+    polygons: list[npt.NDArray[tuple[int, Literal[2]], np.float32 | np.float64]],
+    image: npt.NDArray[tuple[int, int], np.bool_ | np.integer],
+    label: int | bool,
+    mode: RasterMode | None = None,
+) -> None: ...
+def rasterize_shapes(
+    shapes: list[
+        npt.NDArray[tuple[int, Literal[2]], np.float32 | np.float64]
+        | list[npt.NDArray[tuple[int, Literal[2]], np.float32 | np.float64]]
+    ],
+    labels: Sequence[int | bool] | npt.NDArray[tuple[int], np.bool_ | np.integer],
+    image: npt.NDArray[tuple[int, int], np.bool_ | np.integer],
+    mode: RasterMode | None = None,
+) -> None: ...
+def trace_label_boundaries(
+    labels: npt.NDArray[tuple[int, int], LabelT],
+    background: int | bool | None = None,
+) -> dict[int | bool, list[npt.NDArray[tuple[int, Literal[2]], np.float32]]]: ...
+def triangulate_labels_face(
+    labels: npt.NDArray[tuple[int, int], LabelT],
+    background: int | bool | None = None,
+    exact: bool = False,
+) -> tuple[
+    npt.NDArray[tuple[int], LabelT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int, Literal[2]], np.float32],
+    npt.NDArray[tuple[int, Literal[2]], np.uint32],
+]: ...
+@overload
+def triangulate_paths_edge(
+    paths: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    closed: bool | Sequence[bool] = False,
+    limit: float | Sequence[float] = 3.0,
+    bevel: bool | Sequence[bool] = False,
+    cap: CapStyle | Sequence[CapStyle] = 'butt',
+    join: JoinStyle | Sequence[JoinStyle] | None = None,
+    attributes: Literal[False] = False,
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int, Literal[2]], np.uint32],
+]: ...
+@overload
+def triangulate_paths_edge(
+    paths: list[npt.NDArray[tuple[int, Literal[2]], FloatT]],
+    closed: bool | Sequence[bool] = False,
+    limit: float | Sequence[float] = 3.0,
+    bevel: bool | Sequence[bool] = False,
+    cap: CapStyle | Sequence[CapStyle] = 'butt',
+    join: JoinStyle | Sequence[JoinStyle] | None = None,
+    *,
+    attributes: Literal[True],
+) -> tuple[
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[2]], FloatT],
+    npt.NDArray[tuple[int, Literal[3]], np.uint32],
+    npt.NDArray[tuple[int, Literal[2]], np.uint32],
+    npt.NDArray[tuple[int], FloatT],
+    npt.NDArray[tuple[int], FloatT],
+]: ...
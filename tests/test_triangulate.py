@@ -1,11 +1,25 @@
 import numpy as np
 import pytest
 from bermuda import (
+    TriangulationError,
+    label_contours,
+    offset_polygons,
+    polygon_boolean,
+    rasterize_polygons,
+    rasterize_shapes,
+    simplify_polygons,
     split_polygons_on_repeated_edges,
+    trace_label_boundaries,
     triangulate_path_edge,
+    triangulate_path_edge_dashed,
+    triangulate_path_ribbon_3d,
+    triangulate_path_tube_3d,
+    triangulate_paths_edge,
     triangulate_polygons_face,
     triangulate_polygons_face_3d,
     triangulate_polygons_with_edge,
+    triangulate_labels_face,
+    triangulate_shapes_face,
 )
 
 
@@ -205,6 +219,314 @@ def test_change_limit():
     assert len(triangles) == 12
 
 
+def test_square_cap():
+    centers, offsets, triangles = triangulate_path_edge(
+        np.array([[0, 0], [10, 0]], dtype='float32'), cap='square'
+    )
+    assert len(triangles) == 2
+    vertices = centers + 2 * offsets
+    np.testing.assert_allclose(vertices[:, 0], [-1, -1, 11, 11])
+
+
+def test_round_cap():
+    centers, offsets, triangles = triangulate_path_edge(
+        np.array([[0, 0], [10, 0]], dtype='float32'), cap='round'
+    )
+    assert len(triangles) > 2
+    lengths = np.linalg.norm(offsets, axis=1)
+    assert np.all(np.isclose(lengths, 0) | np.isclose(lengths, 0.5))
+
+
+def test_unknown_cap():
+    with pytest.raises(ValueError, match='cap style'):
+        triangulate_path_edge(
+            np.array([[0, 0], [10, 0]], dtype='float32'), cap='arrow'
+        )
+
+
+@pytest.mark.parametrize(
+    ('join', 'bevel', 'expected'),
+    [
+        (None, False, 8),
+        (None, True, 12),
+        ('miter', True, 8),
+        ('bevel', False, 12),
+        ('round', False, 24),
+    ],
+)
+def test_join_style(join, bevel, expected):
+    _centers, _offsets, triangles = triangulate_path_edge(
+        np.array([[0, 0], [0, 10], [10, 10], [10, 0]], dtype='float32'),
+        closed=True,
+        bevel=bevel,
+        join=join,
+    )
+    assert len(triangles) == expected
+
+
+def test_unknown_join():
+    with pytest.raises(ValueError, match='join style'):
+        triangulate_path_edge(
+            np.array([[0, 0], [10, 0], [10, 10]], dtype='float32'),
+            join='arc',
+        )
+
+
+def test_variable_width():
+    path = np.array([[0, 0], [10, 0], [20, 0]], dtype='float32')
+    centers, offsets, _triangles = triangulate_path_edge(
+        path, width=np.array([2, 4, 6], dtype='float32')
+    )
+    np.testing.assert_allclose(np.abs(offsets[:, 1]), [1, 1, 2, 2, 3, 3])
+    np.testing.assert_array_equal(centers[:, 0], [0, 0, 10, 10, 20, 20])
+
+
+def test_variable_width_wrong_length():
+    path = np.array([[0, 0], [10, 0], [20, 0]], dtype='float32')
+    with pytest.raises(ValueError, match='width'):
+        triangulate_path_edge(path, width=np.array([1, 2], dtype='float32'))
+
+
+def test_dashed_path():
+    path = np.array([[0, 0], [10, 0]], dtype='float32')
+    centers, offsets, triangles, arc_lengths = triangulate_path_edge_dashed(
+        path, dash=[2, 1]
+    )
+    assert centers.shape == offsets.shape == (16, 2)
+    assert len(triangles) == 8
+    np.testing.assert_array_equal(arc_lengths[:4], [0, 0, 2, 2])
+    np.testing.assert_array_equal(centers[:, 0], arc_lengths)
+
+
+def test_dotted_path():
+    path = np.array([[0, 0], [10, 0]], dtype='float32')
+    _centers, _offsets, triangles, _arc_lengths = triangulate_path_edge_dashed(
+        path, dash=[0, 2], cap='round'
+    )
+    assert len(triangles) > 0
+
+
+def test_dashed_path_negative_length():
+    path = np.array([[0, 0], [10, 0]], dtype='float32')
+    with pytest.raises(ValueError, match='non-negative'):
+        triangulate_path_edge_dashed(path, dash=[2, -1])
+
+
+
+
+@pytest.mark.parametrize('dtype', [np.float32, np.float64])
+def test_path_edge_out(dtype):
+    path = np.array([[0, 0], [0, 10], [10, 10]], dtype=dtype)
+    expected = triangulate_path_edge(path)
+    out = (
+        np.zeros((100, 2), dtype=dtype),
+        np.zeros((100, 2), dtype=dtype),
+        np.zeros((100, 3), dtype=np.uint32),
+    )
+    result = triangulate_path_edge(path, out=out)
+    for res, exp, buffer in zip(result, expected, out):
+        np.testing.assert_array_equal(res, exp)
+        # results are views of the preallocated arrays
+        assert np.shares_memory(res, buffer)
+
+
+@pytest.mark.parametrize(
+    ('out', 'match'),
+    [
+        ((np.zeros((1, 2)), np.zeros((100, 2)), np.zeros((100, 3))), 'n >= '),
+        (
+            (
+                np.zeros((100, 2), dtype=np.float32),
+                np.zeros((100, 2)),
+                np.zeros((100, 3), dtype=np.uint32),
+            ),
+            'dtype float64',
+        ),
+        (
+            (
+                np.zeros((100, 2)),
+                np.zeros((100, 2))[:, ::-1],
+                np.zeros((100, 3), dtype=np.uint32),
+            ),
+            'C-contiguous',
+        ),
+    ],
+)
+def test_path_edge_out_invalid(out, match):
+    path = np.array([[0, 0], [0, 10], [10, 10]], dtype=np.float64)
+    with pytest.raises(ValueError, match=match):
+        triangulate_path_edge(path, out=out)
+
+
+def test_path_edge_out_read_only():
+    path = np.array([[0, 0], [0, 10]], dtype=np.float32)
+    centers = np.zeros((10, 2), dtype=np.float32)
+    centers.flags.writeable = False
+    out = (centers, np.zeros_like(centers), np.zeros((10, 3), dtype=np.uint32))
+    with pytest.raises(ValueError, match='not writeable'):
+        triangulate_path_edge(path, out=out)
+
+
+def test_triangulate_polygons_face_out():
+    polygons = [
+        np.array([(0, 0), (10, 0), (10, 10), (0, 10)], dtype=np.float32),
+        np.array([(4, 4), (6, 4), (6, 6), (4, 6)], dtype=np.float32),
+    ]
+    triangles, points = triangulate_polygons_face(polygons)
+    out = (
+        np.zeros((20, 3), dtype=np.uint32),
+        np.zeros((20, 2), dtype=np.float32),
+    )
+    out_triangles, out_points = triangulate_polygons_face(polygons, out=out)
+    np.testing.assert_array_equal(out_triangles, triangles)
+    np.testing.assert_array_equal(out_points, points)
+    assert out_points.base is out[1]
+
+
+@pytest.mark.parametrize('with_hole', [False, True])
+def test_triangulate_polygons_face_repeated_vertices(with_hole):
+    # rings with repeated vertices are copied to remove them, others are borrowed
+    square = np.array([(0, 0), (10, 0), (10, 10), (0, 10)], dtype=np.float32)
+    repeated = np.array(
+        [(0, 0), (10, 0), (10, 0), (10, 10), (0, 10)], dtype=np.float32
+    )
+    holes = (
+        [np.array([(4, 4), (6, 4), (6, 6), (4, 6)], dtype=np.float32)]
+        if with_hole
+        else []
+    )
+    expected = triangulate_polygons_face([square, *holes])
+    result = triangulate_polygons_face([repeated, *holes])
+    for res, exp in zip(result, expected):
+        np.testing.assert_array_equal(res, exp)
+    assert len(repeated) == 5
+
+
+def test_non_contiguous_input():
+    # strided arrays are copied instead of borrowed
+    path = np.array([[0, 0, 0], [0, 10, 0], [10, 10, 0]], dtype=np.float32)
+    expected = triangulate_path_edge(np.ascontiguousarray(path[:, :2]))
+    result = triangulate_path_edge(path[:, :2])
+    for res, exp in zip(result, expected):
+        np.testing.assert_array_equal(res, exp)
+
+@pytest.mark.parametrize('dtype', [np.float32, np.float64])
+def test_path_ribbon_3d(dtype):
+    path = np.array([[0, 0, 0], [2, 0, 0], [2, 2, 0], [2, 2, 2]], dtype=dtype)
+    centers, tangents, normals, triangles, sides = triangulate_path_ribbon_3d(
+        path
+    )
+    assert centers.dtype == tangents.dtype == normals.dtype == dtype
+    assert centers.shape == tangents.shape == normals.shape == (8, 3)
+    assert triangles.shape == (6, 3)
+    np.testing.assert_array_equal(centers, np.repeat(path, 2, axis=0))
+    np.testing.assert_array_equal(sides, [-1, 1] * 4)
+    np.testing.assert_allclose(np.linalg.norm(tangents, axis=1), 1, rtol=1e-6)
+    np.testing.assert_allclose(
+        np.sum(tangents * normals, axis=1), 0, atol=1e-6
+    )
+
+
+def test_path_ribbon_3d_closed():
+    path = np.array([[0, 0, 0], [1, 0, 0], [1, 1, 0]], dtype=np.float32)
+    *_, triangles, _sides = triangulate_path_ribbon_3d(path, closed=True)
+    assert len(triangles) == 6
+
+
+@pytest.mark.parametrize('dtype', [np.float32, np.float64])
+def test_path_tube_3d(dtype):
+    path = np.array([[0, 0, 0], [0, 0, 5]], dtype=dtype)
+    centers, offsets, triangles = triangulate_path_tube_3d(path, segments=6)
+    assert centers.dtype == offsets.dtype == dtype
+    assert centers.shape == offsets.shape == (12, 3)
+    assert triangles.shape == (12, 3)
+    np.testing.assert_allclose(np.linalg.norm(offsets, axis=1), 1, rtol=1e-6)
+    np.testing.assert_array_equal(offsets[:, 2], 0)
+
+
+def test_path_tube_3d_caps():
+    path = np.array([[0, 0, 0], [0, 0, 5], [0, 5, 5]], dtype=np.float32)
+    _, _, triangles = triangulate_path_tube_3d(path, segments=6)
+    _, _, capped = triangulate_path_tube_3d(path, segments=6, caps=True)
+    assert len(capped) == len(triangles) + 2 * 6
+    # caps are ignored for closed paths
+    _, _, closed = triangulate_path_tube_3d(path, closed=True, caps=True)
+    assert len(closed) == 3 * 2 * 8
+
+def test_triangulate_paths_edge():
+    path = np.array([[0, 0], [0, 10], [10, 10], [10, 0]], dtype='float32')
+    centers, offsets, triangles, path_ranges = triangulate_paths_edge(
+        [path, path, path],
+        closed=[True, False, True],
+        bevel=[False, False, True],
+    )
+    assert path_ranges.tolist() == [[0, 10], [10, 18], [18, 32]]
+    assert centers.shape == offsets.shape == (32, 2)
+    assert len(triangles) == 8 + 6 + 12
+    for closed, bevel, (start, stop) in zip(
+        [True, False, True], [False, False, True], path_ranges
+    ):
+        exp_centers, exp_offsets, _ = triangulate_path_edge(
+            path, closed=closed, bevel=bevel
+        )
+        np.testing.assert_array_equal(centers[start:stop], exp_centers)
+        np.testing.assert_array_equal(offsets[start:stop], exp_offsets)
+
+
+def test_triangulate_paths_edge_default_values():
+    path = np.array([[0, 0], [0, 10], [10, 10], [10, 0]], dtype='float32')
+    _centers, _offsets, triangles, path_ranges = triangulate_paths_edge(
+        [path, path]
+    )
+    assert path_ranges.tolist() == [[0, 8], [8, 16]]
+    assert len(triangles) == 12
+
+
+def test_triangulate_paths_edge_cap():
+    path = np.array([[0, 0], [10, 0]], dtype='float32')
+    _centers, _offsets, triangles, path_ranges = triangulate_paths_edge(
+        [path, path], cap=['butt', 'square']
+    )
+    assert path_ranges.tolist() == [[0, 4], [4, 8]]
+    assert len(triangles) == 4
+
+
+def test_triangulate_paths_edge_join():
+    path = np.array([[0, 0], [0, 10], [10, 10], [10, 0]], dtype='float32')
+    _centers, _offsets, triangles, _path_ranges = triangulate_paths_edge(
+        [path, path], closed=True, join=['miter', 'round']
+    )
+    assert len(triangles) == 8 + 24
+
+
+def test_path_attributes():
+    path = np.array([[0, 0], [10, 0], [10, 10]], dtype='float32')
+    centers, offsets, _triangles, arc_lengths, sides = triangulate_path_edge(
+        path, attributes=True
+    )
+    np.testing.assert_array_equal(arc_lengths, [0, 0, 10, 10, 20, 20])
+    # left side of the path is above the first segment
+    np.testing.assert_array_equal(sides, [-1, 1, -1, 1, -1, 1])
+    assert len(centers) == len(offsets) == len(sides)
+
+
+def test_triangulate_paths_edge_attributes():
+    path = np.array([[0, 0], [0, 10]], dtype='float32')
+    *_, path_ranges, arc_lengths, sides = triangulate_paths_edge(
+        [path, path], attributes=True
+    )
+    np.testing.assert_array_equal(path_ranges, [[0, 4], [4, 8]])
+    np.testing.assert_array_equal(arc_lengths, [0, 0, 10, 10] * 2)
+    np.testing.assert_array_equal(sides, [-1, 1, -1, 1] * 2)
+
+
+
+def test_triangulate_paths_edge_wrong_length():
+    path = np.array([[0, 0], [0, 10], [10, 10]], dtype='float32')
+    with pytest.raises(ValueError, match='closed'):
+        triangulate_paths_edge([path, path], closed=[True])
+
+
 TEST_POLYGONS = [
     (np.array(x, dtype=np.float32), y)
     for x, y in [
@@ -283,6 +605,43 @@ def test_triangulate_polygons_face_with_edge_non_convex_3d(
     assert triangles_ == expected
 
 
+def _tilted_square_with_hole(dtype):
+    origin = np.array([10, -5, 3], dtype=np.float64)
+    u = np.array([0.6, 0, 0.8])
+    v = np.array([0, 1, 0])
+    rings = [
+        [(0, 0), (4, 0), (4, 4), (0, 4)],
+        [(1, 1), (3, 1), (3, 3), (1, 3)],
+    ]
+    return [
+        np.array([origin + s * u + t * v for s, t in ring], dtype=dtype)
+        for ring in rings
+    ]
+
+
+@pytest.mark.parametrize('dtype', [np.float32, np.float64])
+def test_triangulate_polygons_face_3d_tilted(dtype):
+    polygons = _tilted_square_with_hole(dtype)
+    triangles, points = triangulate_polygons_face_3d(polygons)
+    assert points.dtype == dtype
+    assert len(triangles) == 8
+    # original vertices are returned unchanged
+    vertices = {tuple(p) for polygon in polygons for p in polygon}
+    assert {tuple(p) for p in points} == vertices
+    normal = np.array([0.8, 0, -0.6])
+    distances = (points.astype(np.float64) - polygons[0][0]) @ normal
+    np.testing.assert_allclose(distances, 0, atol=1e-5)
+
+
+def test_triangulate_polygons_face_3d_not_planar():
+    polygons = _tilted_square_with_hole(np.float64)
+    polygons[1][2, 2] += 0.5
+    with pytest.raises(TriangulationError, match='not planar'):
+        triangulate_polygons_face_3d(polygons)
+    triangles, _ = triangulate_polygons_face_3d(polygons, tolerance=0.5)
+    assert len(triangles) == 8
+
+
 def test_triangulate_polygon_in_polygon_numpy():
     polygons = [
         np.array([(0, 0), (10, 0), (10, 10), (0, 10)], dtype=np.float32),
@@ -314,6 +673,119 @@ def test_triangulate_polygon_with_hole():
     assert len(triangles) == 8
 
 
+
+def _triangles_area(triangles, points):
+    a, b, c = (points[triangles[:, i]].astype(np.float64) for i in range(3))
+    cross = (b[:, 0] - a[:, 0]) * (c[:, 1] - a[:, 1]) - (b[:, 1] - a[:, 1]) * (
+        c[:, 0] - a[:, 0]
+    )
+    return np.abs(cross).sum() / 2
+
+
+@pytest.mark.parametrize(
+    ('fill_rule', 'area'),
+    [
+        (None, 6),
+        ('evenodd', 6),
+        ('nonzero', 7),
+        ('positive', 7),
+        ('negative', 0),
+    ],
+)
+def test_triangulate_polygons_face_fill_rule(fill_rule, area):
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    kwargs = {} if fill_rule is None else {'fill_rule': fill_rule}
+    triangles, points = triangulate_polygons_face([square, square + 1], **kwargs)
+    assert _triangles_area(triangles, points) == area
+    (triangles, points), _ = triangulate_polygons_with_edge(
+        [square, square + 1], **kwargs
+    )
+    assert _triangles_area(triangles, points) == area
+
+
+def test_unknown_fill_rule():
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    with pytest.raises(ValueError, match='Unknown fill rule'):
+        triangulate_polygons_face([square], fill_rule='winding')
+
+
+@pytest.mark.parametrize('dtype', [np.float32, np.float64])
+def test_triangulate_polygons_face_delaunay(dtype):
+    angles = np.linspace(0, 2 * np.pi, 32, endpoint=False)
+    ellipse = np.stack([10 * np.cos(angles), 5 * np.sin(angles)], axis=1).astype(dtype)
+    hole = ellipse / 4
+    fast_triangles, fast_points = triangulate_polygons_face([ellipse, hole])
+    triangles, points = triangulate_polygons_face(
+        [ellipse, hole], quality='delaunay'
+    )
+    assert points.dtype == dtype
+    assert len(triangles) == len(fast_triangles)
+    assert np.isclose(
+        _triangles_area(triangles, points),
+        _triangles_area(fast_triangles, fast_points),
+    )
+    (triangles, points), _ = triangulate_polygons_with_edge(
+        [ellipse], quality='delaunay'
+    )
+    assert len(triangles) == len(ellipse) - 2
+
+
+def _min_angle(triangles, points):
+    corners = points[triangles].astype(np.float64)
+    angles = []
+    for k in range(3):
+        u = corners[:, (k + 1) % 3] - corners[:, k]
+        v = corners[:, (k + 2) % 3] - corners[:, k]
+        cross = u[:, 0] * v[:, 1] - u[:, 1] * v[:, 0]
+        angles.append(np.degrees(np.arctan2(np.abs(cross), (u * v).sum(axis=1))))
+    return np.min(angles)
+
+
+def test_triangulate_polygons_face_refined():
+    rectangle = np.array([(0, 0), (20, 0), (20, 1), (0, 1)], dtype=np.float32)
+    triangles, points = triangulate_polygons_face(
+        [rectangle], min_angle=25, max_area=1
+    )
+    assert len(points) > len(rectangle)
+    np.testing.assert_array_equal(points[: len(rectangle)], rectangle)
+    assert np.isclose(_triangles_area(triangles, points), 20)
+    assert _min_angle(triangles, points) >= 25 - 1e-2
+    (triangles, points), _ = triangulate_polygons_with_edge(
+        [rectangle], quality='refined'
+    )
+    assert _min_angle(triangles, points) >= 20 - 1e-2
+
+
+def test_refinement_requires_refined_quality():
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    with pytest.raises(ValueError, match="quality='refined'"):
+        triangulate_polygons_face([square], quality='delaunay', max_area=1)
+
+
+@pytest.mark.parametrize(
+    ('min_angle', 'max_area', 'match'),
+    [
+        (None, 0, 'max_area'),
+        (None, -1, 'max_area'),
+        (None, float('inf'), 'max_area'),
+        (None, float('nan'), 'max_area'),
+        (float('nan'), None, 'min_angle'),
+        (float('inf'), 1, 'min_angle'),
+    ],
+)
+def test_invalid_refinement_bounds(min_angle, max_area, match):
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    with pytest.raises(ValueError, match=match):
+        triangulate_polygons_face(
+            [square], min_angle=min_angle, max_area=max_area
+        )
+
+
+def test_unknown_quality():
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    with pytest.raises(ValueError, match='Unknown quality'):
+        triangulate_polygons_face([square], quality='best')
+
 def test_triangulate_polygon_segfault1():
     """Test on polygon that lead to segfault during test"""
     polygon = np.array(
@@ -520,11 +992,352 @@ def test_edge_triangulate_segment_closed():
     assert len(edge_triangles) == 6
 
 
+def test_edge_triangulate_empty_path():
+    (edge_centers, edge_offsets, edge_triangles) = triangulate_path_edge(
+        np.zeros((0, 2), dtype=np.float32)
+    )
+    assert len(edge_centers) == 0
+    assert len(edge_offsets) == 0
+    assert len(edge_triangles) == 0
+
+
+def _triangles_area(points, triangles):
+    p1, p2, p3 = (points[triangles[:, i]] for i in range(3))
+    cross = (p2[:, 0] - p1[:, 0]) * (p3[:, 1] - p1[:, 1]) - (
+        p2[:, 1] - p1[:, 1]
+    ) * (p3[:, 0] - p1[:, 0])
+    return np.abs(cross).sum() / 2
+
+
+def test_triangulate_shapes_face():
+    square1 = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    square2 = square1 + 1
+    outer = np.array([(0, 0), (10, 0), (10, 10), (0, 10)], dtype=np.float32)
+    hole = np.array([(4, 4), (6, 4), (6, 6), (4, 6)], dtype=np.float32)
+    triangles, points, offsets = triangulate_shapes_face(
+        [square1, [square2], [outer, hole]]
+    )
+    assert offsets.tolist() == [[0, 0], [4, 2], [8, 4], [16, 12]]
+    assert len(points) == 16
+    assert len(triangles) == 12
+    for i, expected_area in enumerate([4, 4, 96]):
+        shape_triangles = triangles[offsets[i, 1] : offsets[i + 1, 1]]
+        assert np.all(shape_triangles >= offsets[i, 0])
+        assert np.all(shape_triangles < offsets[i + 1, 0])
+        assert _triangles_area(points, shape_triangles) == expected_area
+
+
+def test_triangulate_shapes_face_error_in_shape():
+    squares = [
+        np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32) + (3 * i, 0)
+        for i in range(5)
+    ]
+    invalid = np.array(
+        [(0, 0), (4, 0), (2, 1), (np.inf, 1), (0, 4)], dtype=np.float32
+    )
+    shapes = [[square] for square in squares]
+    shapes[2].append(invalid)
+    with pytest.raises(TriangulationError, match='Shape at index 2') as exc_info:
+        triangulate_shapes_face(shapes)
+    assert exc_info.value.shape_index == 2
+    assert exc_info.value.polygon_index == 1
+    assert exc_info.value.vertex == [np.inf, 1]
+
+
+def test_triangulate_shapes_face_empty():
+    triangles, points, offsets = triangulate_shapes_face([])
+    assert triangles.shape == (0, 3)
+    assert points.shape == (0, 2)
+    assert offsets.tolist() == [[0, 0]]
+
+
+@pytest.mark.parametrize('dtype', [np.uint8, np.int32, np.int64, np.uint64])
+def test_label_contours(dtype):
+    labels = np.zeros((6, 7), dtype=dtype)
+    labels[1:5, 1:5] = 1
+    labels[2, 2] = 0
+    labels[1:3, 6] = 3
+    contours = label_contours(labels)
+    assert sorted(contours) == [1, 3]
+    areas = sorted(_signed_area(ring) for ring in contours[1])
+    assert areas == [-0.5, 15.5]
+    assert contours[1][0].dtype == np.float32
+    assert len(contours[3]) == 1
+    # points are in (row, column) order
+    assert contours[3][0][:, 1].min() == 5.5
+
+
+def test_label_contours_mask_and_background():
+    mask = np.zeros((4, 4), dtype=bool)
+    mask[1:3, 1:3] = True
+    contours = label_contours(mask[:, ::-1])
+    assert list(contours) == [True]
+    assert _signed_area(contours[True][0]) == 3.5
+    contours = label_contours(np.where(mask, 0, 5).astype(np.int16), background=5)
+    assert list(contours) == [0]
+
+
+def test_triangulate_labels_face():
+    labels = np.zeros((6, 7), dtype=np.int32)
+    labels[1:5, 1:5] = 1
+    labels[2, 2] = 0
+    labels[1:3, 6] = 3
+    label_values, triangles, points, offsets = triangulate_labels_face(labels)
+    assert label_values.tolist() == [1, 3]
+    assert label_values.dtype == np.int32
+    assert points.dtype == np.float32
+    assert len(offsets) == 3
+    for i, expected_area in enumerate([15, 1.5]):
+        shape_triangles = triangles[offsets[i, 1] : offsets[i + 1, 1]]
+        assert _triangles_area(points, shape_triangles) == expected_area
+
+
+def test_trace_label_boundaries():
+    labels = np.zeros((6, 7), dtype=np.uint16)
+    labels[1:5, 1:5] = 1
+    labels[2, 2] = 0
+    labels[1:3, 6] = 3
+    boundaries = trace_label_boundaries(labels)
+    assert sorted(boundaries) == [1, 3]
+    areas = sorted(_signed_area(ring) for ring in boundaries[1])
+    assert areas == [-1, 16]
+    assert boundaries[1][1].shape == (4, 2)
+    assert boundaries[3][0][:, 1].min() == 5.5
+
+
+def test_triangulate_labels_face_exact():
+    labels = np.zeros((6, 7), dtype=np.int32)
+    labels[1:5, 1:5] = 1
+    labels[2, 2] = 0
+    labels[1:3, 6] = 3
+    label_values, triangles, points, offsets = triangulate_labels_face(
+        labels, exact=True
+    )
+    assert label_values.tolist() == [1, 3]
+    for i, expected_area in enumerate([15, 2]):
+        shape_triangles = triangles[offsets[i, 1] : offsets[i + 1, 1]]
+        assert _triangles_area(points, shape_triangles) == expected_area
+
+
+@pytest.mark.parametrize(
+    ('mode', 'expected'),
+    [
+        (None, [[1, 1, 1], [1, 1, 0], [1, 0, 0]]),
+        ('pixel-center', [[1, 1, 1], [1, 1, 0], [1, 0, 0]]),
+        ('any-touch', [[1, 1, 1], [1, 1, 1], [1, 1, 0]]),
+    ],
+)
+def test_rasterize_polygons(mode, expected):
+    triangle = np.array([(0, 0), (0, 3), (3, 0)], dtype=np.float32)
+    image = np.zeros((3, 3), dtype=np.uint8)
+    rasterize_polygons([triangle], image, 1, mode=mode)
+    assert image.tolist() == expected
+
+
+def test_rasterize_polygons_hole_and_view():
+    outer = np.array([(-0.5, -0.5), (3.5, -0.5), (3.5, 3.5), (-0.5, 3.5)])
+    hole = np.array([(0.5, 0.5), (2.5, 0.5), (2.5, 2.5), (0.5, 2.5)])
+    image = np.full((6, 4), 9, dtype=np.int64)
+    # rows of a C-contiguous array are contiguous as well
+    rasterize_polygons([outer, hole], image[1:5], -1)
+    assert (image[0] == 9).all()
+    assert (image[5] == 9).all()
+    assert (image[1:5] == -1).sum() == 12
+    assert image[2:4, 1:3].tolist() == [[9, 9], [9, 9]]
+    with pytest.raises(ValueError, match='C-contiguous'):
+        rasterize_polygons([outer], image[:, :2], 1)
+
+
+def test_rasterize_shapes_roundtrip():
+    labels = np.zeros((6, 7), dtype=np.uint16)
+    labels[1:5, 1:5] = 1
+    labels[2, 2] = 0
+    labels[1:3, 6] = 3
+    boundaries = trace_label_boundaries(labels)
+    image = np.zeros_like(labels)
+    rasterize_shapes(list(boundaries.values()), list(boundaries), image)
+    np.testing.assert_array_equal(image, labels)
+    with pytest.raises(ValueError, match='labels'):
+        rasterize_shapes(list(boundaries.values()), [1], image)
+
+
+def test_triangulation_error_is_value_error():
+    assert issubclass(TriangulationError, ValueError)
+
+
+@pytest.mark.parametrize('value', [np.nan, np.inf])
+def test_triangulation_error_attributes(value):
+    square = np.array([(10, 10), (11, 10), (11, 11), (10, 11)], dtype=np.float32)
+    polygon = np.array(
+        [(0, 0), (4, 0), (2, 1), (value, 1), (0, 4)], dtype=np.float32
+    )
+    with pytest.raises(TriangulationError, match='non-finite') as exc_info:
+        triangulate_polygons_face([square, polygon])
+    assert isinstance(exc_info.value, ValueError)
+    assert exc_info.value.polygon_index == 1
+    np.testing.assert_array_equal(exc_info.value.vertex, [value, 1])
+
+
+@pytest.mark.parametrize('dtype', [np.float32, np.float64])
+def test_coordinates_keep_dtype(dtype):
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=dtype)
+    centers, offsets, _ = triangulate_path_edge(square, closed=True)
+    assert centers.dtype == dtype
+    assert offsets.dtype == dtype
+    *arrays, arc_lengths = triangulate_path_edge_dashed(square, [1, 1])
+    assert arrays[0].dtype == dtype
+    assert arc_lengths.dtype == dtype
+    centers, *_ = triangulate_paths_edge([square, square + 3])
+    assert centers.dtype == dtype
+    _, points = triangulate_polygons_face([square, square + 3])
+    assert points.dtype == dtype
+    (_, points), (centers, _, _) = triangulate_polygons_with_edge([square])
+    assert points.dtype == dtype
+    assert centers.dtype == dtype
+    _, points, _ = triangulate_shapes_face([square, [square + 3]])
+    assert points.dtype == dtype
+    square_3d = np.insert(square, 2, 1, axis=1)
+    _, points = triangulate_polygons_face_3d([square_3d])
+    assert points.dtype == dtype
+    (polygon,) = split_polygons_on_repeated_edges([square])
+    assert polygon.dtype == dtype
+    (polygon,) = polygon_boolean([square], [square + 1], 'intersection')
+    assert polygon.dtype == dtype
+
+
+def test_mixed_dtypes_promoted_to_float64():
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    _, points = triangulate_polygons_face(
+        [square, (square + 3).astype(np.float64)]
+    )
+    assert points.dtype == np.float64
+
+
+def test_float64_precision():
+    # vertices 1 apart are not distinguishable in float32 at this magnitude
+    polygon = np.array(
+        [(1e8, 0), (1e8 + 1, 0), (1e8 + 1, 1), (1e8, 1)], dtype=np.float64
+    )
+    triangles, points = triangulate_polygons_face([polygon])
+    assert len(triangles) == 2
+    assert sorted(points.tolist()) == sorted(polygon.tolist())
+
+
 def test_split_polygons_on_repeated_edges(country_with_hole, tmp_path):
     res = split_polygons_on_repeated_edges([country_with_hole])
     assert len(res) == 2
 
 
+
+def _signed_area(ring):
+    x, y = ring[:, 0].astype(np.float64), ring[:, 1].astype(np.float64)
+    return (np.dot(x, np.roll(y, -1)) - np.dot(y, np.roll(x, -1))) / 2
+
+
+@pytest.mark.parametrize(
+    ('operation', 'area', 'rings'),
+    [
+        ('union', 7, 1),
+        ('intersection', 1, 1),
+        ('difference', 3, 1),
+        ('xor', 6, 2),
+    ],
+)
+def test_polygon_boolean(operation, area, rings):
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    res = polygon_boolean([square], [square + 1], operation)
+    assert len(res) == rings
+    assert sum(_signed_area(ring) for ring in res) == area
+
+
+def test_polygon_boolean_with_hole():
+    outer = np.array([(0, 0), (4, 0), (4, 4), (0, 4)], dtype=np.float32)
+    hole = np.array([(1, 1), (3, 1), (3, 3), (1, 3)], dtype=np.float32)
+    res = polygon_boolean([outer, hole], [outer + 5], 'union')
+    areas = sorted(_signed_area(ring) for ring in res)
+    assert areas == [-4, 16, 16]
+
+
+def test_polygon_boolean_unknown_operation():
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    with pytest.raises(ValueError, match='Unknown boolean operation'):
+        polygon_boolean([square], [square], 'sum')
+
+
+@pytest.mark.parametrize(
+    ('distance', 'join', 'area'),
+    [
+        (1, 'miter', 36),
+        (1, 'bevel', 34),
+        (-1, 'round', 4),
+        (-2.5, 'miter', 0),
+    ],
+)
+def test_offset_polygons(distance, join, area):
+    square = np.array([(0, 0), (4, 0), (4, 4), (0, 4)], dtype=np.float32)
+    res = offset_polygons([square], distance, join=join)
+    assert all(ring.dtype == np.float32 for ring in res)
+    assert sum(_signed_area(ring) for ring in res) == area
+
+
+def test_offset_polygons_with_hole():
+    outer = np.array([(0, 0), (10, 0), (10, 10), (0, 10)], dtype=np.float64)
+    hole = np.array([(4, 4), (6, 4), (6, 6), (4, 6)], dtype=np.float64)
+    res = offset_polygons([outer, hole], -1)
+    areas = sorted(_signed_area(ring) for ring in res)
+    assert areas == [-16, 64]
+    assert len(offset_polygons([outer, hole], 1.5)) == 1
+
+
+@pytest.mark.parametrize('distance', [np.nan, np.inf, -np.inf])
+def test_offset_polygons_non_finite_distance(distance):
+    square = np.array([(0, 0), (4, 0), (4, 4), (0, 4)], dtype=np.float32)
+    with pytest.raises(ValueError, match='finite'):
+        offset_polygons([square], distance)
+
+
+def test_offset_polygons_distance_overflow():
+    # finite distance which does not fit into the coordinate type
+    square = np.array([(0, 0), (4, 0), (4, 4), (0, 4)], dtype=np.float32)
+    with pytest.raises(TriangulationError, match='not finite'):
+        offset_polygons([square], 1e300)
+
+
+def _staircase(size):
+    steps = [((k + 1, size - k), (k, size - k)) for k in range(size - 1, 0, -1)]
+    return np.array(
+        [(0, 0), (size, 0)] + [p for step in steps for p in step] + [(1, size), (0, size)],
+        dtype=np.float32,
+    )
+
+
+@pytest.mark.parametrize('method', ['douglas-peucker', 'visvalingam-whyatt'])
+def test_simplify_polygons(method):
+    ring = _staircase(50)
+    res = simplify_polygons([ring, ring + 100], 1, method=method)
+    assert len(res) == 2
+    for simplified in res:
+        assert simplified.dtype == np.float32
+        assert 3 <= len(simplified) <= 4
+
+
+def test_simplify_polygons_preserve_topology():
+    outer = np.array(
+        [(0, 0), (4, 0), (4, -0.5), (6, -0.5), (6, 0), (10, 0), (10, 10), (0, 10)],
+        dtype=np.float64,
+    )
+    hole = np.array([(4.5, -0.3), (5.5, -0.3), (5.5, 0.3), (4.5, 0.3)], dtype=np.float64)
+    res = simplify_polygons([outer, hole], 1)
+    assert len(res[0]) == 4
+    res = simplify_polygons([outer, hole], 1, preserve_topology=True)
+    assert len(res[0]) > 4
+
+
+def test_simplify_polygons_unknown_method():
+    square = np.array([(0, 0), (2, 0), (2, 2), (0, 2)], dtype=np.float32)
+    with pytest.raises(ValueError, match='Unknown simplification method'):
+        simplify_polygons([square], 1, method='fast')
+
 @pytest.fixture
 def country_with_hole():
     return np.array(